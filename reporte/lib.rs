@@ -2,13 +2,56 @@
 
 #[ink::contract]
 mod reporte {
+    use core::cmp::Reverse;
     use core::ops::Mul;
-    use ink::prelude::string::ToString;
     use ink::prelude::vec::Vec;
     use scale_info::prelude::string::String;
-    use scale_info::prelude::vec;
 
-    use sistema_elecciones::SistemaEleccionesRef;
+    use sistema_elecciones::{EleccionInfo, MetadatosEleccion, PerfilCandidato, Ronda, SistemaEleccionesRef, DIGITOS_VISIBLES_DNI};
+
+    /// Errores que pueden devolver los mensajes del contrato de reportes.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// El llamador no es el administrador del contrato de reportes.
+        NoEsAdministrador,
+        /// Todavía no se configuró el contrato de `SistemaElecciones` a consultar.
+        SistemaEleccionesNoSeteado,
+        /// Error devuelto por el contrato de `SistemaElecciones`.
+        SistemaElecciones(sistema_elecciones::Error),
+    }
+
+    impl From<sistema_elecciones::Error> for Error {
+        fn from(error: sistema_elecciones::Error) -> Self {
+            Error::SistemaElecciones(error)
+        }
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// ID, nombre, apellido y DNI enmascarado de un votante.
+    type DatosVotante = (AccountId, String, String, String);
+    /// ID, nombre, apellido, DNI enmascarado, total de votos y perfil (partido, lista, nombre en la boleta y plataforma) de un candidato.
+    type DatosCandidato = (AccountId, String, String, String, u32, PerfilCandidato);
+    /// Votos emitidos, porcentaje de participación, votos válidos, votos en blanco y votos nulos.
+    type DatosParticipacion = (u32, u32, u32, u32, u32);
+    /// Candidatos electos, candidatos empatados en la última banca, todos los candidatos ordenados por votos
+    /// y los metadatos de la elección.
+    type ResultadoEleccion = (Vec<DatosCandidato>, Vec<DatosCandidato>, Vec<DatosCandidato>, MetadatosEleccion);
+
+    /// Reemplaza por asteriscos todos los caracteres del DNI salvo los últimos DIGITOS_VISIBLES_DNI, para que los reportes
+    /// no expongan DNI completos. Los identificadores derivados de un compromiso de DNI (que empiezan con '#') ya no
+    /// revelan el DNI y se retornan sin cambios.
+    fn enmascarar_dni(dni: &str) -> String {
+        if dni.starts_with('#') {
+            return String::from(dni);
+        }
+        let ocultos = dni.chars().count().saturating_sub(DIGITOS_VISIBLES_DNI);
+        dni.chars()
+            .enumerate()
+            .map(|(indice, caracter)| if indice < ocultos { '*' } else { caracter })
+            .collect()
+    }
 
     #[ink(storage)]
     pub struct Reporte {
@@ -16,6 +59,12 @@ mod reporte {
         sistema_elecciones: Option<SistemaEleccionesRef>,
     }
 
+    impl Default for Reporte {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     #[ink(impl)]
     impl Reporte {
         /// Constructor that initializes `sistema_elecciones` to `None`.
@@ -32,12 +81,12 @@ mod reporte {
         pub fn set_sistema_elecciones(
             &mut self,
             sistema_elecciones: SistemaEleccionesRef,
-        ) -> Result<String, String> {
+        ) -> Result<()> {
             if self.env().caller() != self.administrador {
-                return Err("No sos el administrador".to_string());
+                return Err(Error::NoEsAdministrador);
             }
             self.sistema_elecciones = Some(sistema_elecciones);
-            return Ok("Sistema elecciones guardado correctamente!".to_string());
+            Ok(())
         }
 
         /// Utilizado por todos los usuarios.
@@ -45,25 +94,22 @@ mod reporte {
         /// Parametors:
         ///     id_eleccion: u64: ID de la elección.
         /// Retorno:
-        ///     Result<Vec<(AccountId, String, String, String)>, String>: Vector con el ID de cada votante y su información detallada, o un mensaje de error.
+        ///     Result<Vec<(AccountId, String, String, String)>>: Vector con el ID de cada votante y su información detallada, o el error correspondiente.
         /// Descripción:
         /// La función recupera la lista de votantes de una elección dada por su ID (`id_eleccion`). Llama a una función privada
         /// para obtener los datos y luego añade información detallada sobre cada votante. Retorna un vector de tuplas con
         /// el `AccountId`, nombre, dirección y otros detalles del votante, o un mensaje de error en caso de fallo.
+        /// El DNI se muestra enmascarado: solo los últimos dígitos, o el identificador del compromiso si el sistema no guarda el DNI.
         #[ink(message)]
         pub fn reporte_de_votantes_por_eleccion(
             &mut self,
             id_eleccion: u64,
-        ) -> Result<Vec<(AccountId, String, String, String)>, String> {
+        ) -> Result<Vec<DatosVotante>> {
             let sistema_elecciones = match &mut self.sistema_elecciones {
-                None => return Err("Sistema elecciones no seteado".to_string()),
+                None => return Err(Error::SistemaEleccionesNoSeteado),
                 Some(value) => value,
             };
-            let datos_votantes =
-                match sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion) {
-                    Err(msg) => return Err(msg),
-                    Ok(datos) => datos,
-                };
+            let datos_votantes = sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion)?;
 
             Ok(datos_votantes
                 .iter()
@@ -72,10 +118,10 @@ mod reporte {
                         .obtener_informacion_usuario(datos_votante.0)
                         .unwrap_or_default();
                     (
-                        datos_votante.0.clone(),
+                        datos_votante.0,
                         datos_usuario.0,
                         datos_usuario.1,
-                        datos_usuario.2,
+                        enmascarar_dni(&datos_usuario.2),
                     )
                 })
                 .collect())
@@ -85,35 +131,55 @@ mod reporte {
         /// Parametros:
         ///     id_eleccion: u64: ID de la elección.
         /// Retorno:
-        ///     Result<(u32, u32), String>: Una tupla con la cantidad de votantes efectivos y el porcentaje de participación, o un mensaje de error.
+        ///     Result<(u32, u32, u32, u32, u32)>: Una tupla con la cantidad de votantes efectivos, el porcentaje de participación
+        ///     y la cantidad de votos válidos, en blanco y nulos, o el error correspondiente.
         /// Descripción:
         /// La función recupera la participación en una elección indicada por `id_eleccion`. Llama a una función privada
-        /// para obtener los datos de los votantes. Calcula el número de votantes que participaron efectivamente y el
-        /// porcentaje de participación. Devuelve estos valores en una tupla, o un mensaje de error si falla.
+        /// para obtener los datos de los votantes. Calcula el número de votantes que participaron efectivamente, incluyendo
+        /// a quienes delegaron su voto en alguien que votó, y el porcentaje de participación, que es 0% si la elección no tiene votantes. Con los resultados de la elección separa los votos en blanco y nulos; el resto
+        /// de los votos emitidos son válidos, salvo los votos secretos que no se revelaron. Devuelve estos valores en una tupla,
+        /// o un mensaje de error si falla.
         #[ink(message)]
         pub fn reporte_de_participacion_por_eleccion(
             &mut self,
             id_eleccion: u64,
-        ) -> Result<(u32, u32), String> {
+        ) -> Result<DatosParticipacion> {
             let sistema_elecciones = match &mut self.sistema_elecciones {
-                None => return Err("Sistema elecciones no seteado".to_string()),
+                None => return Err(Error::SistemaEleccionesNoSeteado),
                 Some(value) => value,
             };
-            let datos_votantes =
-                match sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion) {
-                    Err(msg) => return Err(msg),
-                    Ok(datos) => datos,
-                };
+            let datos_votantes = sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion)?;
 
+            let resultados = sistema_elecciones.obtener_resultados(id_eleccion)?;
+
+            // Los votos delegados cuentan como participación aunque quien delegó no haya votado
             let cantidad_votantes = datos_votantes.len() as u32;
-            let cantidad_votantes_voto_efectivo = datos_votantes
+            let cantidad_votantes_voto_efectivo = (datos_votantes
                 .iter()
                 .filter(|vot: &&(ink::primitives::AccountId, bool)| vot.1)
-                .count() as u32;
-            let porcentaje_participacion = cantidad_votantes_voto_efectivo
-                .mul(100)
-                .div_ceil(cantidad_votantes);
-            Ok((cantidad_votantes_voto_efectivo, porcentaje_participacion))
+                .count() as u32)
+                .saturating_add(resultados.votos_delegados as u32);
+            // Una elección sin votantes registrados tiene 0% de participación
+            let porcentaje_participacion = if cantidad_votantes == 0 {
+                0
+            } else {
+                cantidad_votantes_voto_efectivo
+                    .mul(100)
+                    .div_ceil(cantidad_votantes)
+            };
+            let votos_en_blanco = resultados.votos_en_blanco as u32;
+            let votos_nulos = resultados.votos_nulos as u32;
+            let votos_validos = cantidad_votantes_voto_efectivo
+                .saturating_sub(votos_en_blanco)
+                .saturating_sub(votos_nulos)
+                .saturating_sub(resultados.votos_sin_revelar as u32);
+            Ok((
+                cantidad_votantes_voto_efectivo,
+                porcentaje_participacion,
+                votos_validos,
+                votos_en_blanco,
+                votos_nulos,
+            ))
         }
 
         /// Permite obtener un reporte los datos de un candidato en particular dentro de una elección específica.
@@ -121,66 +187,100 @@ mod reporte {
         ///    eleccion_id (u64): El ID de la elección de la cual se quiere obtener la información del candidato.
         ///
         /// Retorno
-        /// Result< ((AccountId, String, String, String, u32), Vec<(AccountId, String, String, String, u32)>), String>:
-        /// Los datos del ganador de la eleccion si no resulta en empate y un Vector ordenado con: ID de cada candidato, Nombre, Apellido, DNI y su total de votos,
-        /// o un mensaje de error
+        /// Result<(Vec<DatosCandidato>, Vec<DatosCandidato>, Vec<DatosCandidato>, MetadatosEleccion)>:
+        /// Los candidatos que ocupan las bancas de la elección, los empatados en la última banca (que quedan sin asignar),
+        /// un Vector ordenado con: ID de cada candidato, Nombre, Apellido, DNI enmascarado, su total de votos y su perfil,
+        /// y el título, la descripción, el cargo y la jurisdicción de la elección, o el error correspondiente
         #[ink(message)]
         pub fn reporte_de_resultado_por_eleccion(
             &mut self,
             id_eleccion: u64,
-        ) -> Result<
-            (
-                Option<(AccountId, String, String, String, u32)>,
-                Vec<(AccountId, String, String, String, u32)>,
-            ),
-            String,
-        > {
+        ) -> Result<ResultadoEleccion> {
             let sistema_elecciones = match &mut self.sistema_elecciones {
-                None => return Err("Sistema elecciones no seteado".to_string()),
+                None => return Err(Error::SistemaEleccionesNoSeteado),
                 Some(value) => value,
             };
-            let mut datos_candidatos =
-                match sistema_elecciones.obtener_candidatos_eleccion_por_id(id_eleccion) {
-                    Err(msg) => return Err(msg),
-                    Ok(datos) => datos,
-                };
+            let mut datos_candidatos = sistema_elecciones.obtener_candidatos_eleccion_por_id(id_eleccion)?;
+            let resultados = sistema_elecciones.obtener_resultados(id_eleccion)?;
 
             // Ordenar datos_candidatos por la cantidad de votos (descendente)
-            datos_candidatos.sort_by(|a, b| b.1.cmp(&a.1));
+            datos_candidatos.sort_by_key(|datos_candidato| Reverse(datos_candidato.1));
 
-            let candidatos: Vec<(ink::primitives::AccountId, String, String, String, u32)> =
+            let candidatos: Vec<DatosCandidato> =
                 datos_candidatos
                     .iter()
                     .map(|datos_candidato| {
                         let datos_usuario = sistema_elecciones
                             .obtener_informacion_usuario(datos_candidato.0)
                             .unwrap_or_default();
+                        let perfil = sistema_elecciones
+                            .obtener_perfil_candidato(id_eleccion, datos_candidato.0)
+                            .unwrap_or_default();
                         (
-                            datos_candidato.0.clone(),
+                            datos_candidato.0,
                             datos_usuario.0,
                             datos_usuario.1,
-                            datos_usuario.2,
+                            enmascarar_dni(&datos_usuario.2),
                             datos_candidato.1,
+                            perfil,
                         )
                     })
                     .collect();
 
-            if candidatos.len() >= 2 && candidatos[0].4 == candidatos[1].4 {
-                return Ok((None, candidatos));
-            }
-            Ok((Some(candidatos[0].clone()), candidatos))
+            let datos_de = |ids: &[AccountId]| -> Vec<DatosCandidato> {
+                ids.iter()
+                    .filter_map(|id| candidatos.iter().find(|candidato| candidato.0 == *id).cloned())
+                    .collect()
+            };
+            let ganadores = datos_de(&resultados.ganadores);
+            let empatados_en_corte = datos_de(&resultados.empatados_en_corte);
+            let metadatos = sistema_elecciones.obtener_informacion_eleccion(id_eleccion)?.metadatos;
+            Ok((ganadores, empatados_en_corte, candidatos, metadatos))
+        }
+
+        /// Obtiene la información general de una elección específica.
+        /// Parámetros
+        ///    id_eleccion (u64): El ID de la elección.
+        ///
+        /// Retorno
+        /// Result<EleccionInfo>: Las fechas, el sistema de votación, la fase y los metadatos de la elección (título, descripción,
+        /// cargo, jurisdicción y hash del documento), o el error correspondiente.
+        #[ink(message)]
+        pub fn reporte_de_informacion_por_eleccion(&mut self, id_eleccion: u64) -> Result<EleccionInfo> {
+            let sistema_elecciones = match &mut self.sistema_elecciones {
+                None => return Err(Error::SistemaEleccionesNoSeteado),
+                Some(value) => value,
+            };
+            Ok(sistema_elecciones.obtener_informacion_eleccion(id_eleccion)?)
+        }
+
+        /// Obtiene el conteo ronda por ronda de una elección por preferencia instantánea.
+        /// Parámetros
+        ///    id_eleccion (u64): El ID de la elección.
+        ///
+        /// Retorno
+        /// Result<Vec<Ronda>>: Para cada ronda, los votos de los candidatos que seguían en competencia, los candidatos eliminados
+        /// al terminarla y las boletas agotadas, o el error correspondiente. Si la elección es por mayoría el vector está vacío.
+        #[ink(message)]
+        pub fn reporte_de_rondas_por_eleccion(&mut self, id_eleccion: u64) -> Result<Vec<Ronda>> {
+            let sistema_elecciones = match &mut self.sistema_elecciones {
+                None => return Err(Error::SistemaEleccionesNoSeteado),
+                Some(value) => value,
+            };
+            let resultados = sistema_elecciones.obtener_resultados(id_eleccion)?;
+            Ok(resultados.rondas)
         }
     }
 
-    // #[cfg(test)]
+    #[cfg(test)]
     struct SistemaEleccionesFake;
 
-    // #[cfg(test)]
+    #[cfg(test)]
     impl SistemaEleccionesFake {
         fn obtener_votantes_eleccion_por_id(
             &self,
             id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, bool)>, String> {
+        ) -> Result<Vec<(AccountId, bool)>> {
             match id_eleccion {
                 1 => Ok(vec![
                     (AccountId::from([0x08; 32]), true),
@@ -202,21 +302,22 @@ mod reporte {
                     (AccountId::from([0x04; 32]), true),
                     (AccountId::from([0x01; 32]), true),
                 ]),
-                3 => Ok(vec![
+                3 | 4 => Ok(vec![
                     (AccountId::from([0x01; 32]), true),
                     (AccountId::from([0x08; 32]), true),
                     (AccountId::from([0x02; 32]), false),
                     (AccountId::from([0x06; 32]), true),
                     (AccountId::from([0x04; 32]), true),
                 ]),
-                _ => Err("Eleccion no existe".to_string()),
+                5 => Ok(vec![]),
+                _ => Err(sistema_elecciones::Error::EleccionInexistente.into()),
             }
         }
 
         fn obtener_candidatos_eleccion_por_id(
             &self,
             id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, u32)>, String> {
+        ) -> Result<Vec<(AccountId, u32)>> {
             match id_eleccion {
                 1 => Ok(vec![
                     (AccountId::from([0x0A; 32]), 2),
@@ -231,101 +332,206 @@ mod reporte {
                     (AccountId::from([0x0A; 32]), 2),
                     (AccountId::from([0x0C; 32]), 2),
                 ]),
-                _ => Err("Eleccion no existe".to_string()),
+                4 => Ok(vec![
+                    (AccountId::from([0x0B; 32]), 3),
+                    (AccountId::from([0x0A; 32]), 5),
+                    (AccountId::from([0x0C; 32]), 3),
+                ]),
+                5 => Ok(vec![]),
+                _ => Err(sistema_elecciones::Error::EleccionInexistente.into()),
+            }
+        }
+
+        fn obtener_resultados(&self, id_eleccion: u32) -> Result<sistema_elecciones::Resultados> {
+            let rondas = match id_eleccion {
+                1 | 3 | 4 | 5 => vec![],
+                2 => vec![
+                    Ronda {
+                        votos_candidatos: vec![
+                            (AccountId::from([0x0B; 32]), 2),
+                            (AccountId::from([0x0A; 32]), 3),
+                            (AccountId::from([0x0C; 32]), 3),
+                        ],
+                        eliminados: vec![AccountId::from([0x0B; 32])],
+                        votos_agotados: 0,
+                    },
+                    Ronda {
+                        votos_candidatos: vec![
+                            (AccountId::from([0x0A; 32]), 5),
+                            (AccountId::from([0x0C; 32]), 3),
+                        ],
+                        eliminados: vec![],
+                        votos_agotados: 0,
+                    },
+                ],
+                _ => return Err(sistema_elecciones::Error::EleccionInexistente.into()),
+            };
+            // La elección 4 elige dos bancas y la segunda queda empatada, la elección 5 no tuvo votantes
+            let (ganadores, empatados_en_corte) = match id_eleccion {
+                1 => (vec![AccountId::from([0x0C; 32])], vec![]),
+                5 => (vec![], vec![]),
+                2 => (vec![AccountId::from([0x0A; 32])], vec![]),
+                3 => (vec![], vec![AccountId::from([0x0A; 32]), AccountId::from([0x0C; 32])]),
+                _ => (vec![AccountId::from([0x0A; 32])], vec![AccountId::from([0x0B; 32]), AccountId::from([0x0C; 32])]),
+            };
+            let votos_candidatos = self.obtener_candidatos_eleccion_por_id(id_eleccion)?
+                .iter()
+                .map(|(id, votos)| (*id, *votos as u64))
+                .collect();
+            // En la elección 1 hay un voto en blanco y uno nulo, en la elección 2 un voto secreto sin revelar
+            // y en la elección 4 el votante que no votó delegó su voto
+            let (votos_sin_revelar, votos_en_blanco, votos_nulos, votos_delegados) = match id_eleccion {
+                1 => (0, 1, 1, 0),
+                2 => (1, 0, 0, 0),
+                4 => (0, 0, 0, 1),
+                _ => (0, 0, 0, 0),
+            };
+            Ok(sistema_elecciones::Resultados {
+                votos_totales: self.obtener_votantes_eleccion_por_id(id_eleccion)?.len() as u64,
+                votos_realizados: 8,
+                votos_delegados,
+                votos_sin_revelar,
+                votos_en_blanco,
+                votos_nulos,
+                votos_candidatos,
+                rondas,
+                ganadores,
+                empatados_en_corte,
+            })
+        }
+
+        fn obtener_informacion_eleccion(&self, id_eleccion: u32) -> Result<EleccionInfo> {
+            if !(1..=4).contains(&id_eleccion) {
+                return Err(sistema_elecciones::Error::EleccionInexistente.into());
+            }
+            Ok(EleccionInfo {
+                id: id_eleccion as u64,
+                fecha_inicio: 1719835200000,
+                fecha_final: 1735646400000,
+                fecha_fin_revelacion: None,
+                inicio_inscripcion: 0,
+                cierre_inscripcion_candidatos: 1719835200000,
+                cierre_inscripcion_votantes: 1719835200000,
+                sistema_votacion: sistema_elecciones::SistemaVotacion::Mayoria,
+                bancas: if id_eleccion == 4 { 2 } else { 1 },
+                cantidad_candidatos: self.obtener_candidatos_eleccion_por_id(id_eleccion)?.len() as u32,
+                cantidad_votantes: self.obtener_votantes_eleccion_por_id(id_eleccion)?.len() as u32,
+                estado: sistema_elecciones::EstadoEleccion::Finalizada,
+                metadatos: MetadatosEleccion {
+                    titulo: "Elección de intendente".to_string(),
+                    descripcion: "Elección general de intendente municipal.".to_string(),
+                    cargo: "Intendente".to_string(),
+                    jurisdiccion: "AR-B-001".to_string(),
+                    hash_documento: None,
+                },
+            })
+        }
+
+        fn obtener_perfil_candidato(&self, _id_eleccion: u32, candidato: AccountId) -> Result<PerfilCandidato> {
+            if candidato == AccountId::from([0x0A; 32]) {
+                Ok(PerfilCandidato {
+                    partido: "Frente Vecinal".to_string(),
+                    numero_lista: 501,
+                    nombre_boleta: "Ana Pérez".to_string(),
+                    plataforma: "Más espacios verdes.".to_string(),
+                    hash_contenido: None,
+                })
+            } else {
+                Err(sistema_elecciones::Error::CandidatoInexistente.into())
             }
         }
 
         fn obtener_informacion_usuario(
             &self,
             id_usuario: AccountId,
-        ) -> Option<(String, String, String)> {
+        ) -> Result<(String, String, String)> {
             if id_usuario == AccountId::from([0x01; 32]) {
-                Some((
+                Ok((
                     "Alice".to_string(),
                     "Wonderland".to_string(),
                     "54326961".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x02; 32]) {
-                Some((
+                Ok((
                     "Bob".to_string(),
                     "Builder".to_string(),
                     "64128970".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x03; 32]) {
-                Some((
+                Ok((
                     "Carlos".to_string(),
                     "Caceres".to_string(),
                     "54326961".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x04; 32]) {
-                Some((
+                Ok((
                     "Ana".to_string(),
                     "Martínez".to_string(),
                     "45678901".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x05; 32]) {
-                Some((
+                Ok((
                     "Luis".to_string(),
                     "Sánchez".to_string(),
                     "56789012".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x06; 32]) {
-                Some((
+                Ok((
                     "Elena".to_string(),
                     "Rodríguez".to_string(),
                     "67890123".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x07; 32]) {
-                Some((
+                Ok((
                     "Pedro".to_string(),
                     "Fernández".to_string(),
                     "78901234".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x08; 32]) {
-                Some((
+                Ok((
                     "Juan".to_string(),
                     "Pérez".to_string(),
                     "12345678".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x09; 32]) {
-                Some((
+                Ok((
                     "María".to_string(),
                     "González".to_string(),
                     "23456789".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x0A; 32]) {
                 //CANDIDATO
-                Some((
+                Ok((
                     "Carlos".to_string(),
                     "Gómez".to_string(),
                     "34567890".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x0B; 32]) {
                 //CANDIDATO
-                Some((
+                Ok((
                     "Ricardo".to_string(),
                     "Palacios".to_string(),
                     "24218796".to_string(),
                 ))
             } else if id_usuario == AccountId::from([0x0C; 32]) {
                 //CANDIDATO
-                Some((
+                Ok((
                     "Tomas".to_string(),
                     "Lopez".to_string(),
                     "78921353".to_string(),
                 ))
             } else {
-                None
+                Err(sistema_elecciones::Error::UsuarioInexistente.into())
             }
         }
     }
 
-    // #[cfg(test)]
+    #[cfg(test)]
     struct ReporteFake {
         sistema_elecciones: Option<SistemaEleccionesFake>,
     }
 
-    // #[cfg(test)]
+    #[cfg(test)]
     impl ReporteFake {
         fn new(sistema_elecciones: SistemaEleccionesFake) -> Self {
             ReporteFake {
@@ -342,16 +548,12 @@ mod reporte {
         fn reporte_de_votantes_por_eleccion(
             &mut self,
             id_eleccion: u32,
-        ) -> Result<Vec<(AccountId, String, String, String)>, String> {
+        ) -> Result<Vec<DatosVotante>> {
             let sistema_elecciones = match &mut self.sistema_elecciones {
-                None => return Err("Sistema elecciones no seteado".to_string()),
+                None => return Err(Error::SistemaEleccionesNoSeteado),
                 Some(value) => value,
             };
-            let datos_votantes =
-                match sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion) {
-                    Err(msg) => return Err(msg),
-                    Ok(datos) => datos,
-                };
+            let datos_votantes = sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion)?;
             let reporte = datos_votantes
                 .iter()
                 .map(|datos_votante| {
@@ -359,10 +561,10 @@ mod reporte {
                         .obtener_informacion_usuario(datos_votante.0)
                         .unwrap_or_default();
                     (
-                        datos_votante.0.clone(),
+                        datos_votante.0,
                         datos_usuario.0,
                         datos_usuario.1,
-                        datos_usuario.2,
+                        enmascarar_dni(&datos_usuario.2),
                     )
                 })
                 .collect();
@@ -372,72 +574,106 @@ mod reporte {
         fn reporte_de_participacion_por_eleccion(
             &mut self,
             id_eleccion: u32,
-        ) -> Result<(u32, u32), String> {
+        ) -> Result<DatosParticipacion> {
             let sistema_elecciones = match &mut self.sistema_elecciones {
-                None => return Err("Sistema elecciones no seteado".to_string()),
+                None => return Err(Error::SistemaEleccionesNoSeteado),
                 Some(value) => value,
             };
-            let datos_votantes =
-                match sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion) {
-                    Err(msg) => return Err(msg),
-                    Ok(datos) => datos,
-                };
+            let datos_votantes = sistema_elecciones.obtener_votantes_eleccion_por_id(id_eleccion)?;
+
+            let resultados = sistema_elecciones.obtener_resultados(id_eleccion)?;
 
+            // Los votos delegados cuentan como participación aunque quien delegó no haya votado
             let cantidad_votantes = datos_votantes.len() as u32;
-            let cantidad_votantes_voto_efectivo = datos_votantes
+            let cantidad_votantes_voto_efectivo = (datos_votantes
                 .iter()
                 .filter(|vot: &&(ink::primitives::AccountId, bool)| vot.1)
-                .count() as u32;
-            let porcentaje_participacion = cantidad_votantes_voto_efectivo
-                .mul(100)
-                .div_ceil(cantidad_votantes);
-            Ok((cantidad_votantes_voto_efectivo, porcentaje_participacion))
+                .count() as u32)
+                .saturating_add(resultados.votos_delegados as u32);
+            // Una elección sin votantes registrados tiene 0% de participación
+            let porcentaje_participacion = if cantidad_votantes == 0 {
+                0
+            } else {
+                cantidad_votantes_voto_efectivo
+                    .mul(100)
+                    .div_ceil(cantidad_votantes)
+            };
+            let votos_en_blanco = resultados.votos_en_blanco as u32;
+            let votos_nulos = resultados.votos_nulos as u32;
+            let votos_validos = cantidad_votantes_voto_efectivo
+                .saturating_sub(votos_en_blanco)
+                .saturating_sub(votos_nulos)
+                .saturating_sub(resultados.votos_sin_revelar as u32);
+            Ok((
+                cantidad_votantes_voto_efectivo,
+                porcentaje_participacion,
+                votos_validos,
+                votos_en_blanco,
+                votos_nulos,
+            ))
         }
 
         fn reporte_de_resultado_por_eleccion(
             &mut self,
             id_eleccion: u32,
-        ) -> Result<
-            (
-                Option<(AccountId, String, String, String, u32)>,
-                Vec<(AccountId, String, String, String, u32)>,
-            ),
-            String,
-        > {
+        ) -> Result<ResultadoEleccion> {
             let sistema_elecciones = match &mut self.sistema_elecciones {
-                None => return Err("Sistema elecciones no seteado".to_string()),
+                None => return Err(Error::SistemaEleccionesNoSeteado),
                 Some(value) => value,
             };
-            let mut datos_candidatos =
-                match sistema_elecciones.obtener_candidatos_eleccion_por_id(id_eleccion) {
-                    Err(msg) => return Err(msg),
-                    Ok(datos) => datos,
-                };
+            let mut datos_candidatos = sistema_elecciones.obtener_candidatos_eleccion_por_id(id_eleccion)?;
+            let resultados = sistema_elecciones.obtener_resultados(id_eleccion)?;
 
             // Ordenar datos_candidatos por la cantidad de votos (descendente)
-            datos_candidatos.sort_by(|a, b| b.1.cmp(&a.1));
+            datos_candidatos.sort_by_key(|datos_candidato| Reverse(datos_candidato.1));
 
-            let candidatos: Vec<(ink::primitives::AccountId, String, String, String, u32)> =
+            let candidatos: Vec<DatosCandidato> =
                 datos_candidatos
                     .iter()
                     .map(|datos_candidato| {
                         let datos_usuario = sistema_elecciones
                             .obtener_informacion_usuario(datos_candidato.0)
                             .unwrap_or_default();
+                        let perfil = sistema_elecciones
+                            .obtener_perfil_candidato(id_eleccion, datos_candidato.0)
+                            .unwrap_or_default();
                         (
-                            datos_candidato.0.clone(),
+                            datos_candidato.0,
                             datos_usuario.0,
                             datos_usuario.1,
-                            datos_usuario.2,
+                            enmascarar_dni(&datos_usuario.2),
                             datos_candidato.1,
+                            perfil,
                         )
                     })
                     .collect();
 
-            if candidatos.len() >= 2 && candidatos[0].4 == candidatos[1].4 {
-                return Ok((None, candidatos));
-            }
-            Ok((Some(candidatos[0].clone()), candidatos))
+            let datos_de = |ids: &[AccountId]| -> Vec<DatosCandidato> {
+                ids.iter()
+                    .filter_map(|id| candidatos.iter().find(|candidato| candidato.0 == *id).cloned())
+                    .collect()
+            };
+            let ganadores = datos_de(&resultados.ganadores);
+            let empatados_en_corte = datos_de(&resultados.empatados_en_corte);
+            let metadatos = sistema_elecciones.obtener_informacion_eleccion(id_eleccion)?.metadatos;
+            Ok((ganadores, empatados_en_corte, candidatos, metadatos))
+        }
+
+        fn reporte_de_informacion_por_eleccion(&mut self, id_eleccion: u32) -> Result<EleccionInfo> {
+            let sistema_elecciones = match &mut self.sistema_elecciones {
+                None => return Err(Error::SistemaEleccionesNoSeteado),
+                Some(value) => value,
+            };
+            sistema_elecciones.obtener_informacion_eleccion(id_eleccion)
+        }
+
+        fn reporte_de_rondas_por_eleccion(&mut self, id_eleccion: u32) -> Result<Vec<Ronda>> {
+            let sistema_elecciones = match &mut self.sistema_elecciones {
+                None => return Err(Error::SistemaEleccionesNoSeteado),
+                Some(value) => value,
+            };
+            let resultados = sistema_elecciones.obtener_resultados(id_eleccion)?;
+            Ok(resultados.rondas)
         }
     }
     // Módulo de pruebas
@@ -445,6 +681,8 @@ mod reporte {
     mod tests {
         use ink::primitives::AccountId;
 
+        use super::enmascarar_dni;
+        use super::Error;
         use super::ReporteFake;
         use super::SistemaEleccionesFake;
 
@@ -452,7 +690,7 @@ mod reporte {
         fn test_reporte_de_votantes_por_eleccion_error_sin_sistema() {
             let mut reporte = ReporteFake::new_vacio();
             let result = reporte.reporte_de_votantes_por_eleccion(0);
-            assert!(result.is_err());
+            assert_eq!(result, Err(Error::SistemaEleccionesNoSeteado));
         }
 
         #[test]
@@ -460,7 +698,12 @@ mod reporte {
             let sist_elecciones = SistemaEleccionesFake;
             let mut reporte = ReporteFake::new(sist_elecciones);
             let result = reporte.reporte_de_votantes_por_eleccion(0);
-            assert!(result.is_err());
+            assert_eq!(
+                result,
+                Err(Error::SistemaElecciones(
+                    sistema_elecciones::Error::EleccionInexistente
+                ))
+            );
         }
 
         #[test]
@@ -477,6 +720,7 @@ mod reporte {
 
             assert_eq!(result.as_ref().unwrap()[4].0, AccountId::from([0x03; 32]));
             assert_eq!(result.as_ref().unwrap()[4].1, "Carlos".to_string());
+            assert_eq!(result.as_ref().unwrap()[4].3, "*****961".to_string());
 
             let result = reporte.reporte_de_votantes_por_eleccion(3);
 
@@ -487,6 +731,15 @@ mod reporte {
             assert_eq!(result.as_ref().unwrap()[4].0, AccountId::from([0x04; 32]));
         }
 
+        #[test]
+        fn test_enmascarar_dni() {
+            assert_eq!(enmascarar_dni("54326961"), "*****961");
+            assert_eq!(enmascarar_dni("12"), "12");
+            assert_eq!(enmascarar_dni(""), "");
+            // Los identificadores de un compromiso de DNI no revelan el DNI
+            assert_eq!(enmascarar_dni("#1a2b3c4d"), "#1a2b3c4d");
+        }
+
         #[test]
         fn test_reporte_de_participacion_por_eleccion_error_sin_sistema() {
             let mut reporte = ReporteFake::new_vacio();
@@ -522,6 +775,40 @@ mod reporte {
             assert_eq!(result.as_ref().unwrap().1, 72);
         }
 
+        #[test]
+        fn test_reporte_de_participacion_por_eleccion_votos_validos_blancos_y_nulos() {
+            let sist_elecciones = SistemaEleccionesFake;
+            let mut reporte = ReporteFake::new(sist_elecciones);
+
+            // 5 votos emitidos: 3 válidos, 1 en blanco y 1 nulo
+            let (_, _, validos, en_blanco, nulos) = reporte.reporte_de_participacion_por_eleccion(1).unwrap();
+            assert_eq!((validos, en_blanco, nulos), (3, 1, 1));
+
+            // 8 votos emitidos, uno secreto sin revelar que no es válido
+            let (_, _, validos, en_blanco, nulos) = reporte.reporte_de_participacion_por_eleccion(2).unwrap();
+            assert_eq!((validos, en_blanco, nulos), (7, 0, 0));
+        }
+
+        #[test]
+        fn test_reporte_de_participacion_por_eleccion_con_voto_delegado() {
+            let sist_elecciones = SistemaEleccionesFake;
+            let mut reporte = ReporteFake::new(sist_elecciones);
+
+            // 4 de 5 votantes votaron y el restante delegó su voto
+            let result = reporte.reporte_de_participacion_por_eleccion(4).unwrap();
+            assert_eq!((result.0, result.1, result.2), (5, 100, 5));
+        }
+
+        #[test]
+        fn test_reporte_de_participacion_por_eleccion_sin_votantes() {
+            let sist_elecciones = SistemaEleccionesFake;
+            let mut reporte = ReporteFake::new(sist_elecciones);
+
+            // Sin votantes registrados la participación es 0% en lugar de dividir por cero
+            let result = reporte.reporte_de_participacion_por_eleccion(5);
+            assert_eq!(result, Ok((0, 0, 0, 0, 0)));
+        }
+
         #[test]
         fn test_reporte_de_resultado_por_eleccion_error_sin_sistema() {
             let mut reporte = ReporteFake::new_vacio();
@@ -543,13 +830,14 @@ mod reporte {
             let result = reporte.reporte_de_resultado_por_eleccion(3);
 
             assert!(result.is_ok());
-            assert!(result.as_ref().unwrap().0.is_none());
+            assert!(result.as_ref().unwrap().0.is_empty());
+            assert_eq!(result.as_ref().unwrap().1.len(), 2);
 
-            assert_eq!(result.as_ref().unwrap().1[0].0, AccountId::from([0x0A; 32]));
-            assert_eq!(result.as_ref().unwrap().1[0].4, 2);
+            assert_eq!(result.as_ref().unwrap().2[0].0, AccountId::from([0x0A; 32]));
+            assert_eq!(result.as_ref().unwrap().2[0].4, 2);
 
-            assert_eq!(result.as_ref().unwrap().1[1].0, AccountId::from([0x0C; 32]));
-            assert_eq!(result.as_ref().unwrap().1[1].4, 2);
+            assert_eq!(result.as_ref().unwrap().2[1].0, AccountId::from([0x0C; 32]));
+            assert_eq!(result.as_ref().unwrap().2[1].4, 2);
         }
 
         #[test]
@@ -559,21 +847,22 @@ mod reporte {
             let result = reporte.reporte_de_resultado_por_eleccion(2);
 
             assert!(result.is_ok());
-            assert!(result.as_ref().unwrap().0.is_some());
+            assert_eq!(result.as_ref().unwrap().0.len(), 1);
+            assert_eq!(result.as_ref().unwrap().0[0].0, AccountId::from([0x0A; 32]));
 
-            assert_ne!(result.as_ref().unwrap().1[0].0, AccountId::from([0x0B; 32]));
+            assert_ne!(result.as_ref().unwrap().2[0].0, AccountId::from([0x0B; 32]));
             // (AccountId::from([0x0B; 32]), 1),
             // (AccountId::from([0x0A; 32]), 5),
             // (AccountId::from([0x0C; 32]), 2),
 
-            assert_eq!(result.as_ref().unwrap().1[0].0, AccountId::from([0x0A; 32]));
-            assert_eq!(result.as_ref().unwrap().1[0].4, 5);
+            assert_eq!(result.as_ref().unwrap().2[0].0, AccountId::from([0x0A; 32]));
+            assert_eq!(result.as_ref().unwrap().2[0].4, 5);
 
-            assert_eq!(result.as_ref().unwrap().1[1].0, AccountId::from([0x0C; 32]));
-            assert_eq!(result.as_ref().unwrap().1[1].4, 2);
+            assert_eq!(result.as_ref().unwrap().2[1].0, AccountId::from([0x0C; 32]));
+            assert_eq!(result.as_ref().unwrap().2[1].4, 2);
 
-            assert_eq!(result.as_ref().unwrap().1[2].0, AccountId::from([0x0B; 32]));
-            assert_eq!(result.as_ref().unwrap().1[2].4, 1);
+            assert_eq!(result.as_ref().unwrap().2[2].0, AccountId::from([0x0B; 32]));
+            assert_eq!(result.as_ref().unwrap().2[2].4, 1);
         }
         #[test]
         fn test_reporte_de_resultado_por_eleccion_exito_victoria_2() {
@@ -582,18 +871,88 @@ mod reporte {
             let result = reporte.reporte_de_resultado_por_eleccion(1);
 
             assert!(result.is_ok());
-            assert!(result.as_ref().unwrap().0.is_some());
+            assert_eq!(result.as_ref().unwrap().0.len(), 1);
 
-            assert_ne!(result.as_ref().unwrap().1[0].0, AccountId::from([0x0B; 32]));
+            assert_ne!(result.as_ref().unwrap().2[0].0, AccountId::from([0x0B; 32]));
 
             // (AccountId::from([0x0A; 32]), 2),
             // (AccountId::from([0x0C; 32]), 3),
 
-            assert_eq!(result.as_ref().unwrap().1[0].0, AccountId::from([0x0C; 32]));
-            assert_eq!(result.as_ref().unwrap().1[0].4, 3);
+            assert_eq!(result.as_ref().unwrap().2[0].0, AccountId::from([0x0C; 32]));
+            assert_eq!(result.as_ref().unwrap().2[0].4, 3);
+
+            assert_eq!(result.as_ref().unwrap().2[1].0, AccountId::from([0x0A; 32]));
+            assert_eq!(result.as_ref().unwrap().2[1].4, 2);
+        }
+
+        #[test]
+        fn test_reporte_de_resultado_por_eleccion_empate_en_corte() {
+            let sist_elecciones = SistemaEleccionesFake;
+            let mut reporte = ReporteFake::new(sist_elecciones);
+            let (ganadores, empatados_en_corte, candidatos, metadatos) = reporte.reporte_de_resultado_por_eleccion(4).unwrap();
+
+            // Dos bancas: Carlos ocupa la primera, Ricardo y Tomas empatan en la segunda
+            assert_eq!(ganadores.len(), 1);
+            assert_eq!(ganadores[0].0, AccountId::from([0x0A; 32]));
+            assert_eq!(ganadores[0].1, "Carlos");
+            assert_eq!(empatados_en_corte.len(), 2);
+            assert_eq!(empatados_en_corte[0].0, AccountId::from([0x0B; 32]));
+            assert_eq!(empatados_en_corte[0].4, 3);
+            assert_eq!(empatados_en_corte[1].0, AccountId::from([0x0C; 32]));
+            assert_eq!(candidatos.len(), 3);
+            assert_eq!(metadatos.cargo, "Intendente");
+        }
+
+        #[test]
+        fn test_reporte_de_resultado_por_eleccion_incluye_perfil() {
+            let mut reporte = ReporteFake::new(SistemaEleccionesFake);
+            let (ganadores, _, candidatos, _) = reporte.reporte_de_resultado_por_eleccion(2).unwrap();
+            assert_eq!(ganadores[0].0, AccountId::from([0x0A; 32]));
+            assert_eq!(ganadores[0].5.partido, "Frente Vecinal");
+            assert_eq!(ganadores[0].5.numero_lista, 501);
+            assert_eq!(ganadores[0].5.nombre_boleta, "Ana Pérez");
+            // Los candidatos sin perfil cargado aparecen con un perfil vacío
+            assert_eq!(candidatos[1].5, Default::default());
+        }
+
+        #[test]
+        fn test_reporte_de_informacion_por_eleccion() {
+            let mut reporte = ReporteFake::new_vacio();
+            assert_eq!(reporte.reporte_de_informacion_por_eleccion(1), Err(Error::SistemaEleccionesNoSeteado));
+
+            let mut reporte = ReporteFake::new(SistemaEleccionesFake);
+            let informacion = reporte.reporte_de_informacion_por_eleccion(2).unwrap();
+            assert_eq!(informacion.id, 2);
+            assert_eq!(informacion.cantidad_candidatos, 3);
+            assert_eq!(informacion.metadatos.titulo, "Elección de intendente");
+            assert_eq!(informacion.metadatos.jurisdiccion, "AR-B-001");
+            assert_eq!(
+                reporte.reporte_de_informacion_por_eleccion(0),
+                Err(Error::SistemaElecciones(sistema_elecciones::Error::EleccionInexistente))
+            );
+        }
+
+        #[test]
+        fn test_reporte_de_rondas_por_eleccion_error_sin_sistema() {
+            let mut reporte = ReporteFake::new_vacio();
+            let result = reporte.reporte_de_rondas_por_eleccion(2);
+            assert_eq!(result, Err(Error::SistemaEleccionesNoSeteado));
+        }
+
+        #[test]
+        fn test_reporte_de_rondas_por_eleccion_exito() {
+            let sist_elecciones = SistemaEleccionesFake;
+            let mut reporte = ReporteFake::new(sist_elecciones);
+
+            let rondas = reporte.reporte_de_rondas_por_eleccion(2).unwrap();
+            assert_eq!(rondas.len(), 2);
+            assert_eq!(rondas[0].eliminados, vec![AccountId::from([0x0B; 32])]);
+            assert!(rondas[1].eliminados.is_empty());
+            assert_eq!(rondas[1].votos_candidatos[0], (AccountId::from([0x0A; 32]), 5));
 
-            assert_eq!(result.as_ref().unwrap().1[1].0, AccountId::from([0x0A; 32]));
-            assert_eq!(result.as_ref().unwrap().1[1].4, 2);
+            // Las elecciones por mayoría no tienen rondas
+            assert_eq!(reporte.reporte_de_rondas_por_eleccion(1), Ok(vec![]));
+            assert!(reporte.reporte_de_rondas_por_eleccion(0).is_err());
         }
     }
 }
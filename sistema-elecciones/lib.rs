@@ -1,39 +1,406 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-pub use self::sistema_elecciones::{SistemaElecciones, SistemaEleccionesRef};
+pub use self::sistema_elecciones::{
+    AccionAdministrativa, AccionHistorial, CambioDeDatos, CandidatoInfo, CodigoRechazo, ConfiguracionEleccion, EleccionInfo, Error, EstadoEleccion, MetadatosEleccion, MotivoRechazo, Pagina, PerfilCandidato, Preferencias, PropuestaAccion, PropuestaAdministrador, RegistroHistorial, Resultados, Rol, Ronda, SistemaElecciones, SistemaEleccionesRef, SistemaVotacion, TIPO_DE_USUARIO, Voto,
+    ResultadosPorCuenta, SolicitudPendiente, UsuarioInfo, BLOQUES_VENCIMIENTO_PROPUESTAS, DURACION_MINIMA_VOTACION,
+    LARGO_MAXIMO_CARGO, LARGO_MAXIMO_DESCRIPCION, LARGO_MAXIMO_DNI, LARGO_MAXIMO_JURISDICCION, LARGO_MAXIMO_MOTIVO, LARGO_MAXIMO_NOMBRE, LARGO_MAXIMO_NOMBRE_BOLETA, LARGO_MAXIMO_PARTIDO, LARGO_MAXIMO_PLATAFORMA, LARGO_MAXIMO_TITULO, LARGO_MINIMO_DNI, DIGITOS_VISIBLES_DNI, MAXIMO_ELEMENTOS_POR_PAGINA, MAXIMO_USUARIOS_POR_LOTE, VOTO_EN_BLANCO, VOTO_NULO,
+};
 
 #[ink::contract]
 mod sistema_elecciones {
+    use ink::prelude::format;
     use ink::prelude::string::String;
-    use ink::prelude::vec::Vec;
-    use scale_info::prelude::format;
+    use ink::prelude::{vec, vec::Vec};
+    use ink::storage::Mapping;
+
+    /// Errores que pueden devolver los mensajes del contrato.
+    /// Es público para que los contratos que llaman a `SistemaElecciones` (como `reporte`) puedan distinguir cada caso.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error
+    {
+        /// El llamador no es el administrador del sistema.
+        NoEsAdministrador,
+        /// El llamador no es generador de reportes, auditor ni administrador.
+        NoEsGeneradorReportes,
+        /// El llamador no es operador de padrón de la elección ni administrador.
+        NoEsOperadorPadron,
+        /// La cuenta ya tiene el rol indicado.
+        RolYaOtorgado,
+        /// La cuenta no tiene el rol indicado.
+        RolNoOtorgado,
+        /// Solo el rol de operador de padrón puede limitarse a una elección.
+        RolSinAlcancePorEleccion,
+        /// No se puede quitar el rol al último administrador.
+        UltimoAdministrador,
+        /// No hay una transferencia de administrador pendiente.
+        SinTransferenciaPendiente,
+        /// El llamador no es la cuenta propuesta como nuevo administrador.
+        NoEsAdministradorPropuesto,
+        /// La propuesta de transferencia de administrador venció.
+        TransferenciaVencida,
+        /// La acción requiere la aprobación de varios administradores: se debe proponer con `proponer_accion`.
+        RequiereMultifirma,
+        /// El umbral de aprobación debe estar entre 1 y la cantidad de administradores.
+        UmbralInvalido,
+        /// No existe una propuesta con el id indicado.
+        PropuestaInexistente,
+        /// El llamador ya confirmó la propuesta.
+        PropuestaYaConfirmada,
+        /// La propuesta ya fue ejecutada.
+        PropuestaYaEjecutada,
+        /// La propuesta venció sin alcanzar las confirmaciones necesarias.
+        PropuestaVencida,
+        /// El llamador no está registrado en el sistema.
+        UsuarioNoRegistrado,
+        /// No existe un usuario registrado con el id indicado.
+        UsuarioInexistente,
+        /// El registro de usuarios no está activado.
+        RegistroNoActivado,
+        /// El registro de usuarios ya está activado.
+        RegistroYaActivado,
+        /// El registro de usuarios ya está desactivado.
+        RegistroYaDesactivado,
+        /// El administrador intentó registrarse como usuario.
+        AdministradorNoPuedeRegistrarse,
+        /// La solicitud de registro del llamador ya fue rechazada.
+        RegistroRechazado,
+        /// El llamador ya está registrado como usuario.
+        UsuarioYaRegistrado,
+        /// El llamador ya está en la cola de usuarios pendientes.
+        UsuarioYaPendiente,
+        /// El nombre o el apellido están vacíos o superan LARGO_MAXIMO_NOMBRE bytes.
+        NombreInvalido,
+        /// El DNI no tiene solo dígitos o su largo no está entre LARGO_MINIMO_DNI y LARGO_MAXIMO_DNI.
+        DniInvalido,
+        /// Ya hay un usuario registrado o pendiente con ese DNI.
+        DniYaRegistrado,
+        /// La protección del DNI está activada: hay que registrarse con el compromiso del DNI.
+        RequiereCompromisoDni,
+        /// La protección del DNI no está activada.
+        ProteccionDniNoActivada,
+        /// La protección del DNI ya está activada.
+        ProteccionDniYaActivada,
+        /// No se puede activar la protección del DNI porque ya hay usuarios registrados o pendientes.
+        ProteccionDniNoDisponible,
+        /// Un operador de padrón todavía no confirmó el DNI del usuario con `confirmar_dni`.
+        DniNoConfirmado,
+        /// La cuenta no está rechazada en el sistema o en la elección indicada.
+        UsuarioNoRechazado,
+        /// La cuenta ya apeló ese rechazo.
+        ApelacionYaPresentada,
+        /// La cuenta fue bloqueada definitivamente.
+        UsuarioBloqueado,
+        /// El detalle del motivo o el descargo supera LARGO_MAXIMO_MOTIVO bytes.
+        MotivoInvalido,
+        /// El usuario no tiene un cambio de datos pendiente.
+        CambioDeDatosInexistente,
+        /// No hay usuarios pendientes para procesar.
+        NoHayUsuariosPendientes,
+        /// El usuario indicado no está pendiente de aprobación.
+        UsuarioNoPendiente,
+        /// Se enviaron más cuentas de las que se pueden procesar en una sola llamada.
+        LoteDemasiadoGrande,
+        /// No existe una elección con el id indicado.
+        EleccionInexistente,
+        /// La fecha inicial no respeta ninguno de los formatos aceptados.
+        FechaInicialInvalida,
+        /// La fecha final no respeta ninguno de los formatos aceptados.
+        FechaFinalInvalida,
+        /// La fecha inicial no es anterior a la fecha final.
+        OrdenDeFechasInvalido,
+        /// La votación dura menos que DURACION_MINIMA_VOTACION.
+        DuracionInsuficiente,
+        /// La fecha inicial no es posterior al momento actual.
+        FechaEnElPasado,
+        /// La votación todavía no comenzó.
+        VotacionNoIniciada,
+        /// La votación ya fue iniciada.
+        VotacionYaIniciada,
+        /// La votación ya finalizó.
+        VotacionFinalizada,
+        /// La inscripción a la elección todavía no abrió o ya cerró para el tipo de usuario solicitado.
+        InscripcionCerrada,
+        /// La elección ya finalizó.
+        EleccionFinalizada,
+        /// La elección fue cancelada.
+        EleccionCancelada,
+        /// La elección solo se puede editar antes de que abra la inscripción.
+        EleccionNoEditable,
+        /// Las nuevas fechas de una postergación son anteriores a las actuales.
+        PostergacionInvalida,
+        /// La elección todavía no finalizó, por lo que sus datos no están disponibles.
+        EleccionNoFinalizada,
+        /// El llamador ya está inscripto en la elección.
+        YaInscriptoEnEleccion,
+        /// El llamador ya fue rechazado en la elección.
+        RechazadoEnEleccion,
+        /// No existe un candidato con el id indicado en la elección.
+        CandidatoInexistente,
+        /// El llamador no es votante de la elección.
+        NoRegistradoEnEleccion,
+        /// El llamador no es candidato de la elección.
+        NoEsCandidato,
+        /// El votante ya emitió su voto.
+        VotoYaEmitido,
+        /// La fecha de fin de revelación no respeta el formato esperado o no es posterior a la fecha final.
+        FechaRevelacionInvalida,
+        /// Alguna fecha de las ventanas de inscripción no respeta el formato esperado o las ventanas no terminan
+        /// antes de la fecha de inicio.
+        VentanaInscripcionInvalida,
+        /// Algún texto de los metadatos de la elección supera su largo máximo (LARGO_MAXIMO_TITULO, LARGO_MAXIMO_DESCRIPCION,
+        /// LARGO_MAXIMO_CARGO o LARGO_MAXIMO_JURISDICCION bytes).
+        MetadatosInvalidos,
+        /// Algún texto del perfil del candidato supera su largo máximo (LARGO_MAXIMO_PARTIDO, LARGO_MAXIMO_NOMBRE_BOLETA
+        /// o LARGO_MAXIMO_PLATAFORMA bytes).
+        PerfilCandidatoInvalido,
+        /// La elección es de voto secreto, por lo que se debe enviar el compromiso del voto.
+        EleccionConVotoSecreto,
+        /// La elección no es de voto secreto, por lo que no hay votos para comprometer ni revelar.
+        EleccionSinVotoSecreto,
+        /// La votación todavía no finalizó, por lo que no se pueden revelar los votos.
+        RevelacionNoIniciada,
+        /// El período de revelación de votos ya finalizó.
+        RevelacionFinalizada,
+        /// El votante no comprometió ningún voto en la elección.
+        VotoNoEmitido,
+        /// El votante ya reveló su voto.
+        VotoYaRevelado,
+        /// El candidato y la sal no coinciden con el compromiso enviado durante la votación.
+        CompromisoInvalido,
+        /// El tipo de voto no corresponde al sistema de votación de la elección.
+        TipoDeVotoInvalido,
+        /// Las preferencias están vacías, repiten un candidato o incluyen un candidato inexistente.
+        PreferenciasInvalidas,
+        /// Los candidatos aprobados están vacíos, se repiten o incluyen un candidato inexistente.
+        AprobacionInvalida,
+        /// Se aprobaron más candidatos de los que permite la elección.
+        DemasiadosCandidatosAprobados,
+        /// La cantidad de bancas o el máximo de candidatos a aprobar no es válido para el sistema de votación.
+        BancasInvalidas,
+        /// El delegado no es votante de la elección.
+        DelegadoNoEsVotante,
+        /// La delegación es a uno mismo o formaría un ciclo de delegaciones.
+        DelegacionCircular,
+        /// El votante delegó su voto, por lo que no puede votar ni volver a delegar.
+        VotoYaDelegado,
+        /// El votante no delegó su voto.
+        VotoNoDelegado,
+        /// El delegado ya votó, por lo que la delegación no se puede revocar.
+        DelegadoYaVoto,
+        /// Se produjo un overflow en una operación aritmética.
+        Overflow,
+    }
+
+    impl core::fmt::Display for Error
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+        {
+            let mensaje = match self
+            {
+                Error::NoEsAdministrador => "No eres el administrador.",
+                Error::NoEsGeneradorReportes => "No es generador de reportes, auditor ni administrador!",
+                Error::NoEsOperadorPadron => "No eres operador de padrón de esta elección ni administrador.",
+                Error::RolYaOtorgado => "La cuenta ya tiene ese rol.",
+                Error::RolNoOtorgado => "La cuenta no tiene ese rol.",
+                Error::RolSinAlcancePorEleccion => "Solo el rol de operador de padrón puede otorgarse para una elección.",
+                Error::UltimoAdministrador => "No se puede quitar el rol al último administrador.",
+                Error::SinTransferenciaPendiente => "No hay una transferencia de administrador pendiente.",
+                Error::NoEsAdministradorPropuesto => "No eres la cuenta propuesta como nuevo administrador.",
+                Error::TransferenciaVencida => "La propuesta de transferencia de administrador venció.",
+                Error::RequiereMultifirma => "Esta acción requiere la aprobación de varios administradores, debes proponerla.",
+                Error::UmbralInvalido => "El umbral de aprobación debe estar entre 1 y la cantidad de administradores.",
+                Error::PropuestaInexistente => "No existe una propuesta con ese id.",
+                Error::PropuestaYaConfirmada => "Ya confirmaste esta propuesta.",
+                Error::PropuestaYaEjecutada => "La propuesta ya fue ejecutada.",
+                Error::PropuestaVencida => "La propuesta venció.",
+                Error::UsuarioNoRegistrado => "No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.",
+                Error::UsuarioInexistente => "No existe un usuario con ese id.",
+                Error::RegistroNoActivado => "El registro todavía no está activado.",
+                Error::RegistroYaActivado => "El registro ya está activado.",
+                Error::RegistroYaDesactivado => "El registro ya está desactivado.",
+                Error::AdministradorNoPuedeRegistrarse => "Eres el administrador, no puedes registrarte.",
+                Error::RegistroRechazado => "Tu solicitud de registro ya fue rechazada. Puedes apelar el rechazo una vez.",
+                Error::UsuarioYaRegistrado => "Ya estás registrado como usuario.",
+                Error::UsuarioYaPendiente => "Ya estás en la cola de usuarios pendientes.",
+                Error::NombreInvalido => "El nombre y el apellido no pueden estar vacíos ni superar los 64 bytes.",
+                Error::DniInvalido => "El DNI debe tener entre 7 y 8 dígitos, sin puntos ni espacios.",
+                Error::DniYaRegistrado => "Ya hay un usuario registrado o pendiente con ese DNI.",
+                Error::RequiereCompromisoDni => "El sistema no guarda el DNI: registrate con el compromiso de tu DNI.",
+                Error::ProteccionDniNoActivada => "La protección del DNI no está activada.",
+                Error::ProteccionDniYaActivada => "La protección del DNI ya está activada.",
+                Error::ProteccionDniNoDisponible => "No se puede activar la protección del DNI porque ya hay usuarios registrados o pendientes.",
+                Error::DniNoConfirmado => "El padrón todavía no confirmó el DNI del usuario.",
+                Error::UsuarioNoRechazado => "La cuenta no está rechazada.",
+                Error::ApelacionYaPresentada => "Ya apelaste este rechazo, no puedes volver a apelarlo.",
+                Error::UsuarioBloqueado => "La cuenta fue bloqueada definitivamente.",
+                Error::MotivoInvalido => "El motivo o el descargo no pueden superar los 256 bytes.",
+                Error::CambioDeDatosInexistente => "El usuario no tiene un cambio de datos pendiente.",
+                Error::NoHayUsuariosPendientes => "No hay usuarios pendientes.",
+                Error::UsuarioNoPendiente => "El usuario no está pendiente de aprobación.",
+                Error::LoteDemasiadoGrande => "Se enviaron demasiadas cuentas para procesar en una sola llamada.",
+                Error::EleccionInexistente => "No existe una elección con ese id.",
+                Error::FechaInicialInvalida => "Error en el formato de la fecha inicial. Formatos: dd-mm-YYYY hh:mm [±hh:mm], ISO-8601 o milisegundos; sin desplazamiento se toma UTC.",
+                Error::FechaFinalInvalida => "Error en el formato de la fecha final. Formatos: dd-mm-YYYY hh:mm [±hh:mm], ISO-8601 o milisegundos; sin desplazamiento se toma UTC.",
+                Error::OrdenDeFechasInvalido => "La fecha inicial debe ser anterior a la fecha final.",
+                Error::DuracionInsuficiente => "La votación debe durar al menos una hora.",
+                Error::FechaEnElPasado => "La fecha inicial debe ser posterior al momento actual.",
+                Error::VotacionNoIniciada => "Todavía no es la fecha para la votación.",
+                Error::VotacionYaIniciada => "La votación ya inició.",
+                Error::VotacionFinalizada => "La votación ya finalizó.",
+                Error::InscripcionCerrada => "La inscripción a la elección no está abierta, no te puedes registrar.",
+                Error::EleccionFinalizada => "La elección ya finalizó.",
+                Error::EleccionCancelada => "La elección fue cancelada.",
+                Error::EleccionNoEditable => "La elección ya abrió la inscripción, no se puede editar. Solo se puede postergar o cancelar.",
+                Error::PostergacionInvalida => "Las nuevas fechas no pueden ser anteriores a las actuales.",
+                Error::EleccionNoFinalizada => "La elección no finalizó, no puedes obtener los datos.",
+                Error::YaInscriptoEnEleccion => "Ya está registrado en la elección.",
+                Error::RechazadoEnEleccion => "Ya has sido rechazado no puedes ingresar a la eleccion. Puedes apelar el rechazo una vez.",
+                Error::CandidatoInexistente => "No existe un candidato con este id.",
+                Error::NoRegistradoEnEleccion => "No estás registrado en la elección.",
+                Error::NoEsCandidato => "No sos candidato en la elección.",
+                Error::VotoYaEmitido => "No se realizó el voto porque ya votaste anteriormente.",
+                Error::FechaRevelacionInvalida => "La fecha de fin de revelación debe tener un formato válido y ser posterior a la fecha final.",
+                Error::PerfilCandidatoInvalido => "El partido, el nombre en la boleta o la plataforma del candidato son demasiado largos.",
+                Error::MetadatosInvalidos => "El título, la descripción, el cargo o la jurisdicción de la elección son demasiado largos.",
+                Error::VentanaInscripcionInvalida => "Las fechas de inscripción deben tener un formato válido, la apertura debe ser anterior a los cierres y los cierres no pueden ser posteriores a la fecha de inicio.",
+                Error::EleccionConVotoSecreto => "La elección es de voto secreto, debes enviar el compromiso de tu voto.",
+                Error::EleccionSinVotoSecreto => "La elección no es de voto secreto.",
+                Error::RevelacionNoIniciada => "La votación todavía no finalizó, no puedes revelar tu voto.",
+                Error::RevelacionFinalizada => "El período para revelar los votos ya finalizó.",
+                Error::VotoNoEmitido => "No comprometiste ningún voto en esta elección.",
+                Error::VotoYaRevelado => "Ya revelaste tu voto.",
+                Error::CompromisoInvalido => "El candidato y la sal no coinciden con el voto comprometido.",
+                Error::TipoDeVotoInvalido => "El tipo de voto no corresponde al sistema de votación de la elección.",
+                Error::PreferenciasInvalidas => "Las preferencias deben incluir al menos un candidato existente y no pueden repetir candidatos.",
+                Error::AprobacionInvalida => "Debes aprobar al menos un candidato existente y no puedes repetir candidatos.",
+                Error::DemasiadosCandidatosAprobados => "Aprobaste más candidatos de los que permite la elección.",
+                Error::BancasInvalidas => "Debe haber al menos una banca y un candidato a aprobar. La preferencia instantánea elige una sola banca.",
+                Error::DelegadoNoEsVotante => "El delegado no es votante de esta elección.",
+                Error::DelegacionCircular => "No puedes delegar tu voto en ti mismo ni en alguien que te lo delegó.",
+                Error::VotoYaDelegado => "Ya delegaste tu voto en esta elección.",
+                Error::VotoNoDelegado => "No delegaste tu voto en esta elección.",
+                Error::DelegadoYaVoto => "Tu delegado ya votó, no puedes revocar la delegación.",
+                Error::Overflow => "Se produjo un overflow.",
+            };
+            f.write_str(mensaje)
+        }
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// Resultado de procesar cada cuenta de un lote, en el mismo orden en que se enviaron.
+    pub type ResultadosPorCuenta = Vec<(AccountId, Result<()>)>;
+
+    /// Cantidad máxima de cuentas que se pueden aceptar o rechazar en una sola llamada, para no exceder el límite de gas.
+    pub const MAXIMO_USUARIOS_POR_LOTE:usize = 50;
+
+    /// Cantidad máxima de bytes del nombre y del apellido de un usuario, como los demás textos que se guardan en la cadena.
+    pub const LARGO_MAXIMO_NOMBRE:usize = 64;
+
+    /// Cantidad mínima y máxima de dígitos del DNI de un usuario.
+    pub const LARGO_MINIMO_DNI:usize = 7;
+    pub const LARGO_MAXIMO_DNI:usize = 8;
+
+    /// Cantidad de dígitos del final del DNI que se muestran en los reportes y que se conservan de un usuario dado de baja.
+    pub const DIGITOS_VISIBLES_DNI:usize = 3;
+
+    /// Duración mínima de la votación de una elección, en milisegundos: una hora.
+    pub const DURACION_MINIMA_VOTACION:u64 = 60 * 60 * 1000;
 
-    enum ERRORES
+    /// Cantidad máxima de elementos que retorna una consulta paginada. Si se pide un límite mayor se usa este.
+    pub const MAXIMO_ELEMENTOS_POR_PAGINA:u32 = 100;
+
+    /// Página de una consulta paginada.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Pagina<T>
     {
-        NO_ES_ADMINISTRADOR,
-        USUARIO_NO_REGISTRADO,
+        pub elementos:Vec<T>,
+        pub siguiente:Option<u32>, // Offset con el que se pide la página siguiente, None si es la última
     }
 
-    #[derive(scale::Decode, scale::Encode, Debug, PartialEq)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout,Clone))]
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
     pub enum TIPO_DE_USUARIO
     {
         VOTANTE,
         CANDIDATO
     }
 
-    impl ERRORES
+    /// Roles que se pueden otorgar a una cuenta. Una cuenta puede tener varios roles.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum Rol
     {
-        fn to_string(&self) -> String
-        {
-            match self 
-            {
-                ERRORES::NO_ES_ADMINISTRADOR => String::from("No eres el administrador."),
-                ERRORES::USUARIO_NO_REGISTRADO => String::from("No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.")
-            }
-        }
+        /// Crea elecciones, inicia votaciones, maneja el registro y otorga o revoca roles. Puede hacer todo lo que hacen los demás roles.
+        Administrador,
+        /// Acepta o rechaza a los usuarios pendientes del sistema y de las elecciones. Puede otorgarse para una sola elección.
+        OperadorPadron,
+        /// Consulta los datos de usuarios, votantes, candidatos y resultados, sin poder modificarlos.
+        Auditor,
+        /// Consulta los mismos datos que el auditor para generar reportes, como el contrato `reporte`.
+        GeneradorReportes,
+    }
+
+    /// Transferencia del rol de administrador que espera ser aceptada por la cuenta propuesta.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PropuestaAdministrador
+    {
+        pub proponente:AccountId, // Administrador que propuso la transferencia y pierde el rol al aceptarse
+        pub nuevo:AccountId,
+        pub vence_en_bloque:Option<u32>, // Último bloque en que se puede aceptar, None si no vence
+    }
+
+    /// Acciones que, con la aprobación multifirma activada, se ejecutan recién cuando las confirma la cantidad
+    /// de administradores indicada por el umbral.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AccionAdministrativa
+    {
+        /// Equivale a `crear_eleccion`. Las fechas se validan al ejecutarse.
+        CrearEleccion { fecha_inicial:String, fecha_final:String, configuracion:ConfiguracionEleccion },
+        /// Equivale a `iniciar_votacion`.
+        IniciarVotacion { eleccion_id:u64 },
+        /// Equivale a `transferir_administrador`: el administrador que propuso la acción es quien pierde el rol al aceptarse.
+        TransferirAdministrador { id:AccountId },
+        /// Equivale a `asignar_generador_reportes`.
+        AsignarGeneradorReportes { id:AccountId },
+        /// Equivale a `otorgar_rol`. Con la multifirma activada, los roles solo se otorgan así.
+        OtorgarRol { cuenta:AccountId, rol:Rol, eleccion_id:Option<u64> },
+        /// Equivale a `revocar_rol`. Con la multifirma activada, los roles solo se revocan así.
+        RevocarRol { cuenta:AccountId, rol:Rol, eleccion_id:Option<u64> },
+        /// Equivale a `configurar_vencimiento_transferencia`.
+        ConfigurarVencimientoTransferencia { bloques:Option<u32> },
+        /// Cambia el umbral de confirmaciones y la cantidad de bloques durante los que se puede confirmar una propuesta.
+        ConfigurarMultifirma { umbral:u32, bloques_vencimiento:u32 },
+        /// Equivale a `editar_eleccion`.
+        EditarEleccion { eleccion_id:u64, fecha_inicial:String, fecha_final:String, configuracion:ConfiguracionEleccion },
+        /// Equivale a `postergar_eleccion`.
+        PostergarEleccion { eleccion_id:u64, fecha_inicial:String, fecha_final:String, motivo:String },
+        /// Equivale a `cancelar_eleccion`.
+        CancelarEleccion { eleccion_id:u64, motivo:String },
+    }
+
+    /// Acción propuesta por un administrador que espera las confirmaciones de los demás.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PropuestaAccion
+    {
+        pub id:u32,
+        pub proponente:AccountId,
+        pub accion:AccionAdministrativa,
+        pub confirmaciones:u32, // Confirmantes que siguen siendo administradores, incluido quien la propuso
+        pub confirmantes:Vec<AccountId>, // Administradores que la confirmaron, empezando por quien la propuso
+        pub vence_en_bloque:u32, // Último bloque en que se puede confirmar
+        pub ejecutada:bool,
     }
 
+    /// Bloques durante los que se puede confirmar una propuesta si no se configuró otro valor, aproximadamente una semana con bloques de 6 segundos.
+    pub const BLOQUES_VENCIMIENTO_PROPUESTAS:u32 = 100_800;
+
     #[derive(scale::Decode, scale::Encode, Debug,Clone)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     struct Usuario
@@ -41,7 +408,293 @@ mod sistema_elecciones {
         id:AccountId,
         nombre:String,
         apellido:String,
-        dni:String,
+        dni:String, // Vacío si se guardó solo el compromiso del DNI
+        compromiso_dni:Option<Hash>, // Calculado por el usuario con su propia sal secreta
+        huella_dni:Option<Hash>, // Calculada por el padrón con su sal secreta al confirmar el DNI
+    }
+
+    impl Usuario
+    {
+        /// Retorna el DNI del usuario o, si solo se guardó su compromiso, un identificador con los primeros bytes del compromiso.
+        fn identificador_dni(&self) -> String
+        {
+            match self.compromiso_dni {
+                Some(compromiso) => {
+                    let bytes:&[u8] = compromiso.as_ref();
+                    bytes.iter().take(4).fold(String::from("#"), |mut identificador, byte| {
+                        identificador.push_str(&format!("{:02x}", byte));
+                        identificador
+                    })
+                },
+                None => self.dni.clone(),
+            }
+        }
+
+        /// Retorna una copia del usuario con el DNI enmascarado, salvo los últimos DIGITOS_VISIBLES_DNI, y sin la huella del DNI.
+        /// Es lo que se conserva de un usuario que se da de baja mientras participa de una elección ya iniciada.
+        fn dado_de_baja(&self) -> Usuario
+        {
+            let ocultos = self.dni.len().saturating_sub(DIGITOS_VISIBLES_DNI);
+            let dni = self.dni.chars().enumerate().map(|(indice, digito)| if indice < ocultos { '*' } else { digito }).collect();
+            Usuario { dni, huella_dni: None, ..self.clone() }
+        }
+    }
+
+    /// Datos de un usuario registrado o pendiente, tal como los retornan las consultas.
+    /// Si está activada la protección del DNI, `dni` tiene un identificador derivado del compromiso en lugar del DNI.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct UsuarioInfo
+    {
+        pub id:AccountId,
+        pub nombre:String,
+        pub apellido:String,
+        pub dni:String,
+    }
+
+    impl From<Usuario> for UsuarioInfo
+    {
+        fn from(usuario:Usuario) -> Self
+        {
+            let dni = usuario.identificador_dni();
+            UsuarioInfo { id: usuario.id, nombre: usuario.nombre, apellido: usuario.apellido, dni }
+        }
+    }
+
+    /// Nombre y apellido que un usuario registrado pidió usar en lugar de los actuales, pendientes de aprobación.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct CambioDeDatos
+    {
+        pub nombre:String,
+        pub apellido:String,
+    }
+
+    /// Datos de un candidato de una elección, tal como los retorna `obtener_informacion_candidato_eleccion`.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CandidatoInfo
+    {
+        pub candidato_id:u32,
+        pub id:AccountId,
+        pub nombre:String,
+        pub apellido:String,
+        pub dni:String,
+        pub perfil:PerfilCandidato,
+    }
+
+    /// Cantidad máxima de bytes de cada texto del perfil de un candidato.
+    pub const LARGO_MAXIMO_PARTIDO:usize = 128;
+    pub const LARGO_MAXIMO_NOMBRE_BOLETA:usize = 64;
+    pub const LARGO_MAXIMO_PLATAFORMA:usize = 1024;
+
+    /// Datos con los que un candidato se presenta en una elección. Los envía al solicitar el ingreso y se publican
+    /// cuando se acepta su candidatura.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PerfilCandidato
+    {
+        pub partido:String, // Partido o alianza, hasta LARGO_MAXIMO_PARTIDO bytes
+        pub numero_lista:u32,
+        pub nombre_boleta:String, // Nombre con el que aparece en la boleta, hasta LARGO_MAXIMO_NOMBRE_BOLETA bytes
+        pub plataforma:String, // Resumen de la plataforma, hasta LARGO_MAXIMO_PLATAFORMA bytes
+        pub hash_contenido:Option<Hash>, // Hash del contenido completo de la plataforma, guardado fuera de la cadena
+    }
+
+    impl PerfilCandidato
+    {
+        /// Verifica que ningún texto supere su largo máximo.
+        fn es_valido(&self) -> bool
+        {
+            self.partido.len() <= LARGO_MAXIMO_PARTIDO && self.nombre_boleta.len() <= LARGO_MAXIMO_NOMBRE_BOLETA
+                && self.plataforma.len() <= LARGO_MAXIMO_PLATAFORMA
+        }
+    }
+
+    /// Solicitud de un usuario para ingresar a una elección que espera ser aceptada o rechazada.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SolicitudPendiente
+    {
+        pub usuario:AccountId,
+        pub tipo:TIPO_DE_USUARIO,
+    }
+
+    /// Cantidad máxima de bytes del detalle de un motivo de rechazo o del descargo de una apelación.
+    pub const LARGO_MAXIMO_MOTIVO:usize = 256;
+
+    /// Código del motivo por el que se rechaza o se bloquea una solicitud.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, Default, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum CodigoRechazo
+    {
+        #[default]
+        SinEspecificar,
+        DatosIncorrectos,
+        DniNoVerificado,
+        NoHabilitado, // No cumple los requisitos para votar o ser candidato
+        Otro,
+    }
+
+    /// Motivo de un rechazo o de un bloqueo: un código y un detalle de hasta LARGO_MAXIMO_MOTIVO bytes.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Default, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct MotivoRechazo
+    {
+        pub codigo:CodigoRechazo,
+        pub detalle:String,
+    }
+
+    /// Hecho registrado en el historial de una cuenta.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AccionHistorial
+    {
+        Solicitud, // Pidió registrarse en el sistema o ingresar a la elección
+        Aceptacion,
+        Rechazo(MotivoRechazo),
+        Apelacion(String), // Descargo del usuario
+        Rehabilitacion,
+        Bloqueo(MotivoRechazo),
+        SolicitudCambioDeDatos(CambioDeDatos),
+        CambioDeDatosAceptado,
+        CambioDeDatosRechazado,
+        Retiro, // Dejó la elección antes de la votación, estando pendiente, como votante o como candidato
+        Baja, // Se dio de baja del sistema
+    }
+
+    /// Entrada del historial de una cuenta. Si `eleccion_id` es None se refiere a su registro en el sistema.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct RegistroHistorial
+    {
+        pub eleccion_id:Option<u64>,
+        pub accion:AccionHistorial,
+        pub autor:AccountId, // El propio usuario o quien procesó su solicitud
+        pub fecha:u64,
+    }
+
+    /// Ámbito de una solicitud y su cuenta: el registro en el sistema si el ámbito es None, o la elección indicada.
+    type CuentaEnAmbito = (Option<u64>, AccountId);
+
+    /// Situación de la reconsideración de un rechazo en el sistema o en una elección.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    enum Reconsideracion
+    {
+        ApelacionPresentada, // Puede volver a enviar su solicitud una vez
+        ApelacionUsada,
+        Bloqueado,
+    }
+
+    /// Forma en que los votantes eligen a los candidatos y en que se cuentan los votos.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, Default, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum SistemaVotacion
+    {
+        /// Cada votante elige un candidato y gana el que obtiene más votos.
+        #[default]
+        Mayoria,
+        /// Cada votante ordena a los candidatos por preferencia y el ganador se define por segunda vuelta instantánea:
+        /// en cada ronda se elimina al candidato con menos votos y sus boletas pasan a la siguiente preferencia.
+        PreferenciaInstantanea,
+        /// Cada votante aprueba a cualquier cantidad de candidatos y cada uno suma un voto.
+        Aprobacion,
+        /// Como la aprobación, pero cada votante puede aprobar hasta la cantidad de candidatos indicada.
+        Limitado(u32),
+    }
+
+    /// IDs de candidatos ordenados de mayor a menor preferencia.
+    pub type Preferencias = Vec<u32>;
+
+    /// Voto de un votante. Debe corresponder al sistema de votación de la elección.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Voto
+    {
+        /// Voto por un único candidato, para elecciones por mayoría.
+        Candidato(u32),
+        /// Candidatos ordenados de mayor a menor preferencia, para elecciones por preferencia instantánea.
+        /// No hace falta ordenar a todos los candidatos.
+        Preferencias(Preferencias),
+        /// Candidatos aprobados, sin orden, para elecciones por aprobación o de voto limitado.
+        Aprobacion(Vec<u32>),
+        /// Voto en blanco. Es válido en cualquier sistema de votación y no suma votos a ningún candidato.
+        EnBlanco,
+        /// Voto nulo. Es válido en cualquier sistema de votación y no suma votos a ningún candidato.
+        Nulo,
+    }
+
+    /// ID de candidato que se envía a `votar_a_candidato` para votar en blanco.
+    pub const VOTO_EN_BLANCO:u32 = 0;
+    /// ID de candidato que se envía a `votar_a_candidato` para votar nulo.
+    pub const VOTO_NULO:u32 = u32::MAX;
+
+    /// Opciones con las que se crea una elección. `ConfiguracionEleccion::default()` crea una elección por mayoría de voto público
+    /// con una sola banca.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ConfiguracionEleccion
+    {
+        /// Si es Some la elección es de voto secreto: durante la votación cada votante envía el compromiso de su voto
+        /// y, una vez pasada la fecha final, lo revela hasta esta fecha (en los formatos que acepta `crear_eleccion`).
+        pub fin_revelacion:Option<String>,
+        pub sistema_votacion:SistemaVotacion,
+        /// Cantidad de bancas a cubrir: los resultados incluyen a los candidatos más votados hasta completarlas.
+        pub bancas:u32,
+        /// Apertura de la inscripción, en los formatos que acepta `crear_eleccion`. Hasta esa fecha la elección queda en borrador.
+        /// Si es None la inscripción abre al crear la elección.
+        pub inicio_inscripcion:Option<String>,
+        /// Hasta cuándo se pueden inscribir candidatos. Si es None, hasta la fecha de inicio.
+        pub cierre_inscripcion_candidatos:Option<String>,
+        /// Hasta cuándo se pueden inscribir votantes. Si es None, hasta la fecha de inicio.
+        pub cierre_inscripcion_votantes:Option<String>,
+        /// Título, descripción y demás datos que ven los usuarios antes de ingresar a la elección.
+        pub metadatos:MetadatosEleccion,
+    }
+
+    /// Cantidad máxima de bytes de cada texto de los metadatos de una elección.
+    pub const LARGO_MAXIMO_TITULO:usize = 128;
+    pub const LARGO_MAXIMO_DESCRIPCION:usize = 1024;
+    pub const LARGO_MAXIMO_CARGO:usize = 128;
+    pub const LARGO_MAXIMO_JURISDICCION:usize = 32;
+
+    /// Datos descriptivos de una elección. No afectan la votación, solo informan a los usuarios qué se elige.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct MetadatosEleccion
+    {
+        pub titulo:String, // Hasta LARGO_MAXIMO_TITULO bytes
+        pub descripcion:String, // Hasta LARGO_MAXIMO_DESCRIPCION bytes
+        pub cargo:String, // Cargo que se elige, hasta LARGO_MAXIMO_CARGO bytes
+        pub jurisdiccion:String, // Código de jurisdicción o distrito, hasta LARGO_MAXIMO_JURISDICCION bytes
+        pub hash_documento:Option<Hash>, // Hash de un documento fuera de la cadena con las bases de la elección
+    }
+
+    impl MetadatosEleccion
+    {
+        /// Verifica que ningún texto supere su largo máximo, para no guardar datos arbitrariamente grandes en cada elección.
+        fn es_valido(&self) -> bool
+        {
+            self.titulo.len() <= LARGO_MAXIMO_TITULO && self.descripcion.len() <= LARGO_MAXIMO_DESCRIPCION
+                && self.cargo.len() <= LARGO_MAXIMO_CARGO && self.jurisdiccion.len() <= LARGO_MAXIMO_JURISDICCION
+        }
+    }
+
+    impl Default for ConfiguracionEleccion
+    {
+        fn default() -> Self
+        {
+            ConfiguracionEleccion {
+                fin_revelacion: None,
+                sistema_votacion: SistemaVotacion::default(),
+                bancas: 1,
+                inicio_inscripcion: None,
+                cierre_inscripcion_candidatos: None,
+                cierre_inscripcion_votantes: None,
+                metadatos: MetadatosEleccion::default(),
+            }
+        }
     }
 
     #[derive(scale::Decode, scale::Encode, Debug,Clone,PartialEq)]
@@ -50,6 +703,23 @@ mod sistema_elecciones {
     {
         id:AccountId,
         voto_emitido:bool,
+        compromiso:Option<Hash>, // Solo en elecciones de voto secreto: hash de (elección, votante, voto, sal)
+        voto_revelado:bool,
+        delegado:Option<AccountId>, // Votante en quien delegó su voto
+        peso:u32, // Votos que cuenta su voto: el propio más los que le delegaron, directa o indirectamente
+        indice:u32, // Su índice en votantes_por_indice
+    }
+
+    /// Votantes que delegaron su voto directamente en otro votante de la elección.
+    type Delegantes = Vec<AccountId>;
+
+    /// Boleta de una elección por preferencia instantánea.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    struct Boleta
+    {
+        preferencias:Preferencias,
+        peso:u32, // Votos que representa la boleta, por las delegaciones que recibió quien la emitió
     }
 
     #[derive(scale::Decode, scale::Encode, Debug,Clone,PartialEq)]
@@ -59,2095 +729,7019 @@ mod sistema_elecciones {
         id:AccountId,
         candidato_id:u32,
         votos_totales:u32,
+        retirado:bool, // Los candidatos retirados conservan su candidato_id, que no se reasigna
+    }
+
+    /// Posiciones de una cola FIFO cuyos elementos se guardan en un `Mapping` indexado por posición.
+    /// Los elementos vigentes son los que están en las posiciones [inicio, fin).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, Default, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    struct Cola
+    {
+        inicio:u32,
+        fin:u32,
+    }
+
+    impl Cola
+    {
+        fn esta_vacia(&self) -> bool
+        {
+            self.inicio == self.fin
+        }
+        /// Reserva la siguiente posición al final de la cola y la retorna.
+        fn encolar(&mut self) -> Result<u32>
+        {
+            let posicion = self.fin;
+            self.fin = self.fin.checked_add(1).ok_or(Error::Overflow)?;
+            Ok(posicion)
+        }
+        /// Retira la posición del primer elemento de la cola, o None si está vacía.
+        fn desencolar(&mut self) -> Option<u32>
+        {
+            if self.esta_vacia() { return None; }
+            let posicion = self.inicio;
+            self.inicio = self.inicio.checked_add(1)?;
+            Some(posicion)
+        }
+    }
+
+    /// Fase de una elección. Las fases avanzan en este orden según las fechas de la elección y nunca retroceden;
+    /// una elección cancelada queda cancelada.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum EstadoEleccion
+    {
+        /// La elección fue creada pero todavía no abrió la inscripción.
+        Borrador,
+        /// Los usuarios pueden solicitar ingresar como votantes o candidatos, cada uno dentro de su ventana de inscripción.
+        /// Dura desde la apertura de la inscripción hasta la fecha de inicio.
+        Inscripcion,
+        /// Se puede votar. Va desde la fecha de inicio hasta la fecha final, inclusive.
+        Votacion,
+        /// Pasó la fecha final pero los resultados no son definitivos: en las elecciones de voto secreto,
+        /// es el período en que se revelan los votos.
+        Escrutinio,
+        /// Los resultados son definitivos.
+        Finalizada,
+        /// La elección fue cancelada.
+        Cancelada,
     }
 
-    #[derive(scale::Decode, scale::Encode, Debug,PartialEq)]
+    /// Datos propios de una elección.
+    /// Los votantes, candidatos y usuarios pendientes o rechazados de la elección se guardan en los `Mapping`
+    /// de `SistemaElecciones` con claves `(eleccion_id, ...)`, ya que un `Mapping` no puede guardarse dentro
+    /// de un valor de otro `Mapping`. Acá solo se llevan los contadores para recorrerlos.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     struct Eleccion
     {
         id:u64,
-        candidatos:Vec<CandidatoConteo>,
-        votantes:Vec<Votante>,
-        usuarios_rechazados:Vec<AccountId>,
-        usuarios_pendientes:Vec<(AccountId,TIPO_DE_USUARIO)>,
-        votacion_iniciada:bool,
+        cantidad_candidatos:u32,
+        cantidad_votantes:u32,
+        candidatos_retirados:u32,
+        votantes_retirados:u32,
+        votos_emitidos:u32,
+        votos_delegados:u32, // Votos delegados en votantes que ya votaron, no incluye el voto propio de cada uno
+        votos_revelados:u32, // Votos revelados en elecciones de voto secreto, contando los delegados
+        votos_en_blanco:u32,
+        votos_nulos:u32,
+        cantidad_boletas:u32, // Boletas con preferencias guardadas, solo en elecciones por preferencia instantánea
+        sistema_votacion:SistemaVotacion,
+        bancas:u32,
+        cola_pendientes:Cola,
+        cantidad_rechazados:u32,
+        estado:EstadoEleccion, // Última fase guardada, ver actualizar_estado()
+        inicio_inscripcion:u64,
+        cierre_inscripcion_candidatos:u64, // Las solicitudes se aceptan mientras el timestamp sea menor al cierre
+        cierre_inscripcion_votantes:u64,
         fecha_inicio:u64,
         fecha_final:u64,
+        fecha_fin_revelacion:Option<u64>, // Some si la elección es de voto secreto
+        metadatos:MetadatosEleccion,
         resultados:Option<Resultados>
     }
 
+    /// Resumen de una elección, tal como lo retornan `listar_elecciones` y `obtener_informacion_eleccion`.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EleccionInfo
+    {
+        pub id:u64,
+        pub fecha_inicio:u64, // En milisegundos
+        pub fecha_final:u64,
+        pub fecha_fin_revelacion:Option<u64>, // Some si la elección es de voto secreto
+        pub inicio_inscripcion:u64,
+        pub cierre_inscripcion_candidatos:u64,
+        pub cierre_inscripcion_votantes:u64,
+        pub sistema_votacion:SistemaVotacion,
+        pub bancas:u32,
+        pub cantidad_candidatos:u32,
+        pub cantidad_votantes:u32,
+        pub estado:EstadoEleccion,
+        pub metadatos:MetadatosEleccion,
+    }
+
+    /// Resultados de una elección finalizada. Los campos son públicos para que otros contratos, como `reporte`, puedan leerlos.
     #[derive(scale::Decode, scale::Encode, Debug, Clone,PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct Resultados
     {
-        votos_totales:u64, // Votos totales, cuentan los que votaron y no votaron
-        votos_realizados:u64, // Votos realizados, cuentan solo los que votaron
-        votos_candidatos:Vec<(AccountId, u64)>
+        pub votos_totales:u64, // Votos totales, cuentan los que votaron y no votaron
+        pub votos_realizados:u64, // Votos realizados, cuentan solo los que votaron
+        pub votos_delegados:u64, // Votos delegados que se sumaron al voto de un delegado
+        pub votos_sin_revelar:u64, // Votos secretos que no se revelaron a tiempo, no cuentan para ningún candidato
+        pub votos_en_blanco:u64,
+        pub votos_nulos:u64,
+        pub votos_candidatos:Vec<(AccountId, u64)>, // En preferencia instantánea, los votos de la última ronda en que siguió cada candidato
+        pub rondas:Vec<Ronda>, // Solo en preferencia instantánea
+        pub ganadores:Vec<AccountId>, // Candidatos electos, de mayor a menor cantidad de votos. Pueden ser menos que las bancas si hay empate en el corte
+        pub empatados_en_corte:Vec<AccountId>, // Candidatos empatados en la última banca, hay más empatados que bancas restantes
     }
 
-    impl Eleccion
+    /// Conteo de una ronda de una elección por preferencia instantánea.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone,PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Ronda
     {
-        /// Este método verifica si hay un usuario pendiente con el ID especificado en la lista de usuarios pendientes.
-        fn contiene_usuario_pendiente(&self, id: AccountId) -> bool {
-            self.usuarios_pendientes.iter().any(|(usuario_id, _tipo)| *usuario_id == id)
-        }
+        pub votos_candidatos:Vec<(AccountId, u64)>, // Votos de cada candidato que seguía en competencia
+        pub eliminados:Vec<AccountId>, // Candidatos eliminados al terminar la ronda, vacío en la última ronda
+        pub votos_agotados:u64, // Boletas que no tienen preferencias por ningún candidato en competencia
+    }
 
-        /// Este método verifica si existe un candidato con el ID especificado dentro de la lista de candidatos disponibles.
-        fn existe_candidato(&self, candidato_id:u32) -> bool
-        {
-            candidato_id >= 1 && candidato_id <= self.candidatos.len() as u32
-        }
-        //Verifica que exista el candidato del que se esta intentando obtener la informacion
-        //En el caso de que exista retorna el candidato pasado por parametro
-        fn obtener_informacion_candidato(&self, candidato_id:u32) -> Option<&CandidatoConteo>
+    impl Eleccion
+    {
+        /// Crea una elección sin votantes, candidatos ni usuarios pendientes, con la inscripción abierta hasta la fecha de inicio.
+        fn nueva(id:u64, fecha_inicio:u64, fecha_final:u64) -> Self
         {
-            if !self.existe_candidato(candidato_id) { return None; }
-            match (candidato_id as usize).checked_sub(1) {
-                None => None,
-                Some(index) => Some(&self.candidatos[index])
+            Eleccion {
+                id,
+                cantidad_candidatos: 0,
+                cantidad_votantes: 0,
+                candidatos_retirados: 0,
+                votantes_retirados: 0,
+                votos_emitidos: 0,
+                votos_delegados: 0,
+                votos_revelados: 0,
+                votos_en_blanco: 0,
+                votos_nulos: 0,
+                cantidad_boletas: 0,
+                sistema_votacion: SistemaVotacion::default(),
+                bancas: 1,
+                cola_pendientes: Cola::default(),
+                cantidad_rechazados: 0,
+                estado: EstadoEleccion::Borrador,
+                inicio_inscripcion: 0,
+                cierre_inscripcion_candidatos: fecha_inicio,
+                cierre_inscripcion_votantes: fecha_inicio,
+                fecha_inicio,
+                fecha_final,
+                fecha_fin_revelacion: None,
+                metadatos: MetadatosEleccion::default(),
+                resultados: None,
             }
         }
-        /// Permite a un votante registrado emitir su voto por un candidato en la elección actual.
-        /// - votante_id: AccountId del votante que desea votar.
-        /// - candidato_id: Identificador único del candidato por el cual se desea emitir el voto.
-        /// Descripción:
-        /// Verifica si el candidato existe. Si no existe, retorna un error.
-        /// Verifica si el votante está registrado en la elección. Si no lo está, retorna un error.
-        /// Verifica si el votante ya ha emitido su voto. Si ya lo hizo, retorna un error.
-        /// Registra el voto del votante por el candidato especificado y actualiza los conteos.
-        /// Retorna un mensaje de éxito si el voto se emitió correctamente.
-        pub fn votar_candidato(&mut self, votante_id:AccountId, candidato_id:u32) -> Result<String, String>
-        {
-            if !self.existe_candidato(candidato_id) { return Err(String::from("No existe un candidato con este id.")); }
-
-            let votante = match self.votantes.iter_mut().find(|votante| votante.id == votante_id) {
-                Some(votante) => votante,
-                None => return Err(String::from("No estás registrado en la elección."))
-            };
-            if votante.voto_emitido { return Err(String::from("No se realizó el voto porque ya votaste anteriormente.")); }
-            votante.voto_emitido = true;
 
-            let candidato = match (candidato_id as usize).checked_sub(1) {
-                None => return Err(String::from("Se produjo un overflow intentando obtener el candidato.")),
-                Some(index) => &mut self.candidatos[index]
-            };
-            match candidato.votos_totales.checked_add(1) {
-                None => {
-                    votante.voto_emitido = false;
-                    return Err(String::from("Se produjo un overflow al intentar sumar el voto."));
-                },
-                Some(votos_totales) => {
-                    candidato.votos_totales = votos_totales;
-                    return Ok(String::from("Voto emitido exitosamente."));
-                }
-            }
+        fn es_voto_secreto(&self) -> bool
+        {
+            self.fecha_fin_revelacion.is_some()
         }
 
-        /// Usado por el administrador.
-        /// Procesa el siguiente usuario pendiente, aceptándolo o rechazándolo según el parámetro aceptar_usuario.
-        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario pendiente.
-        /// Result<String, String>: Mensaje de éxito indicando si el usuario fue agregado o rechazado correctamente, o un mensaje de error si no hay usuarios pendientes.
-        /// Descripción:
-        /// Verifica si hay usuarios pendientes. Si no hay, retorna un error indicando que no hay usuarios pendientes.
-        /// Si se acepta al usuario, lo añade a la lista correspondiente (votantes o candidatos) según su tipo. Retorna un mensaje de éxito indicando la operación realizada.
-        /// Si se rechaza al usuario, lo añade a la lista de usuarios rechazados. Retorna un mensaje de éxito indicando que el usuario fue rechazado.
-
-        pub fn procesar_siguiente_usuario_pendiente(&mut self, aceptar_usuario:bool) -> Result<String, String>
-        {
-            let sig_usuario = self.usuarios_pendientes.first();
-            if sig_usuario.is_none() { return Err(String::from("No hay usuarios pendientes.")); }
-
-            let (usuario, tipo) = self.usuarios_pendientes.remove(0);
-            if aceptar_usuario { 
-                match tipo {
-                    TIPO_DE_USUARIO::VOTANTE =>{
-                    self.votantes.push(Votante{
-                        id:usuario,
-                        voto_emitido:false,
-                    });
-
-                   },
-                   TIPO_DE_USUARIO::CANDIDATO=>{
-
-                    let candidato_id = match (self.candidatos.len() as u32).checked_add(1) {
-                        Some(id_validado) => id_validado,
-                        None => return Err(String::from("Ocurrio un overflow al calcular la ID del candidato.")),
-                    };
-                    self.candidatos.push(CandidatoConteo{
-                        id:usuario,
-                        candidato_id,
-                        votos_totales:0,
-                    });
-                   },
+        /// Fase que corresponde a la elección en el momento indicado según sus fechas, sin tener en cuenta la fase guardada.
+        fn estado_por_fecha(&self, ahora:u64) -> EstadoEleccion
+        {
+            if ahora > self.fecha_final {
+                match self.fecha_fin_revelacion {
+                    Some(fin_revelacion) if ahora < fin_revelacion => EstadoEleccion::Escrutinio,
+                    _ => EstadoEleccion::Finalizada,
                 }
-                return Ok(String::from("Usuario agregado exitosamente."));
-            }
-            else{
-                self.usuarios_rechazados.push(usuario);
-                return Ok(String::from("Usuario rechazado exitosamente."));
+            } else if ahora >= self.fecha_inicio {
+                EstadoEleccion::Votacion
+            } else if ahora >= self.inicio_inscripcion {
+                EstadoEleccion::Inscripcion
+            } else {
+                EstadoEleccion::Borrador
             }
         }
-        /// Obtiene los resultados de la votación si están disponibles.
-        /// - block_timestamp: u64 - Marca de tiempo del bloque para verificar si ya se pueden obtener los resultados.
-        /// Retorno:
-        /// Option<&Resultados>: Resultados de la votación si están disponibles, o None si no lo están.
-        /// Descripción:
-        /// Verifica si la fecha final de la elección es mayor que el block_timestamp. Si es así, retorna None ya que los resultados aún no están disponibles.
-        /// Si self.resultados tiene algún valor, devuelve una referencia a esos resultados.
-        /// Si no hay resultados previos, calcula los resultados contando los votos totales y realizados de los votantes, y los votos totales de cada candidato.
- 
-        fn obtener_resultados_votacion(&mut self, block_timestamp:u64) -> Option<&Resultados>
-        {
-            if self.fecha_final > block_timestamp {
-                return None;
-            }
 
-            if self.resultados.is_some() {
-                return self.resultados.as_ref();
+        /// Momento en que cierra la inscripción para el tipo de usuario indicado.
+        fn cierre_inscripcion(&self, tipo:&TIPO_DE_USUARIO) -> u64
+        {
+            match tipo {
+                TIPO_DE_USUARIO::VOTANTE => self.cierre_inscripcion_votantes,
+                TIPO_DE_USUARIO::CANDIDATO => self.cierre_inscripcion_candidatos,
             }
-
-            let mut resultados = Resultados { 
-                votos_totales: 0, 
-                votos_realizados: 0,
-                votos_candidatos: Vec::new(),
-            };
-
-            resultados.votos_totales = self.votantes.len() as u64;
-            resultados.votos_realizados = self.votantes.iter().filter(|v| v.voto_emitido).count() as u64;
-            self.candidatos.iter().for_each(|c| {
-                resultados.votos_candidatos.push((c.id, c.votos_totales as u64));
-            });
-
-            self.resultados = Some(resultados);
-            return self.resultados.as_ref();
         }
-    }
-
-    #[ink(storage)]
-    pub struct SistemaElecciones {
-        administrador:AccountId,
-        generador_reportes:Option<AccountId>,
-        registro_activado:bool,
-        usuarios:Vec<Usuario>,
-        usuarios_pendientes:Vec<Usuario>,
-        usuarios_rechazados:Vec<AccountId>,
-        elecciones:Vec<Eleccion>,
-    }
 
-    #[ink(impl)]
-    impl SistemaElecciones {
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self { 
-                administrador: Self::env().caller(),
-                generador_reportes: None,
-                registro_activado: false,
-                usuarios: Vec::new(),
-                usuarios_pendientes: Vec::new(),
-                usuarios_rechazados: Vec::new(),
-                elecciones: Vec::new(),
-            }
-        }
-        //Verifica que el caller sea el generador de reportes
-        fn es_generador_reportes(&self) -> bool
+        /// Única función que cambia la fase de la elección: la avanza hasta la que corresponde según las fechas y la retorna.
+        /// La fase guardada nunca retrocede, así que una votación iniciada sigue iniciada y una elección cancelada sigue cancelada.
+        /// Quien llama debe guardar la elección si quiere conservar el cambio.
+        fn actualizar_estado(&mut self, ahora:u64) -> EstadoEleccion
         {
-            match self.generador_reportes { 
-                None => false,
-                Some(val) => self.env().caller() == val
+            if self.estado != EstadoEleccion::Cancelada {
+                self.estado = self.estado.max(self.estado_por_fecha(ahora));
             }
+            self.estado
         }
-        //Verifica que el caller sea el administrador del sistema
-        fn es_administrador(&self) -> bool
-        {
-            self.env().caller() == self.administrador
-        }
-        //Dado un id retorna Option de un usuario
-        fn obtener_usuario(&self, id: AccountId) -> Option<&Usuario> 
+
+        /// Pasa la elección a la fase Cancelada, de la que ya no sale.
+        fn cancelar(&mut self)
         {
-            self.usuarios.iter().find(|usuario| usuario.id == id)
+            self.estado = EstadoEleccion::Cancelada;
         }
-        //Verifica que el adress del que hace la llamada al contrato sea un usuario registrado
-        fn es_usuario_registrado(&self) -> bool
+
+        /// Fase de la elección en el momento indicado, sin modificar la fase guardada.
+        fn estado_en(&self, ahora:u64) -> EstadoEleccion
         {
-            let id = self.env().caller();
-            self.usuarios.iter().any(|usuario| usuario.id == id)
+            self.clone().actualizar_estado(ahora)
         }
-        //Verifica que el adress del que hace la llamada al contrato sea un usuario pendiente
-        fn es_usuario_pendiente(&self) -> bool
+
+        /// Resumen de la elección con su fase en el momento indicado.
+        fn informacion(&self, ahora:u64) -> EleccionInfo
         {
-            let id=self.env().caller();
-            self.usuarios_pendientes.iter().any(|usuario| usuario.id == id)
+            EleccionInfo {
+                id: self.id,
+                fecha_inicio: self.fecha_inicio,
+                fecha_final: self.fecha_final,
+                fecha_fin_revelacion: self.fecha_fin_revelacion,
+                inicio_inscripcion: self.inicio_inscripcion,
+                cierre_inscripcion_candidatos: self.cierre_inscripcion_candidatos,
+                cierre_inscripcion_votantes: self.cierre_inscripcion_votantes,
+                sistema_votacion: self.sistema_votacion,
+                bancas: self.bancas,
+                cantidad_candidatos: self.candidatos_activos(),
+                cantidad_votantes: self.votantes_activos(),
+                estado: self.estado_en(ahora),
+                metadatos: self.metadatos.clone(),
+            }
         }
-        //Verifica que la eleccion pasa por parametro exista y retorna un booleano
-        fn existe_eleccion(&self, eleccion_id:u64) -> bool
+
+        /// Cantidad de candidatos aceptados que no se retiraron.
+        fn candidatos_activos(&self) -> u32
         {
-            eleccion_id >= 1 && eleccion_id <= self.elecciones.len() as u64
+            self.cantidad_candidatos.saturating_sub(self.candidatos_retirados)
         }
 
-        /// Obtiene una referencia mutable a una elección por su ID.
-        /// - eleccion_id: u64 - ID de la elección a obtener.
-        /// Option<&mut Eleccion>: Referencia mutable a la elección si existe, o None si no se encuentra.
-        /// Descripción:
-        /// Verifica si existe una elección con el ID proporcionado utilizando el método existe_eleccion().
-        /// Si la elección existe, calcula el índice válido (ajustado para el acceso a vector) y retorna una referencia mutable a la elección.
-        /// Retorna None si no se encuentra ninguna elección con el ID especificado.
-        fn obtener_eleccion_por_id(&mut self, eleccion_id:u64) -> Option<&mut Eleccion>
-        {
-            if self.existe_eleccion(eleccion_id) {
-                match eleccion_id.checked_sub(1) {
-                    Some(index_valid) => return Some(&mut self.elecciones[index_valid as usize]),
-                    None => return None
-                }
-            }
-            return None;
-        }
-        /// Obtiene una referencia inmutable a una elección por su ID.
-        /// - eleccion_id: u64 - ID de la elección a obtener.
-        /// Option<&Eleccion>: Referencia inmutable a la elección si existe, o None si no se encuentra.
-        /// Descripción:
-        /// Verifica si existe una elección con el ID proporcionado utilizando el método existe_eleccion().
-        /// Si la elección existe, calcula el índice válido (ajustado para el acceso a vector) y retorna una referencia inmutable a la elección.
-        /// Retorna None si no se encuentra ninguna elección con el ID especificado.
-        fn obtener_ref_eleccion_por_id(&self, eleccion_id:u64) -> Option<&Eleccion>
-        {
-            if self.existe_eleccion(eleccion_id) {
-                match eleccion_id.checked_sub(1) {
-                    Some(index_valid) => return Some(&self.elecciones[index_valid as usize]),
-                    None => return None
-                }
-            }
-            return None;
-        }
-        /// Valida el estado de una elección antes de permitir que un usuario se registre en ella.
-        /// - eleccion_id: u64 - ID de la elección a validar.
-        /// - block_timestamp: u64 - Timestamp del bloque actual.
-        /// - id_usuario: AccountId - ID del usuario que desea registrarse en la elección.
-        /// Result<&mut Eleccion, String>: Referencia mutable a la elección válida o mensaje de error.
-        /// Descripción:
-        /// Verifica si existe una elección con el ID proporcionado. Si no existe, retorna un error indicando que no existe una elección con ese ID.
-        /// Obtiene la elección y verifica si el usuario ya está registrado como pendiente en ella. Si lo está, retorna un error indicando que ya está registrado en la elección.
-        /// Verifica si la votación en la elección ya ha iniciado o si la fecha de inicio es menor al timestamp del bloque actual. Si es así, retorna un error indicando que la votación ya comenzó y no se puede registrar.
-        /// Verifica si la fecha de finalización de la elección es menor al timestamp del bloque actual. Si es así, retorna un error indicando que la elección ya finalizó y no se puede registrar.
-        /// Retorna una referencia mutable a la elección válida si todas las validaciones pasan.
-        fn validar_estado_eleccion(&mut self,eleccion_id:u64,block_timestamp:u64,id_usuario:AccountId) -> Result<&mut Eleccion,String>
+        /// Cantidad de votantes aceptados que no se retiraron.
+        fn votantes_activos(&self) -> u32
         {
-            let option_eleccion = self.obtener_eleccion_por_id(eleccion_id);
-            if option_eleccion.is_none() { return Err(String::from("No existe una elección con ese id.")); }
-            
-            let eleccion = option_eleccion.unwrap();
-            if eleccion.contiene_usuario_pendiente(id_usuario) { return Err(String::from("Ya está registrado en la elección.")); }
-            
-            if eleccion.votacion_iniciada || eleccion.fecha_inicio < block_timestamp {
-                return Err(String::from("La votación en la elección ya comenzó, no te puedes registrar."));
-            }
-            if eleccion.fecha_final < block_timestamp {
-                return Err(String::from("La elección ya finalizó, no te puedes registrar."));
-            }
-            Ok(eleccion)
+            self.cantidad_votantes.saturating_sub(self.votantes_retirados)
         }
+    }
 
-        // ====-----==== METODOS PÚBLICOS ====----====
-        // ====-----==== METODOS PÚBLICOS ====----====
-        // ====-----==== METODOS PÚBLICOS ====----====
+    // ====-----==== EVENTOS ====----====
 
-    
-        /// Registra un nuevo usuario en el sistema.
-        /// - nombre: String - Nombre del usuario a registrar.
-        /// - apellido: String - Apellido del usuario a registrar.
-        /// - dni: String - DNI del usuario a registrar.
-        /// Descripción:
-        /// Verifica si el registro está activado. Si no lo está, retorna un error indicando que el registro no está activado.
-        /// Verifica si el llamador es el administrador. Si es así, retorna un error indicando que el administrador no puede registrarse.
-        /// Verifica si el llamador ya ha sido rechazado anteriormente. Si es así, retorna un error indicando que la solicitud de registro ya fue rechazada.
-        /// Verifica si el llamador ya está registrado como usuario. Si es así, retorna un error indicando que ya está registrado.
-        /// Verifica si el llamador ya está en la cola de usuarios pendientes. Si es así, retorna un error indicando que ya está en la cola de usuarios pendientes.
-        /// Registra al usuario añadiéndolo a la cola de usuarios pendientes y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn registrarse(&mut self, nombre:String, apellido:String, dni:String) -> Result<String, String>
-        {
-            self.registrarse_privado(nombre, apellido, dni)
+    /// Un usuario solicitó registrarse y quedó en la cola de usuarios pendientes.
+    #[ink(event)]
+    pub struct UsuarioRegistrado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+    }
+
+    /// El administrador aceptó a un usuario pendiente.
+    /// Si `eleccion_id` es None fue aceptado en el sistema, si no fue aceptado en esa elección con el tipo indicado.
+    #[ink(event)]
+    pub struct UsuarioAceptado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+        tipo:Option<TIPO_DE_USUARIO>,
+    }
+
+    /// El administrador rechazó a un usuario pendiente.
+    /// Si `eleccion_id` es None fue rechazado en el sistema, si no fue rechazado en esa elección.
+    #[ink(event)]
+    pub struct UsuarioRechazado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+        codigo:CodigoRechazo,
+    }
+
+    /// Un usuario rechazado apeló el rechazo y puede volver a enviar su solicitud.
+    /// Si `eleccion_id` es None apeló el rechazo en el sistema, si no el rechazo en esa elección.
+    #[ink(event)]
+    pub struct RechazoApelado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+    }
+
+    /// Un administrador rehabilitó a un usuario rechazado.
+    #[ink(event)]
+    pub struct UsuarioRehabilitado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+    }
+
+    /// Un administrador bloqueó definitivamente a un usuario rechazado.
+    #[ink(event)]
+    pub struct UsuarioBloqueado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+        codigo:CodigoRechazo,
+    }
+
+    /// Un usuario registrado pidió cambiar su nombre y apellido.
+    #[ink(event)]
+    pub struct CambioDeDatosSolicitado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+    }
+
+    /// Un operador de padrón o administrador aceptó o rechazó el cambio de datos de un usuario.
+    #[ink(event)]
+    pub struct CambioDeDatosProcesado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        aceptado:bool,
+    }
+
+    /// Un usuario se dio de baja del sistema.
+    #[ink(event)]
+    pub struct UsuarioDadoDeBaja
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+    }
+
+    /// Un usuario dejó una elección antes de la votación, con el tipo con el que estaba pendiente o inscripto.
+    #[ink(event)]
+    pub struct RetiradoDeEleccion
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:u64,
+        tipo:TIPO_DE_USUARIO,
+    }
+
+    /// Un usuario registrado solicitó ingresar a una elección y quedó pendiente de aprobación.
+    #[ink(event)]
+    pub struct IngresoAEleccionSolicitado
+    {
+        #[ink(topic)]
+        usuario:AccountId,
+        #[ink(topic)]
+        eleccion_id:u64,
+        tipo:TIPO_DE_USUARIO,
+    }
+
+    /// El administrador creó una nueva elección.
+    #[ink(event)]
+    pub struct EleccionCreada
+    {
+        #[ink(topic)]
+        eleccion_id:u64,
+        fecha_inicio:u64,
+        fecha_final:u64,
+        voto_secreto:bool,
+        sistema_votacion:SistemaVotacion,
+        bancas:u32,
+        titulo:String,
+    }
+
+    /// El administrador editó una elección en borrador.
+    #[ink(event)]
+    pub struct EleccionEditada
+    {
+        #[ink(topic)]
+        eleccion_id:u64,
+        fecha_inicio:u64,
+        fecha_final:u64,
+    }
+
+    /// El administrador postergó una elección.
+    #[ink(event)]
+    pub struct EleccionPostergada
+    {
+        #[ink(topic)]
+        eleccion_id:u64,
+        fecha_inicio:u64,
+        fecha_final:u64,
+        motivo:String,
+    }
+
+    /// El administrador canceló una elección.
+    #[ink(event)]
+    pub struct EleccionCancelada
+    {
+        #[ink(topic)]
+        eleccion_id:u64,
+        motivo:String,
+    }
+
+    /// Se inició la votación de una elección, ya sea por el administrador o por el primer voto dentro del período.
+    #[ink(event)]
+    pub struct VotacionIniciada
+    {
+        #[ink(topic)]
+        eleccion_id:u64,
+    }
+
+    /// Un votante emitió su voto, o su compromiso si la elección es de voto secreto. No incluye el candidato elegido para no revelar el voto.
+    #[ink(event)]
+    pub struct VotoEmitido
+    {
+        #[ink(topic)]
+        votante:AccountId,
+        #[ink(topic)]
+        eleccion_id:u64,
+    }
+
+    /// Un votante reveló el voto que había comprometido en una elección de voto secreto.
+    #[ink(event)]
+    pub struct VotoRevelado
+    {
+        #[ink(topic)]
+        votante:AccountId,
+        #[ink(topic)]
+        eleccion_id:u64,
+    }
+
+    /// Un votante delegó su voto en otro votante de la misma elección.
+    #[ink(event)]
+    pub struct VotoDelegado
+    {
+        #[ink(topic)]
+        votante:AccountId,
+        #[ink(topic)]
+        delegado:AccountId,
+        #[ink(topic)]
+        eleccion_id:u64,
+    }
+
+    /// Un votante revocó la delegación de su voto y puede volver a votar.
+    #[ink(event)]
+    pub struct DelegacionRevocada
+    {
+        #[ink(topic)]
+        votante:AccountId,
+        #[ink(topic)]
+        eleccion_id:u64,
+    }
+
+    /// Se transfirió el rol de administrador: la cuenta propuesta aceptó la transferencia.
+    #[ink(event)]
+    pub struct AdministradorTransferido
+    {
+        #[ink(topic)]
+        anterior:AccountId,
+        #[ink(topic)]
+        nuevo:AccountId,
+    }
+
+    /// Se asignó un nuevo generador de reportes. Se emite junto con RolOtorgado cada vez que se otorga ese rol.
+    #[ink(event)]
+    pub struct GeneradorReportesAsignado
+    {
+        #[ink(topic)]
+        generador_reportes:AccountId,
+    }
+
+    /// Se otorgó un rol a una cuenta. Si `eleccion_id` es Some, el rol vale solo para esa elección.
+    #[ink(event)]
+    pub struct RolOtorgado
+    {
+        #[ink(topic)]
+        cuenta:AccountId,
+        rol:Rol,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+    }
+
+    /// Un administrador propuso transferir su rol a otra cuenta, que debe aceptarlo.
+    #[ink(event)]
+    pub struct TransferenciaAdministradorPropuesta
+    {
+        #[ink(topic)]
+        proponente:AccountId,
+        #[ink(topic)]
+        nuevo:AccountId,
+        vence_en_bloque:Option<u32>,
+    }
+
+    /// Se canceló la transferencia de administrador pendiente.
+    #[ink(event)]
+    pub struct TransferenciaAdministradorCancelada
+    {
+        #[ink(topic)]
+        proponente:AccountId,
+        #[ink(topic)]
+        nuevo:AccountId,
+    }
+
+    /// Se quitó un rol a una cuenta, ya sea porque un administrador lo revocó o porque la cuenta renunció a él.
+    #[ink(event)]
+    pub struct RolRevocado
+    {
+        #[ink(topic)]
+        cuenta:AccountId,
+        rol:Rol,
+        #[ink(topic)]
+        eleccion_id:Option<u64>,
+        renuncia:bool,
+    }
+
+    /// Un administrador propuso una acción que requiere aprobación multifirma. Su propuesta cuenta como la primera confirmación.
+    #[ink(event)]
+    pub struct AccionPropuesta
+    {
+        #[ink(topic)]
+        propuesta_id:u32,
+        #[ink(topic)]
+        proponente:AccountId,
+        accion:AccionAdministrativa,
+        vence_en_bloque:u32,
+    }
+
+    /// Un administrador confirmó una propuesta pendiente.
+    #[ink(event)]
+    pub struct AccionConfirmada
+    {
+        #[ink(topic)]
+        propuesta_id:u32,
+        #[ink(topic)]
+        confirmante:AccountId,
+        confirmaciones:u32,
+    }
+
+    /// Una propuesta alcanzó el umbral de confirmaciones y se ejecutó su acción.
+    #[ink(event)]
+    pub struct AccionEjecutada
+    {
+        #[ink(topic)]
+        propuesta_id:u32,
+    }
+
+    /// Los usuarios y las elecciones se guardan en `Mapping`s para no tener que decodificar todo el registro en cada llamada.
+    /// Las colas de usuarios pendientes se guardan por posición (ver `Cola`) para mantener el orden de llegada.
+    /// Los roles de cada cuenta se guardan en un `Mapping` por (cuenta, rol), así que una cuenta puede tener varios roles
+    /// y cada rol puede tener varias cuentas.
+    /// Con un umbral de aprobación mayor a 1, las acciones críticas se proponen y se guardan en `propuestas` hasta que
+    /// las confirman suficientes administradores.
+    #[ink(storage)]
+    pub struct SistemaElecciones {
+        roles:Mapping<(AccountId, Rol), ()>, // Roles que valen para todo el sistema
+        operadores_eleccion:Mapping<(u64, AccountId), ()>, // (eleccion_id, cuenta): operadores de padrón de una sola elección
+        cantidad_administradores:u32,
+        transferencia_administrador:Option<PropuestaAdministrador>,
+        bloques_vencimiento_transferencia:Option<u32>, // Bloques durante los que se puede aceptar una transferencia, None si no vencen
+        umbral_aprobacion:u32, // Confirmaciones necesarias para ejecutar una acción crítica, 1 si no se usa la multifirma
+        bloques_vencimiento_propuestas:u32,
+        propuestas:Mapping<u32, PropuestaAccion>,
+        cantidad_propuestas:u32,
+        registro_activado:bool,
+        usuarios:Mapping<AccountId, Usuario>,
+        usuarios_pendientes:Mapping<AccountId, Usuario>,
+        cambios_de_datos_pendientes:Mapping<AccountId, CambioDeDatos>,
+        cola_usuarios_pendientes:Mapping<u32, AccountId>,
+        posiciones_usuarios_pendientes:Cola,
+        posiciones_en_cola_usuarios:Mapping<AccountId, u32>, // Usuario pendiente -> su posición en cola_usuarios_pendientes
+        usuarios_rechazados:Mapping<AccountId, u32>, // Usuario -> índice de su último rechazo en usuarios_rechazados_por_indice
+        cuentas_por_dni:Mapping<String, AccountId>, // DNI -> usuario registrado o pendiente que lo declaró
+        proteccion_dni:bool,
+        cuentas_por_huella_dni:Mapping<Hash, AccountId>, // Huella del DNI confirmada por el padrón -> usuario registrado o pendiente
+        usuarios_por_indice:Mapping<u32, AccountId>, // Índice -> usuario registrado, para listarlos
+        indices_usuarios:Mapping<AccountId, u32>, // Usuario registrado -> su índice en usuarios_por_indice
+        usuarios_dados_de_baja:Mapping<AccountId, Usuario>, // Nombre y DNI enmascarado de quien se dio de baja participando de una elección iniciada
+        cantidad_usuarios:u32,
+        usuarios_rechazados_por_indice:Mapping<u32, AccountId>,
+        cantidad_usuarios_rechazados:u32,
+        elecciones:Mapping<u64, Eleccion>,
+        cantidad_elecciones:u64,
+        votantes:Mapping<(u64, AccountId), Votante>, // (eleccion_id, votante) -> Votante
+        votantes_por_indice:Mapping<(u64, u32), AccountId>, // (eleccion_id, índice) -> votante, para recorrer los votantes de una elección
+        delegantes:Mapping<(u64, AccountId), Delegantes>, // (eleccion_id, votante) -> votantes que delegaron directamente en él
+        elecciones_de_usuario:Mapping<AccountId, Vec<u64>>, // Elecciones en las que el usuario pidió ingresar y de las que no se retiró
+        candidatos:Mapping<(u64, u32), CandidatoConteo>, // (eleccion_id, candidato_id) -> CandidatoConteo
+        candidatos_por_cuenta:Mapping<(u64, AccountId), u32>, // (eleccion_id, candidato) -> candidato_id
+        perfiles_candidatos:Mapping<(u64, AccountId), PerfilCandidato>, // Perfil de los candidatos aceptados y de las candidaturas pendientes
+        boletas:Mapping<(u64, u32), Boleta>, // (eleccion_id, índice) -> boleta, sin guardar quién la emitió
+        pendientes_eleccion:Mapping<(u64, AccountId), TIPO_DE_USUARIO>,
+        cola_pendientes_eleccion:Mapping<(u64, u32), AccountId>,
+        posiciones_pendientes_eleccion:Mapping<(u64, AccountId), u32>, // (eleccion_id, usuario pendiente) -> su posición en cola_pendientes_eleccion
+        rechazados_eleccion:Mapping<(u64, AccountId), u32>, // (eleccion_id, usuario) -> índice de su último rechazo en la elección
+        reconsideraciones:Mapping<CuentaEnAmbito, Reconsideracion>, // Apelación o bloqueo del rechazo en el sistema (None) o en una elección
+        historial_usuarios:Mapping<(AccountId, u32), RegistroHistorial>,
+        cantidad_historial_usuarios:Mapping<AccountId, u32>,
+        rechazados_eleccion_por_indice:Mapping<(u64, u32), AccountId>, // (eleccion_id, índice) -> usuario rechazado
+    }
+
+    impl Default for SistemaElecciones {
+        fn default() -> Self {
+            Self::new()
         }
-        fn registrarse_privado(&mut self, nombre:String, apellido:String, dni:String) -> Result<String, String>
-        {
-            if !self.registro_activado { return Err(String::from("El registro todavía no está activado.")); }
-            let id = self.env().caller();
-            if self.es_administrador() { return Err(String::from("Eres el administrador, no puedes registrarte.")); }
-            if self.usuarios_rechazados.contains(&id) { 
-                return Err(String::from("Tu solicitud de registro ya fue rechazada."));
-            }
-            if self.es_usuario_registrado()
-            {
-                return Err(String::from("Ya estás registrado como usuario."));
-            }
-            if self.es_usuario_pendiente()
-            {
-                return Err(String::from("Ya estás en la cola de usuarios pendientes."));    
+    }
+
+    #[ink(impl)]
+    impl SistemaElecciones {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut roles = Mapping::default();
+            roles.insert((Self::env().caller(), Rol::Administrador), &());
+            Self { 
+                roles,
+                operadores_eleccion: Mapping::default(),
+                cantidad_administradores: 1,
+                transferencia_administrador: None,
+                bloques_vencimiento_transferencia: None,
+                umbral_aprobacion: 1,
+                bloques_vencimiento_propuestas: BLOQUES_VENCIMIENTO_PROPUESTAS,
+                propuestas: Mapping::default(),
+                cantidad_propuestas: 0,
+                registro_activado: false,
+                usuarios: Mapping::default(),
+                usuarios_pendientes: Mapping::default(),
+                cambios_de_datos_pendientes: Mapping::default(),
+                cola_usuarios_pendientes: Mapping::default(),
+                posiciones_usuarios_pendientes: Cola::default(),
+                posiciones_en_cola_usuarios: Mapping::default(),
+                usuarios_rechazados: Mapping::default(),
+                cuentas_por_dni: Mapping::default(),
+                proteccion_dni: false,
+                cuentas_por_huella_dni: Mapping::default(),
+                usuarios_por_indice: Mapping::default(),
+                indices_usuarios: Mapping::default(),
+                usuarios_dados_de_baja: Mapping::default(),
+                cantidad_usuarios: 0,
+                usuarios_rechazados_por_indice: Mapping::default(),
+                cantidad_usuarios_rechazados: 0,
+                elecciones: Mapping::default(),
+                cantidad_elecciones: 0,
+                votantes: Mapping::default(),
+                votantes_por_indice: Mapping::default(),
+                delegantes: Mapping::default(),
+                elecciones_de_usuario: Mapping::default(),
+                candidatos: Mapping::default(),
+                candidatos_por_cuenta: Mapping::default(),
+                perfiles_candidatos: Mapping::default(),
+                boletas: Mapping::default(),
+                pendientes_eleccion: Mapping::default(),
+                cola_pendientes_eleccion: Mapping::default(),
+                posiciones_pendientes_eleccion: Mapping::default(),
+                rechazados_eleccion: Mapping::default(),
+                reconsideraciones: Mapping::default(),
+                historial_usuarios: Mapping::default(),
+                cantidad_historial_usuarios: Mapping::default(),
+                rechazados_eleccion_por_indice: Mapping::default(),
             }
-            let usuario = Usuario { id, nombre, apellido, dni };
-            self.usuarios_pendientes.push(usuario);
-            return Ok(String::from("Registro exitoso. Se te añadió en la cola de usuarios pendientes."));
         }
-        
-        // ====-----==== METODOS ADMINISTRADOR ====----====
-
-        /// Obtiene la información del siguiente usuario pendiente en la lista.
-        /// Result<String, String>: Mensaje de éxito con la información del usuario o error si no hay usuarios pendientes.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Obtiene al siguiente usuario pendiente en la lista de usuarios pendientes.
-        /// Retorna un mensaje de éxito con el nombre, apellido y DNI del usuario pendiente si existe.
-        /// Si no hay usuarios pendientes, retorna un error indicando que no hay usuarios pendientes.
-        #[ink(message)]
-        pub fn obtener_informacion_siguiente_usuario_pendiente(&self) -> Result<String, String>
+        //Verifica que la cuenta tenga el rol en todo el sistema
+        fn tiene_rol(&self, cuenta:AccountId, rol:Rol) -> bool
         {
-            self.obtener_informacion_siguiente_usuario_pendiente_privado()
+            self.roles.contains((cuenta, rol))
         }
-        fn obtener_informacion_siguiente_usuario_pendiente_privado(&self) -> Result<String, String>
+        //Verifica que el caller pueda consultar los datos para reportes: generador de reportes, auditor o administrador
+        fn puede_consultar_reportes(&self) -> bool
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            let sig_usuario = self.usuarios_pendientes.first();
-            match sig_usuario {
-                Some(usuario) => {
-                    let mut str = String::from("Nombre: ") + usuario.nombre.as_str();
-                    str.push_str((String::from("\nApellido: ") + usuario.apellido.as_str()).as_str());
-                    str.push_str((String::from("\nDNI: ") + usuario.dni.as_str()).as_str());
-                    return Ok(str);
-                },
-                None => Err(String::from("No hay usuarios pendientes.")),
-            }
+            let caller = self.env().caller();
+            self.tiene_rol(caller, Rol::GeneradorReportes) || self.tiene_rol(caller, Rol::Auditor) || self.tiene_rol(caller, Rol::Administrador)
         }
-        /// Procesa el siguiente usuario pendiente, aceptándolo o rechazándolo.
-        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario pendiente.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Obtiene al siguiente usuario pendiente en la lista. Si no hay usuarios pendientes, retorna un error.
-        /// Si se acepta al usuario, lo mueve desde la lista de usuarios pendientes a la lista de usuarios.
-        /// Si se rechaza al usuario, lo agrega a la lista de usuarios rechazados.
-        /// Retorna un mensaje de éxito indicando si el usuario fue agregado o rechazado exitosamente.
-        #[ink(message)]
-        pub fn procesar_siguiente_usuario_pendiente(&mut self, aceptar_usuario:bool) -> Result<String, String>
+        //Verifica que el caller pueda procesar usuarios pendientes: administrador, operador de padrón de todo el sistema,
+        //o de la elección indicada si eleccion_id es Some
+        fn es_operador_padron(&self, eleccion_id:Option<u64>) -> bool
         {
-            self.procesar_siguiente_usuario_pendiente_privado(aceptar_usuario)
+            let caller = self.env().caller();
+            self.tiene_rol(caller, Rol::Administrador)
+                || self.tiene_rol(caller, Rol::OperadorPadron)
+                || eleccion_id.is_some_and(|eleccion_id| self.operadores_eleccion.contains((eleccion_id, caller)))
         }
-        fn procesar_siguiente_usuario_pendiente_privado(&mut self, aceptar_usuario:bool) -> Result<String, String>
+        //Verifica que el caller pueda listar los usuarios de todo el sistema, o de la elección indicada si eleccion_id es Some:
+        //operador de padrón o alguno de los roles que consultan reportes
+        fn puede_consultar_padron(&self, eleccion_id:Option<u64>) -> bool
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            let sig_usuario = self.usuarios_pendientes.first();
-            if sig_usuario.is_none() { return Err(String::from("No hay usuarios pendientes.")); }
-    
-            let usuario = self.usuarios_pendientes.remove(0);
-            if aceptar_usuario { 
-                self.usuarios.push(usuario);
-                return Ok(String::from("Usuario agregado exitosamente."));
-            }
-    
-            self.usuarios_rechazados.push(usuario.id);
-            return Ok(String::from("Usuario rechazado exitosamente."));
+            self.es_operador_padron(eleccion_id) || self.puede_consultar_reportes()
         }
-
-
-        // ====-----==== METODOS ELECCIONES ====----====
-        // ====-----==== METODOS ELECCIONES ====----====
-        // ====-----==== METODOS ELECCIONES ====----====
-
-        // ====-----==== METODOS ADMINISTRADOR ====----====
-
-        /// Crea una nueva elección con las fechas especificadas.
-        /// - fecha_inicial: String - Fecha y hora de inicio en formato "dd-mm-YYYY hh:mm".
-        /// - fecha_final: String - Fecha y hora de finalización en formato "dd-mm-YYYY hh:mm".
-        /// Result<String, String>: Mensaje de éxito con el ID de la elección creada o mensaje de error.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Parsea las fechas iniciales y finales proporcionadas en formato específico. Retorna un error si el formato no es válido.
-        /// Genera un nuevo ID para la elección basado en el tamaño actual de la lista de elecciones.
-        /// Crea una nueva instancia de `Eleccion` con los parámetros dados y la agrega a la lista de elecciones.
-        /// Retorna un mensaje de éxito indicando que la elección fue creada exitosamente junto con el ID de la elección.
-        #[ink(message)]
-        pub fn crear_eleccion(&mut self, fecha_inicial:String, fecha_final:String) -> Result<String, String>
+        //Verifica que el caller sea administrador del sistema
+        fn es_administrador(&self) -> bool
         {
-            self.crear_eleccion_privado(fecha_inicial, fecha_final)
+            self.tiene_rol(self.env().caller(), Rol::Administrador)
         }
-        fn crear_eleccion_privado(&mut self, fecha_inicial: String, fecha_final: String) -> Result<String, String> 
+        //Verifica si las acciones críticas deben proponerse y confirmarse por varios administradores
+        fn requiere_multifirma(&self) -> bool
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-    
-            let fecha_inicial_milisegundos = chrono::NaiveDateTime::parse_from_str(&fecha_inicial, "%d-%m-%Y %H:%M");
-            if fecha_inicial_milisegundos.is_err() {
-                return Err(String::from("Error en el formato de la fecha inicial. Formato: dd-mm-YYYY hh:mm"));
-            }
-            let fecha_final_milisegundos = chrono::NaiveDateTime::parse_from_str(&fecha_final, "%d-%m-%Y %H:%M");
-            if fecha_final_milisegundos.is_err() {
-                return Err(String::from("Error en el formato de la fecha final. Formato: dd-mm-YYYY hh:mm"));
-            }
-    
-            let eleccion_id = match (self.elecciones.len() as u64).checked_add(1) {
-                Some(index) => index,
-                None => return Err(String::from("Se produjo un overflow al intentar crear una elección."))
-            };
-            let eleccion = Eleccion {
-                id: eleccion_id,
-                candidatos: Vec::new(),
-                votantes: Vec::new(),
-                usuarios_pendientes: Vec::new(),
-                usuarios_rechazados: Vec::new(),
-                votacion_iniciada:false,
-                fecha_inicio: fecha_inicial_milisegundos.unwrap().and_utc().timestamp_millis() as u64,
-                fecha_final: fecha_final_milisegundos.unwrap().and_utc().timestamp_millis() as u64,
-                resultados:None
-            };
-            self.elecciones.push(eleccion);
-    
-            return Ok(format!("Eleccion creada exitosamente. Id de la elección: {}", eleccion_id));
+            self.umbral_aprobacion > 1
         }
-
-        /// Permite al administrador iniciar una votación si la fecha actual es posterior a la fecha inicial establecida y la votación aún no ha comenzado.
-        /// Inicia la votación de una elección.
-        /// - eleccion_id: u64 - ID de la elección que se desea iniciar.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Obtiene la elección correspondiente al ID proporcionado y verifica las condiciones para iniciar la votación:
-        /// - Si el tiempo actual es posterior a la fecha de finalización de la elección, retorna un error indicando que la votación ya finalizó.
-        /// - Si la votación ya está iniciada, retorna un error indicando que la votación ya inició.
-        /// - Si el tiempo actual es anterior a la fecha de inicio de la elección, retorna un error indicando que todavía no es la fecha para la votación.
-        /// Si todas las condiciones son satisfactorias, marca la votación como iniciada y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn iniciar_votacion(&mut self, eleccion_id:u64) -> Result<String, String>
+        /// Otorga un rol a una cuenta. Si `eleccion_id` es Some, el rol debe ser de operador de padrón y la elección debe existir.
+        fn agregar_rol(&mut self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
         {
-            self.iniciar_votacion_privado(eleccion_id)
+            match eleccion_id {
+                Some(eleccion_id) => {
+                    if rol != Rol::OperadorPadron { return Err(Error::RolSinAlcancePorEleccion); }
+                    if !self.existe_eleccion(eleccion_id) { return Err(Error::EleccionInexistente); }
+                    if self.operadores_eleccion.contains((eleccion_id, cuenta)) { return Err(Error::RolYaOtorgado); }
+                    self.operadores_eleccion.insert((eleccion_id, cuenta), &());
+                },
+                None => {
+                    if self.tiene_rol(cuenta, rol) { return Err(Error::RolYaOtorgado); }
+                    if rol == Rol::Administrador {
+                        self.cantidad_administradores = self.cantidad_administradores.checked_add(1).ok_or(Error::Overflow)?;
+                    }
+                    self.roles.insert((cuenta, rol), &());
+                },
+            }
+            self.env().emit_event(RolOtorgado { cuenta, rol, eleccion_id });
+            if rol == Rol::GeneradorReportes {
+                self.env().emit_event(GeneradorReportesAsignado { generador_reportes: cuenta });
+            }
+            Ok(())
         }
-        pub fn iniciar_votacion_privado(&mut self, eleccion_id:u64) -> Result<String, String>
+        /// Quita un rol a una cuenta. Retorna Error::UltimoAdministrador si se quita el rol al único administrador
+        /// y Error::UmbralInvalido si quedarían menos administradores que el umbral de aprobación.
+        fn quitar_rol(&mut self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>, renuncia:bool) -> Result<()>
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            let block_timestamp = self.env().block_timestamp();
-
-            match self.obtener_eleccion_por_id(eleccion_id) {
-                Some(eleccion) => {
-                    if block_timestamp > eleccion.fecha_final {
-                        return Err(String::from("La votación ya finalizó."));
-                    }
-                    if eleccion.votacion_iniciada {
-                        return Err(String::from("La votación ya inició."));
-                    }
-                    if block_timestamp < eleccion.fecha_inicio {
-                        return Err(String::from("Todavía no es la fecha para la votación."));
+            match eleccion_id {
+                Some(eleccion_id) => {
+                    if rol != Rol::OperadorPadron { return Err(Error::RolSinAlcancePorEleccion); }
+                    if !self.operadores_eleccion.contains((eleccion_id, cuenta)) { return Err(Error::RolNoOtorgado); }
+                    self.operadores_eleccion.remove((eleccion_id, cuenta));
+                },
+                None => {
+                    if !self.tiene_rol(cuenta, rol) { return Err(Error::RolNoOtorgado); }
+                    if rol == Rol::Administrador {
+                        if self.cantidad_administradores <= 1 { return Err(Error::UltimoAdministrador); }
+                        if self.cantidad_administradores <= self.umbral_aprobacion { return Err(Error::UmbralInvalido); }
+                        self.cantidad_administradores -= 1;
                     }
-                    eleccion.votacion_iniciada = true;
-                    return Ok(String::from("Se inició la votación exitosamente."));
+                    self.roles.remove((cuenta, rol));
                 },
-                None => return Err(String::from("No existe una elección con ese id."))
             }
+            self.env().emit_event(RolRevocado { cuenta, rol, eleccion_id, renuncia });
+            Ok(())
         }
-
-        /// Permite al administrador obtener información del siguiente usuario pendiente en una elección específica.
-        /// Obtiene información del siguiente usuario pendiente en una elección.
-        /// - eleccion_id: u64 - ID de la elección de la cual se desea obtener el siguiente usuario pendiente
-        /// Result<String, String>: Mensaje con los datos del usuario pendiente o un mensaje de error si no hay usuarios pendientes.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Obtiene la elección correspondiente al ID proporcionado y luego obtiene la información del primer usuario pendiente
-        /// en la lista de usuarios pendientes de esa elección. Retorna un mensaje con los datos del usuario pendiente si existe,
-        /// indicando si desea ser un VOTANTE o un CANDIDATO. Retorna un mensaje de error si no hay usuarios pendientes.
-        #[ink(message)]
-        pub fn obtener_siguiente_usuario_pendiente_en_una_eleccion(&mut self, eleccion_id:u64) -> Result<String, String>
+        //Dado un id retorna Option de un usuario
+        fn obtener_usuario(&self, id: AccountId) -> Option<Usuario> 
         {
-            self.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(eleccion_id)
+            self.usuarios.get(id)
         }
-        pub fn obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(&mut self, eleccion_id:u64) -> Result<String, String>
+        //Obtiene al usuario registrado o, si se dio de baja participando de una elección iniciada, lo que se conservó de él
+        fn obtener_usuario_o_dado_de_baja(&self, id: AccountId) -> Option<Usuario>
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            let eleccion_elegida = match self.obtener_eleccion_por_id(eleccion_id) {
-                Some(eleccion) => eleccion,
-                None => return Err(String::from("Eleccion no encontrada")),
-            };
-            let sig_usuario = eleccion_elegida.usuarios_pendientes.first();
-            match sig_usuario {
-                Some(usuario_eleccion) => {
-                    let mut datos_usuario = String::from("Usuario: ");
-                    datos_usuario.push_str( hex::encode(usuario_eleccion.0).as_str() );
-                    match usuario_eleccion.1 {
-                        TIPO_DE_USUARIO::VOTANTE => datos_usuario.push_str("\nEl usuario quiere ser un VOTANTE"),
-                        TIPO_DE_USUARIO::CANDIDATO => datos_usuario.push_str("\nEl usuario quiere ser un CANDIDATO")
-                    };
-                    Ok(datos_usuario)
-                },
-                None => Err(String::from("No hay usuarios pendientes.")),
-            }
+            self.obtener_usuario(id).or_else(|| self.usuarios_dados_de_baja.get(id))
         }
-        
-        /// Permite al administrador procesar el siguiente usuario pendiente en una elección específica.
-        /// Parámetros:
-        /// - eleccion_id: u64 - ID de la elección en la que se procesarán los usuarios pendientes.
-        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario pendiente.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Obtiene la elección correspondiente al ID proporcionado. Luego, procesa el siguiente usuario pendiente
-        /// en la elección según la acción indicada (aceptar o rechazar) y retorna el resultado del procesamiento.
-        #[ink(message)]
-        pub fn procesar_usuarios_en_una_eleccion(&mut self, eleccion_id:u64,aceptar_usuario:bool) -> Result<String, String>
+        //Verifica que el adress del que hace la llamada al contrato sea un usuario registrado
+        fn es_usuario_registrado(&self) -> bool
         {
-            self.procesar_usuarios_en_una_eleccion_privado(eleccion_id,aceptar_usuario)
+            self.usuarios.contains(self.env().caller())
+        }
+        //Verifica que el adress del que hace la llamada al contrato sea un usuario pendiente
+        fn es_usuario_pendiente(&self) -> bool
+        {
+            self.usuarios_pendientes.contains(self.env().caller())
         }
-        pub fn procesar_usuarios_en_una_eleccion_privado(&mut self, eleccion_id:u64,aceptar_usuario:bool) -> Result<String, String>
+        //Verifica que la eleccion pasa por parametro exista y retorna un booleano
+        fn existe_eleccion(&self, eleccion_id:u64) -> bool
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            
-            let eleccion_elegida = match self.obtener_eleccion_por_id(eleccion_id) {
-                Some(eleccion) => eleccion,
-                None => return Err(String::from("Eleccion no encontrada")),
-            };
-            return eleccion_elegida.procesar_siguiente_usuario_pendiente(aceptar_usuario);
+            eleccion_id >= 1 && eleccion_id <= self.cantidad_elecciones
         }
 
-        // ====-----==== METODOS PÚBLICOS ====----====
-
-        /// Ingresa al usuario actual a una elección como pendiente de aprobación.
-        /// - eleccion_id: u64 - ID de la elección a la que se desea ingresar.
-        /// - tipo: TIPO_DE_USUARIO - Tipo de usuario que desea ingresar (VOTANTE o CANDIDATO).
+        /// Obtiene una copia de una elección por su ID.
+        /// - eleccion_id: u64 - ID de la elección a obtener.
+        /// Option<Eleccion>: La elección si existe, o None si no se encuentra.
         /// Descripción:
-        /// Verifica si el usuario está registrado. Obtiene el ID del usuario actual y el timestamp actual del bloque.
-        /// Valida el estado actual de la elección y verifica si el usuario ya ha sido rechazado previamente o si ya está pendiente.
-        /// Luego, agrega al usuario como pendiente en la elección y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn ingresar_a_eleccion(&mut self, eleccion_id:u64, tipo:TIPO_DE_USUARIO) -> Result<String, String>
+        /// Verifica si existe una elección con el ID proporcionado utilizando el método existe_eleccion().
+        /// Los cambios hechos sobre la copia deben guardarse con guardar_eleccion().
+        fn obtener_eleccion_por_id(&self, eleccion_id:u64) -> Option<Eleccion>
         {
-            self.ingresar_a_eleccion_privado(eleccion_id,tipo)
+            if !self.existe_eleccion(eleccion_id) { return None; }
+            self.elecciones.get(eleccion_id)
         }
-        fn ingresar_a_eleccion_privado(&mut self, eleccion_id:u64, tipo:TIPO_DE_USUARIO) -> Result<String, String>
+        /// Guarda en el storage los cambios hechos sobre una elección.
+        fn guardar_eleccion(&mut self, eleccion:&Eleccion)
         {
-            if !self.es_usuario_registrado() { return Err(ERRORES::USUARIO_NO_REGISTRADO.to_string()); }
-            let id = self.env().caller();
-
-            let block_timestamp = self.env().block_timestamp();
-            let result = self.validar_estado_eleccion(eleccion_id, block_timestamp, id);
-            let eleccion = match result {
-                Ok(eleccion) => eleccion,
-                Err(mensaje) => return Err(mensaje)
-            };
-            //Validar que un usuario que ya ha sido rechazado en la misma eleccion no intente volver a ponerse como pendiente 
-            if eleccion.usuarios_rechazados.contains(&id) {
-                return Err(String::from("Ya has sido rechazado no puedes ingresar a la eleccion"));
-            }
-            
-            if eleccion.contiene_usuario_pendiente(id){
-                return Err(String::from("No puedes ingresar dos veces a la misma eleccion"));
-            }
+            self.elecciones.insert(eleccion.id, eleccion);
+        }
 
-            eleccion.usuarios_pendientes.push((id,tipo));
+        // ====-----==== METODOS PRIVADOS DE ELECCIONES ====----====
 
-            return Ok(String::from("Ingresó a la elección correctamente Pendiente de aprobacion del Administrador"));
+        /// Este método verifica si hay un usuario pendiente con el ID especificado en la cola de usuarios pendientes de la elección.
+        fn contiene_usuario_pendiente(&self, eleccion_id:u64, id:AccountId) -> bool
+        {
+            self.pendientes_eleccion.contains((eleccion_id, id))
         }
-        
-        /// Realiza el voto a un candidato en una elección específica.
-        /// - eleccion_id: u64 - ID de la elección.
-        /// - candidato_id: u32 - ID del candidato.
-        /// Descripción:
-        /// Verifica si el usuario está registrado. Obtiene el ID del votante y el timestamp actual del bloque.
-        /// Luego, verifica si la elección existe y si la votación está activa y en el período correcto.
-        /// Finalmente, llama al método de votar_candidato de la elección y retorna su resultado.
-        #[ink(message)]
-        pub fn votar_a_candidato(&mut self, eleccion_id:u64, candidato_id:u32) -> Result<String, String>
+        /// Verifica si el usuario ya participa de la elección, ya sea como pendiente, votante o candidato.
+        fn esta_inscripto_en_eleccion(&self, eleccion_id:u64, id:AccountId) -> bool
         {
-            self.votar_a_candidato_privado(eleccion_id, candidato_id)
+            self.contiene_usuario_pendiente(eleccion_id, id)
+                || self.votantes.contains((eleccion_id, id))
+                || self.candidatos_por_cuenta.contains((eleccion_id, id))
         }
-        fn votar_a_candidato_privado(&mut self, eleccion_id:u64, candidato_id:u32) -> Result<String, String>
+        //Verifica que exista el candidato del que se esta intentando obtener la informacion
+        //En el caso de que exista retorna el candidato pasado por parametro
+        fn obtener_informacion_candidato(&self, eleccion:&Eleccion, candidato_id:u32) -> Option<CandidatoConteo>
         {
-            if !self.es_usuario_registrado() { return Err(ERRORES::USUARIO_NO_REGISTRADO.to_string()); }
-            let id = self.env().caller();
-            let block_timestamp = self.env().block_timestamp();
-    
-            match self.obtener_eleccion_por_id(eleccion_id) {
-                Some(eleccion) => {
-                    if !eleccion.votacion_iniciada {
-                        if block_timestamp < eleccion.fecha_inicio {
-                            return Err(String::from("Todavía no es la fecha para la votación."));
-                        }
-                        eleccion.votacion_iniciada = true;
-                    }
-                    if block_timestamp > eleccion.fecha_final {
-                        return Err(String::from("La votación ya finalizó."));
-                    }
-    
-                    return eleccion.votar_candidato(id, candidato_id);
-                },
-                None => return Err(String::from("No existe una elección con ese id."))
-            }
+            self.candidatos.get((eleccion.id, candidato_id)).filter(|candidato| !candidato.retirado)
         }
-
-        /// Obtiene la información de un candidato en una elección específica.
-        /// - eleccion_id: u64 - ID de la elección.
-        /// - candidato_id: u32 - ID del candidato.
-        /// Result<String, String>: Información del candidato o mensaje de error.
-        /// Descripción:
-        /// Busca la elección por su ID y luego obtiene la información del candidato.
-        /// Retorna un mensaje con el nombre, apellido y DNI del candidato si está registrado;
-        /// de lo contrario, retorna un error.
-        #[ink(message)]
-        pub fn obtener_informacion_candidato_eleccion(&self, eleccion_id:u64, candidato_id:u32) -> Result<String, String>
+        /// Este método verifica si existe un candidato con el ID especificado dentro de la lista de candidatos disponibles.
+        /// Los candidatos retirados conservan su ID, pero ya no existen para votar ni para los resultados.
+        fn existe_candidato(&self, eleccion:&Eleccion, candidato_id:u32) -> bool
         {
-            self.obtener_informacion_candidato_eleccion_privado(eleccion_id, candidato_id)
+            self.obtener_informacion_candidato(eleccion, candidato_id).is_some()
         }
-        fn obtener_informacion_candidato_eleccion_privado(&self, eleccion_id:u64, candidato_id:u32) -> Result<String, String>
+        /// Verifica que la lista no esté vacía, que todos sus candidatos existan y que ninguno se repita.
+        fn son_candidatos_distintos(&self, eleccion:&Eleccion, candidatos:&[u32]) -> bool
         {
-            let eleccion_elegida = match self.obtener_ref_eleccion_por_id(eleccion_id) {
-                Some(eleccion) => eleccion,
-                None => return Err(String::from("Eleccion no encontrada.")),
-            };
-            let candidato_info = match eleccion_elegida.obtener_informacion_candidato(candidato_id) {
-                Some(candidato) => candidato,
-                None => return Err(String::from("Candidato no encontrado.")),
-            };
-
-            let info = self.obtener_usuario(candidato_info.id).expect("No se pudo encontrar la información del candidato."); // Si se encuentra el candidato, DEBE estar registrado
-            let mut str = String::from("Nombre: ") + info.nombre.as_str();
-            str.push_str((String::from("\nApellido: ") + info.apellido.as_str()).as_str());
-            str.push_str((String::from("\nDNI: ") + info.dni.as_str()).as_str());
-            return Ok(str);
+            !candidatos.is_empty() && candidatos.iter().enumerate().all(|(posicion, candidato_id)| {
+                self.existe_candidato(eleccion, *candidato_id) && !candidatos[..posicion].contains(candidato_id)
+            })
         }
+        /// Permite a un votante registrado emitir su voto en la elección.
+        /// - eleccion: &mut Eleccion - Elección en la que se vota. Quien llama debe guardarla luego.
+        /// - votante_id: AccountId del votante que desea votar.
+        /// - voto: Voto - Candidato elegido o preferencias del votante.
+        /// Descripción:
+        /// Verifica si el voto es válido para la elección con validar_voto(). Si no lo es, retorna un error.
+        /// Verifica si el votante está registrado en la elección. Si no lo está, retorna un error.
+        /// Verifica si el votante ya ha emitido o delegado su voto. Si ya lo hizo, retorna un error.
+        /// Registra el voto del votante, con el peso de los votos que le delegaron, y actualiza los conteos.
+        fn emitir_voto(&mut self, eleccion:&mut Eleccion, votante_id:AccountId, voto:Voto) -> Result<()>
+        {
+            self.validar_voto(eleccion, &voto)?;
+            let mut votante = self.votantes.get((eleccion.id, votante_id)).ok_or(Error::NoRegistradoEnEleccion)?;
+            if votante.voto_emitido { return Err(Error::VotoYaEmitido); }
+            if votante.delegado.is_some() { return Err(Error::VotoYaDelegado); }
 
-        // ====-----==== METODOS USADOS POR EL ADMINISTRADOR ====----====
-        // ====-----==== METODOS USADOS POR EL ADMINISTRADOR ====----====
-        // ====-----==== METODOS USADOS POR EL ADMINISTRADOR ====----====
+            self.contar_voto(eleccion, voto, votante.peso)?;
+            Self::registrar_voto_emitido(eleccion, &votante)?;
+            votante.voto_emitido = true;
 
-        /// Utilizado por un administrador.
-        /// Activa el registro de usuarios.
-        /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Luego, verifica si el registro ya está activado. Si es así, retorna un error. Si no, activa el registro 
-        /// y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn activar_registro(&mut self) -> Result<String, String>
+            self.votantes.insert((eleccion.id, votante_id), &votante);
+            Ok(())
+        }
+        /// Verifica que el voto corresponda al sistema de votación de la elección y que sus candidatos existan.
+        /// En un voto por preferencias los candidatos no pueden repetirse y debe haber al menos uno.
+        fn validar_voto(&self, eleccion:&Eleccion, voto:&Voto) -> Result<()>
         {
-            self.activar_registro_privado()
+            match (eleccion.sistema_votacion, voto) {
+                (_, Voto::EnBlanco) | (_, Voto::Nulo) => {},
+                (SistemaVotacion::Mayoria, Voto::Candidato(candidato_id)) => {
+                    if !self.existe_candidato(eleccion, *candidato_id) { return Err(Error::CandidatoInexistente); }
+                },
+                (SistemaVotacion::PreferenciaInstantanea, Voto::Preferencias(preferencias)) => {
+                    if !self.son_candidatos_distintos(eleccion, preferencias) { return Err(Error::PreferenciasInvalidas); }
+                },
+                (SistemaVotacion::Aprobacion, Voto::Aprobacion(aprobados)) => {
+                    if !self.son_candidatos_distintos(eleccion, aprobados) { return Err(Error::AprobacionInvalida); }
+                },
+                (SistemaVotacion::Limitado(maximo), Voto::Aprobacion(aprobados)) => {
+                    if !self.son_candidatos_distintos(eleccion, aprobados) { return Err(Error::AprobacionInvalida); }
+                    if aprobados.len() > maximo as usize { return Err(Error::DemasiadosCandidatosAprobados); }
+                },
+                _ => return Err(Error::TipoDeVotoInvalido),
+            }
+            Ok(())
         }
-        fn activar_registro_privado(&mut self) -> Result<String, String>
+        /// Suma un voto ya validado: por mayoría incrementa los votos del candidato, por aprobación los de cada candidato aprobado,
+        /// y por preferencia instantánea guarda la boleta para contarla al calcular los resultados.
+        /// Los votos en blanco y nulos solo incrementan su contador en la elección.
+        /// Cada voto suma `peso` votos, que es el voto propio más los que le delegaron a quien lo emitió.
+        fn contar_voto(&mut self, eleccion:&mut Eleccion, voto:Voto, peso:u32) -> Result<()>
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            if self.registro_activado { return Err(String::from("El registro ya está activado.")); }
-            self.registro_activado = true;
-            return Ok(String::from("Se activó el registro para los usuarios."));
+            match voto {
+                Voto::Candidato(candidato_id) => {
+                    let mut candidato = self.obtener_informacion_candidato(eleccion, candidato_id).ok_or(Error::CandidatoInexistente)?;
+                    candidato.votos_totales = candidato.votos_totales.checked_add(peso).ok_or(Error::Overflow)?;
+                    self.candidatos.insert((eleccion.id, candidato_id), &candidato);
+                },
+                Voto::Preferencias(preferencias) => {
+                    let indice = eleccion.cantidad_boletas;
+                    eleccion.cantidad_boletas = indice.checked_add(1).ok_or(Error::Overflow)?;
+                    self.boletas.insert((eleccion.id, indice), &Boleta { preferencias, peso });
+                },
+                Voto::Aprobacion(aprobados) => {
+                    for candidato_id in aprobados {
+                        let mut candidato = self.obtener_informacion_candidato(eleccion, candidato_id).ok_or(Error::CandidatoInexistente)?;
+                        candidato.votos_totales = candidato.votos_totales.checked_add(peso).ok_or(Error::Overflow)?;
+                        self.candidatos.insert((eleccion.id, candidato_id), &candidato);
+                    }
+                },
+                Voto::EnBlanco => eleccion.votos_en_blanco = eleccion.votos_en_blanco.checked_add(peso).ok_or(Error::Overflow)?,
+                Voto::Nulo => eleccion.votos_nulos = eleccion.votos_nulos.checked_add(peso).ok_or(Error::Overflow)?,
+            }
+            Ok(())
+        }
+        /// Suma a la elección el voto emitido por el votante y los votos que le delegaron.
+        fn registrar_voto_emitido(eleccion:&mut Eleccion, votante:&Votante) -> Result<()>
+        {
+            eleccion.votos_emitidos = eleccion.votos_emitidos.checked_add(1).ok_or(Error::Overflow)?;
+            eleccion.votos_delegados = eleccion.votos_delegados.checked_add(votante.peso.saturating_sub(1)).ok_or(Error::Overflow)?;
+            Ok(())
         }
+        /// Guarda el compromiso del voto de un votante en una elección de voto secreto.
+        /// - eleccion: &mut Eleccion - Elección en la que se vota. Quien llama debe guardarla luego.
+        /// Descripción:
+        /// Verifica si el votante está registrado en la elección y si todavía no votó.
+        /// Marca el voto como emitido, pero no lo suma a ningún candidato hasta que se revele.
+        fn comprometer_voto_en_eleccion(&mut self, eleccion:&mut Eleccion, votante_id:AccountId, compromiso:Hash) -> Result<()>
+        {
+            let mut votante = self.votantes.get((eleccion.id, votante_id)).ok_or(Error::NoRegistradoEnEleccion)?;
+            if votante.voto_emitido { return Err(Error::VotoYaEmitido); }
+            if votante.delegado.is_some() { return Err(Error::VotoYaDelegado); }
 
-        /// Utilizado por un administrador.
-        /// Desactiva el registro de usuarios si no está activo el registro.
+            Self::registrar_voto_emitido(eleccion, &votante)?;
+            votante.voto_emitido = true;
+            votante.compromiso = Some(compromiso);
+
+            self.votantes.insert((eleccion.id, votante_id), &votante);
+            Ok(())
+        }
+        /// Revela el voto comprometido de un votante y lo cuenta.
+        /// - eleccion: &mut Eleccion - Elección en la que se revela. Quien llama debe guardarla luego.
         /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        /// Luego, verifica si el registro ya está desactivado. Si es así, retorna un error. Si no, desactiva el
-        /// registro y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn desactivar_registro(&mut self) -> Result<String, String>
+        /// Verifica que el votante haya comprometido un voto y que no lo haya revelado.
+        /// Verifica que el hash de (elección, votante, voto, sal) coincida con el compromiso y que el voto sea válido para la elección.
+        fn revelar_voto_en_eleccion(&mut self, eleccion:&mut Eleccion, votante_id:AccountId, voto:Voto, sal:[u8; 32]) -> Result<()>
         {
-            self.desactivar_registro_privado()
+            let mut votante = self.votantes.get((eleccion.id, votante_id)).ok_or(Error::NoRegistradoEnEleccion)?;
+            let compromiso = votante.compromiso.ok_or(Error::VotoNoEmitido)?;
+            if votante.voto_revelado { return Err(Error::VotoYaRevelado); }
+            if Self::calcular_compromiso(eleccion.id, votante_id, &voto, sal) != compromiso { return Err(Error::CompromisoInvalido); }
+            self.validar_voto(eleccion, &voto)?;
+
+            self.contar_voto(eleccion, voto, votante.peso)?;
+            eleccion.votos_revelados = eleccion.votos_revelados.checked_add(votante.peso).ok_or(Error::Overflow)?;
+            votante.voto_revelado = true;
+
+            self.votantes.insert((eleccion.id, votante_id), &votante);
+            Ok(())
         }
-        fn desactivar_registro_privado(&mut self) -> Result<String, String>
+        /// Recorre las delegaciones desde `inicio` hasta llegar a un votante que no delegó su voto.
+        /// - origen: AccountId - Votante que delega o revoca. Si la cadena pasa por él, retorna Error::DelegacionCircular.
+        /// Retorno:
+        /// Result<Vec<Votante>>: Los votantes de la cadena, desde `inicio` hasta el delegado final, o el error correspondiente.
+        /// Descripción:
+        /// Como cada delegación se valida al hacerse, las delegaciones existentes no forman ciclos y el recorrido siempre termina.
+        fn cadena_de_delegacion(&self, eleccion_id:u64, inicio:AccountId, origen:AccountId) -> Result<Vec<Votante>>
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            if !self.registro_activado { return Err(String::from("El registro ya está desactivado.")); }
-            self.registro_activado = false;
-            return Ok(String::from("Se desactivó el registro para los usuarios."));
+            let mut cadena = Vec::new();
+            let mut actual = inicio;
+            loop {
+                if actual == origen { return Err(Error::DelegacionCircular); }
+                let votante = self.votantes.get((eleccion_id, actual)).ok_or(Error::DelegadoNoEsVotante)?;
+                let siguiente = votante.delegado;
+                cadena.push(votante);
+                match siguiente {
+                    Some(delegado) => actual = delegado,
+                    None => return Ok(cadena),
+                }
+            }
+        }
+        /// Delega el voto de un votante en otro votante de la elección.
+        /// Descripción:
+        /// Verifica que el votante no haya votado ni delegado su voto, y que la delegación no forme un ciclo.
+        /// El peso del votante (su voto y los que le delegaron) se suma a todos los votantes de la cadena de delegación,
+        /// de modo que el delegado final vota con todos ellos y cada votante intermedio sabe cuántos votos lleva si revoca.
+        fn delegar_voto_en_eleccion(&mut self, eleccion_id:u64, votante_id:AccountId, delegado:AccountId) -> Result<()>
+        {
+            let mut votante = self.votantes.get((eleccion_id, votante_id)).ok_or(Error::NoRegistradoEnEleccion)?;
+            if votante.voto_emitido { return Err(Error::VotoYaEmitido); }
+            if votante.delegado.is_some() { return Err(Error::VotoYaDelegado); }
+
+            let cadena = self.cadena_de_delegacion(eleccion_id, delegado, votante_id)?;
+            for mut eslabon in cadena {
+                eslabon.peso = eslabon.peso.checked_add(votante.peso).ok_or(Error::Overflow)?;
+                self.votantes.insert((eleccion_id, eslabon.id), &eslabon);
+            }
+            votante.delegado = Some(delegado);
+            self.votantes.insert((eleccion_id, votante_id), &votante);
+            let mut delegantes = self.delegantes.get((eleccion_id, delegado)).unwrap_or_default();
+            delegantes.push(votante_id);
+            self.delegantes.insert((eleccion_id, delegado), &delegantes);
+            Ok(())
+        }
+        /// Revoca la delegación del voto de un votante, restando su peso de la cadena de delegación.
+        /// Retorna Error::DelegadoYaVoto si el delegado final ya votó.
+        fn revocar_delegacion_en_eleccion(&mut self, eleccion_id:u64, votante_id:AccountId) -> Result<()>
+        {
+            let mut votante = self.votantes.get((eleccion_id, votante_id)).ok_or(Error::NoRegistradoEnEleccion)?;
+            let delegado = votante.delegado.ok_or(Error::VotoNoDelegado)?;
+
+            let cadena = self.cadena_de_delegacion(eleccion_id, delegado, votante_id)?;
+            if cadena.last().is_some_and(|delegado_final| delegado_final.voto_emitido) { return Err(Error::DelegadoYaVoto); }
+            for mut eslabon in cadena {
+                eslabon.peso = eslabon.peso.saturating_sub(votante.peso);
+                self.votantes.insert((eleccion_id, eslabon.id), &eslabon);
+            }
+            votante.delegado = None;
+            self.votantes.insert((eleccion_id, votante_id), &votante);
+            let mut delegantes = self.delegantes.get((eleccion_id, delegado)).unwrap_or_default();
+            delegantes.retain(|id| *id != votante_id);
+            if delegantes.is_empty() { self.delegantes.remove((eleccion_id, delegado)); } else { self.delegantes.insert((eleccion_id, delegado), &delegantes); }
+            Ok(())
         }
 
-        /// Utilizado por el administrador.
-        /// Permite al administrador transferir el rol de administrador a otro usuario.
-        /// id: AccountId: ID del nuevo administrador.
+        /// Usado por el administrador.
+        /// Procesa el siguiente usuario pendiente de la elección, aceptándolo o rechazándolo según el parámetro aceptar_usuario.
+        /// - eleccion: &mut Eleccion - Elección cuya cola se procesa. Quien llama debe guardarla luego.
+        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario pendiente.
+        /// Result<(AccountId, TIPO_DE_USUARIO)>: El usuario procesado y el tipo con el que se inscribió, o Error::NoHayUsuariosPendientes si no hay usuarios pendientes.
         /// Descripción:
-        ///Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        ///  Si es administrador, asigna el ID proporcionado como el nuevo administrador y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn transferir_administrador(&mut self, id:AccountId) -> Result<String, String>
+        /// Saca de la cola al primer usuario que sigue pendiente, descartando los que ya se procesaron en un lote.
+        /// Si no hay, retorna un error indicando que no hay usuarios pendientes.
+        fn procesar_siguiente_usuario_pendiente_en_eleccion(&mut self, eleccion:&mut Eleccion, aceptar_usuario:bool) -> Result<(AccountId, TIPO_DE_USUARIO)>
         {
-            self.transferir_administrador_privado(id)
+            let mut cola = eleccion.cola_pendientes;
+            let usuario = loop {
+                let posicion = cola.desencolar().ok_or(Error::NoHayUsuariosPendientes)?;
+                if let Some(usuario) = self.cola_pendientes_eleccion.take((eleccion.id, posicion)) {
+                    if self.pendientes_eleccion.contains((eleccion.id, usuario)) { break usuario; }
+                }
+            };
+            eleccion.cola_pendientes = cola;
+            let tipo = self.procesar_usuario_pendiente_en_eleccion(eleccion, usuario, aceptar_usuario)?;
+            Ok((usuario, tipo))
+        }
+        /// Acepta o rechaza a un usuario pendiente de la elección, esté o no primero en la cola.
+        /// - eleccion: &mut Eleccion - Elección del usuario. Quien llama debe guardarla luego.
+        /// - usuario: AccountId - Usuario a procesar.
+        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario.
+        /// Result<TIPO_DE_USUARIO>: El tipo con el que se inscribió, o Error::UsuarioNoPendiente si no está pendiente en la elección.
+        /// Descripción:
+        /// Si se acepta al usuario, lo añade a los votantes o candidatos de la elección según su tipo.
+        /// Si se rechaza al usuario, lo añade a los usuarios rechazados de la elección.
+        /// Su posición en la cola se libera, así que una nueva solicitud lo lista una sola vez.
+        fn procesar_usuario_pendiente_en_eleccion(&mut self, eleccion:&mut Eleccion, usuario:AccountId, aceptar_usuario:bool) -> Result<TIPO_DE_USUARIO>
+        {
+            let tipo = self.pendientes_eleccion.get((eleccion.id, usuario)).ok_or(Error::UsuarioNoPendiente)?;
+
+            if aceptar_usuario {
+                match tipo.clone() {
+                    TIPO_DE_USUARIO::VOTANTE => {
+                        let indice = eleccion.cantidad_votantes;
+                        eleccion.cantidad_votantes = indice.checked_add(1).ok_or(Error::Overflow)?;
+                        self.votantes.insert((eleccion.id, usuario), &Votante{
+                            id:usuario,
+                            voto_emitido:false,
+                            compromiso:None,
+                            voto_revelado:false,
+                            delegado:None,
+                            peso:1,
+                            indice,
+                        });
+                        self.votantes_por_indice.insert((eleccion.id, indice), &usuario);
+                    },
+                    TIPO_DE_USUARIO::CANDIDATO => {
+                        let candidato_id = eleccion.cantidad_candidatos.checked_add(1).ok_or(Error::Overflow)?;
+                        eleccion.cantidad_candidatos = candidato_id;
+                        self.candidatos.insert((eleccion.id, candidato_id), &CandidatoConteo{
+                            id:usuario,
+                            candidato_id,
+                            votos_totales:0,
+                            retirado:false,
+                        });
+                        self.candidatos_por_cuenta.insert((eleccion.id, usuario), &candidato_id);
+                    },
+                }
+                self.registrar_historial(usuario, Some(eleccion.id), AccionHistorial::Aceptacion);
+            } else {
+                self.registrar_rechazo_en_eleccion(eleccion, usuario, MotivoRechazo::default())?;
+            }
+            self.quitar_pendiente_eleccion(eleccion.id, usuario);
+            Ok(tipo)
         }
-        pub fn transferir_administrador_privado(&mut self, id:AccountId) -> Result<String, String>
+        //Quita la solicitud pendiente del usuario en la elección junto con su posición en la cola y retorna su tipo
+        fn quitar_pendiente_eleccion(&mut self, eleccion_id:u64, usuario:AccountId) -> Option<TIPO_DE_USUARIO>
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            self.administrador = id;
-            return Ok(String::from("Se transfirió el rol de administrador correctamente."));
+            if let Some(posicion) = self.posiciones_pendientes_eleccion.take((eleccion_id, usuario)) {
+                self.cola_pendientes_eleccion.remove((eleccion_id, posicion));
+            }
+            self.pendientes_eleccion.take((eleccion_id, usuario))
         }
-        
-        /// Utilizado por el administrador.
-        /// Permite al administrador asignar un generador de reportes.
-        /// id: AccountId: ID del nuevo generador de reportes.
+        //Agrega al usuario a los rechazados de la elección con el motivo indicado y descarta su perfil de candidato
+        fn registrar_rechazo_en_eleccion(&mut self, eleccion:&mut Eleccion, usuario:AccountId, motivo:MotivoRechazo) -> Result<()>
+        {
+            let indice = eleccion.cantidad_rechazados;
+            eleccion.cantidad_rechazados = indice.checked_add(1).ok_or(Error::Overflow)?;
+            self.perfiles_candidatos.remove((eleccion.id, usuario));
+            self.rechazados_eleccion.insert((eleccion.id, usuario), &indice);
+            self.rechazados_eleccion_por_indice.insert((eleccion.id, indice), &usuario);
+            self.registrar_historial(usuario, Some(eleccion.id), AccionHistorial::Rechazo(motivo));
+            Ok(())
+        }
+
+        /// Saca a un usuario de una elección, esté pendiente o aceptado como votante o candidato.
+        /// - eleccion: &mut Eleccion - Elección de la que sale. Quien llama debe validar su fase y guardarla luego.
+        /// - cuenta: AccountId - Usuario que sale de la elección.
+        /// Retorno:
+        /// Result<Option<TIPO_DE_USUARIO>>: El tipo con el que participaba, None si no participaba, o el error correspondiente.
         /// Descripción:
-        /// Verifica si el llamador es un administrador. Si no, retorna un error indicando que no es administrador.
-        ///  Si es administrador, asigna el ID proporcionado como el nuevo generador de reportes y retorna un mensaje de éxito.
-        #[ink(message)]
-        pub fn asignar_generador_reportes(&mut self, id:AccountId) -> Result<String, String>
+        /// Una solicitud pendiente se descarta junto con su perfil y su posición en la cola.
+        /// Un votante sale con retirar_votante(). Un candidato conserva su candidato_id, que no se reasigna ni corre a los demás,
+        /// pero deja de existir para votar y para los resultados. Emite RetiradoDeEleccion.
+        fn retirar_de_eleccion(&mut self, eleccion:&mut Eleccion, cuenta:AccountId) -> Result<Option<TIPO_DE_USUARIO>>
         {
-            self.asignar_generador_reportes_privado(id)
+            let tipo = if let Some(tipo) = self.quitar_pendiente_eleccion(eleccion.id, cuenta) {
+                tipo
+            } else if self.votantes.contains((eleccion.id, cuenta)) {
+                self.retirar_votante(eleccion, cuenta)?;
+                TIPO_DE_USUARIO::VOTANTE
+            } else if let Some(candidato_id) = self.candidatos_por_cuenta.take((eleccion.id, cuenta)) {
+                if let Some(mut candidato) = self.candidatos.get((eleccion.id, candidato_id)) {
+                    candidato.retirado = true;
+                    self.candidatos.insert((eleccion.id, candidato_id), &candidato);
+                }
+                eleccion.candidatos_retirados = eleccion.candidatos_retirados.checked_add(1).ok_or(Error::Overflow)?;
+                TIPO_DE_USUARIO::CANDIDATO
+            } else {
+                return Ok(None);
+            };
+            self.perfiles_candidatos.remove((eleccion.id, cuenta));
+            let mut elecciones = self.elecciones_de_usuario.get(cuenta).unwrap_or_default();
+            elecciones.retain(|eleccion_id| *eleccion_id != eleccion.id);
+            self.elecciones_de_usuario.insert(cuenta, &elecciones);
+            self.registrar_historial(cuenta, Some(eleccion.id), AccionHistorial::Retiro);
+            self.env().emit_event(RetiradoDeEleccion { usuario: cuenta, eleccion_id: eleccion.id, tipo: tipo.clone() });
+            Ok(Some(tipo))
+        }
+        //Saca a un votante de la elección. Primero se revocan las delegaciones que recibió, que devuelven su voto a cada votante que
+        //había delegado en él, y después la suya. Como todavía no se votó, ninguna revocación falla por un delegado que ya votó.
+        //Solo recorre a quienes delegaron directamente en él, no a todos los votantes de la elección
+        fn retirar_votante(&mut self, eleccion:&mut Eleccion, votante_id:AccountId) -> Result<()>
+        {
+            for delegante in self.delegantes.get((eleccion.id, votante_id)).unwrap_or_default() {
+                self.revocar_delegacion_en_eleccion(eleccion.id, delegante)?;
+            }
+            let votante = self.votantes.get((eleccion.id, votante_id)).ok_or(Error::NoRegistradoEnEleccion)?;
+            if votante.delegado.is_some() { self.revocar_delegacion_en_eleccion(eleccion.id, votante_id)?; }
+            self.votantes_por_indice.remove((eleccion.id, votante.indice));
+            self.votantes.remove((eleccion.id, votante_id));
+            eleccion.votantes_retirados = eleccion.votantes_retirados.checked_add(1).ok_or(Error::Overflow)?;
+            Ok(())
+        }
+
+        /// Obtiene los votantes de una elección en el orden en que fueron aceptados.
+        fn obtener_votantes(&self, eleccion:&Eleccion) -> Vec<Votante>
+        {
+            (0..eleccion.cantidad_votantes)
+                .filter_map(|indice| self.votantes_por_indice.get((eleccion.id, indice)))
+                .filter_map(|votante_id| self.votantes.get((eleccion.id, votante_id)))
+                .collect()
         }
-        pub fn asignar_generador_reportes_privado(&mut self, id:AccountId) -> Result<String, String>
+        /// Obtiene los candidatos de una elección ordenados por candidato_id, sin los que se retiraron.
+        fn obtener_candidatos(&self, eleccion:&Eleccion) -> Vec<CandidatoConteo>
         {
-            if !self.es_administrador() { return Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()); }
-            self.generador_reportes = Some(id);
-            return Ok(String::from("Se asigno el generador reportes correctamente."));
+            (1..=eleccion.cantidad_candidatos)
+                .filter_map(|candidato_id| self.obtener_informacion_candidato(eleccion, candidato_id))
+                .collect()
         }
 
-        // ====-----==== METODOS PARA EL GENERADOR DE REPORTES ====----====
-        // ====-----==== METODOS PARA EL GENERADOR DE REPORTES ====----====
-        // ====-----==== METODOS PARA EL GENERADOR DE REPORTES ====----====
+        /// Obtiene los resultados de la votación si están disponibles.
+        /// - eleccion: &mut Eleccion - Elección de la que se obtienen los resultados. Quien llama debe guardarla luego.
+        /// - block_timestamp: u64 - Marca de tiempo del bloque para verificar si ya se pueden obtener los resultados.
+        /// Retorno:
+        /// Option<Resultados>: Resultados de la votación si están disponibles, o None si no lo están.
+        /// Descripción:
+        /// Si la elección no está en la fase Finalizada, retorna None ya que los resultados aún no están disponibles.
+        /// Si la elección ya tiene resultados, devuelve esos resultados.
+        /// Si no hay resultados previos, los calcula a partir de la cantidad de votantes, los votos emitidos, los votos secretos sin revelar y los votos totales de cada candidato.
+        /// En las elecciones por preferencia instantánea los votos de cada candidato se calculan por rondas con calcular_rondas().
+        fn obtener_resultados_votacion(&self, eleccion:&mut Eleccion, block_timestamp:u64) -> Option<Resultados>
+        {
+            if eleccion.actualizar_estado(block_timestamp) != EstadoEleccion::Finalizada {
+                return None;
+            }
+
+            if eleccion.resultados.is_some() {
+                return eleccion.resultados.clone();
+            }
 
+            let (votos_candidatos, rondas) = match eleccion.sistema_votacion {
+                SistemaVotacion::Mayoria | SistemaVotacion::Aprobacion | SistemaVotacion::Limitado(_) => {
+                    (self.obtener_candidatos(eleccion).iter().map(|c| (c.id, c.votos_totales as u64)).collect(), Vec::new())
+                },
+                SistemaVotacion::PreferenciaInstantanea => self.calcular_rondas(eleccion),
+            };
+            let (ganadores, empatados_en_corte) = Self::calcular_ganadores(&votos_candidatos, eleccion.bancas);
+            let resultados = Resultados { 
+                votos_totales: eleccion.votantes_activos() as u64, 
+                votos_realizados: eleccion.votos_emitidos as u64,
+                votos_delegados: eleccion.votos_delegados as u64,
+                votos_sin_revelar: if eleccion.es_voto_secreto() {
+                    (eleccion.votos_emitidos as u64 + eleccion.votos_delegados as u64).saturating_sub(eleccion.votos_revelados as u64)
+                } else { 0 },
+                votos_en_blanco: eleccion.votos_en_blanco as u64,
+                votos_nulos: eleccion.votos_nulos as u64,
+                votos_candidatos,
+                rondas,
+                ganadores,
+                empatados_en_corte,
+            };
 
-        /// Utilizado por el generador de reportes asignado por el administrador.
-        /// Obtiene la informacion del usuario especificado por AccountId
-        /// user_id: AccountId: ID del usuario.
-        /// Option<(String, String, String)>: Tupla con nombre, apellido y DNI del usuario, o None si no se encuentra o el llamador no es el generador de reportes.
-        /// Verifica si el llamador tiene permiso para generar reportes. Si no, retorna None. Luego, busca el usuario por su ID en la lista de usuarios.
-        /// Si el usuario es encontrado, retorna su nombre, apellido y DNI como una tupla. Si no, retorna None.
-        #[ink(message)]
-        pub fn obtener_informacion_usuario(&self, user_id: AccountId) -> Option<(String, String, String)> 
+            eleccion.resultados = Some(resultados);
+            eleccion.resultados.clone()
+        }
+
+        /// Elige a los candidatos que ocupan las bancas de una elección.
+        /// - votos_candidatos: &[(AccountId, u64)] - Votos finales de cada candidato.
+        /// - bancas: u32 - Cantidad de bancas a cubrir.
+        /// Retorno:
+        /// (Vec<AccountId>, Vec<AccountId>): Los candidatos electos, de mayor a menor cantidad de votos, y los empatados en el corte.
+        /// Descripción:
+        /// Los candidatos se ordenan por cantidad de votos. Si los que tienen los mismos votos que el último lugar con banca
+        /// no entran todos en las bancas restantes, ninguno de ellos resulta electo y se informan como empatados en el corte.
+        fn calcular_ganadores(votos_candidatos:&[(AccountId, u64)], bancas:u32) -> (Vec<AccountId>, Vec<AccountId>)
         {
-            self.obtener_informacion_usuario_privado(user_id)
+            let mut ordenados = votos_candidatos.to_vec();
+            ordenados.sort_by_key(|(_, votos)| core::cmp::Reverse(*votos));
+            let bancas = bancas as usize;
+            if ordenados.len() <= bancas {
+                return (ordenados.iter().map(|(id, _)| *id).collect(), Vec::new());
+            }
+
+            let corte = ordenados[bancas - 1].1;
+            let mut ganadores:Vec<AccountId> = ordenados.iter().filter(|(_, votos)| *votos > corte).map(|(id, _)| *id).collect();
+            let en_corte:Vec<AccountId> = ordenados.iter().filter(|(_, votos)| *votos == corte).map(|(id, _)| *id).collect();
+            if ganadores.len() + en_corte.len() <= bancas {
+                ganadores.extend(en_corte);
+                return (ganadores, Vec::new());
+            }
+            (ganadores, en_corte)
         }
-        pub fn obtener_informacion_usuario_privado(&self, user_id: AccountId) -> Option<(String, String, String)> 
+
+        /// Cuenta las boletas de una elección por preferencia instantánea.
+        /// Retorno:
+        /// (Vec<(AccountId, u64)>, Vec<Ronda>): Los votos de cada candidato en la última ronda en que siguió en competencia y el detalle de cada ronda.
+        /// Descripción:
+        /// En cada ronda cada boleta suma un voto a su preferencia más alta entre los candidatos que siguen en competencia.
+        /// Si un candidato tiene más de la mitad de los votos válidos de la ronda, o queda un solo candidato, termina el conteo.
+        /// Si no, se eliminan los candidatos con menos votos. Si todos los que siguen están empatados en el mínimo, el conteo termina en empate.
+        fn calcular_rondas(&self, eleccion:&Eleccion) -> (Vec<(AccountId, u64)>, Vec<Ronda>)
         {
-            if !self.es_generador_reportes() && !self.es_administrador() {return None;}
+            // Indexados por candidato_id - 1. Se incluyen los candidatos retirados para no correr los índices, pero no compiten
+            let candidatos:Vec<CandidatoConteo> = (1..=eleccion.cantidad_candidatos)
+                .filter_map(|candidato_id| self.candidatos.get((eleccion.id, candidato_id)))
+                .collect();
+            let boletas:Vec<Boleta> = (0..eleccion.cantidad_boletas)
+                .filter_map(|indice| self.boletas.get((eleccion.id, indice)))
+                .collect();
+            let mut en_competencia:Vec<bool> = candidatos.iter().map(|candidato| !candidato.retirado).collect();
+            let mut votos_finales = vec![0u64; candidatos.len()];
+            let mut rondas = Vec::new();
+
+            loop {
+                let mut votos = vec![0u64; candidatos.len()];
+                let mut votos_agotados:u64 = 0;
+                for boleta in boletas.iter() {
+                    match boleta.preferencias.iter().find(|candidato_id| en_competencia[(**candidato_id - 1) as usize]) {
+                        Some(candidato_id) => votos[(*candidato_id - 1) as usize] += boleta.peso as u64,
+                        None => votos_agotados += boleta.peso as u64,
+                    }
+                }
 
-            let option_usuario = self.usuarios.iter().find(|usuario| usuario.id == user_id);
-            match option_usuario {
-                None => None,
-                Some(usuario) => Some( (usuario.nombre.clone(),  usuario.apellido.clone(),  usuario.dni.clone()) )
+                let siguen:Vec<usize> = (0..candidatos.len()).filter(|i| en_competencia[*i]).collect();
+                for i in siguen.iter() {
+                    votos_finales[*i] = votos[*i];
+                }
+                let votos_validos:u64 = siguen.iter().map(|i| votos[*i]).sum();
+                let maximo = siguen.iter().map(|i| votos[*i]).max().unwrap_or(0);
+                let minimo = siguen.iter().map(|i| votos[*i]).min().unwrap_or(0);
+
+                let mut ronda = Ronda {
+                    votos_candidatos: siguen.iter().map(|i| (candidatos[*i].id, votos[*i])).collect(),
+                    eliminados: Vec::new(),
+                    votos_agotados,
+                };
+                if siguen.len() <= 1 || maximo.saturating_mul(2) > votos_validos || minimo == maximo {
+                    rondas.push(ronda);
+                    break;
+                }
+                for i in siguen.iter().filter(|i| votos[**i] == minimo) {
+                    en_competencia[*i] = false;
+                    ronda.eliminados.push(candidatos[*i].id);
+                }
+                rondas.push(ronda);
             }
 
+            let votos_candidatos = candidatos.iter().zip(votos_finales)
+                .filter(|(c, _)| !c.retirado)
+                .map(|(c, votos)| (c.id, votos))
+                .collect();
+            (votos_candidatos, rondas)
         }
 
-        /// Utilizado por el generador de reportes asignado por el administrador.
-        /// Obtiene la lista de votantes y su estado de voto para una elección específica.
-        /// eleccion_id: u64: ID de la elección.
-        /// Result<Vec<(AccountId,u32)>, String>: Vector con el ID de cada candidato y su total de votos, o un mensaje de error.
+        /// Valida el estado de una elección antes de permitir que un usuario se registre en ella.
+        /// - eleccion_id: u64 - ID de la elección a validar.
+        /// - block_timestamp: u64 - Timestamp del bloque actual.
+        /// - id_usuario: AccountId - ID del usuario que desea registrarse en la elección.
+        /// Result<Eleccion>: La elección válida o el error correspondiente.
         /// Descripción:
-        /// La función verifica si el usuario es el generador de reportes. Si no lo es, devuelve un error. Obtiene la marca de tiempo
-        ///  del bloque actual y verifica si la elección ha finalizado. Si la elección no ha terminado, devuelve un error. Si la elección
-        ///  existe y ha finalizado, devuelve la lista de votantes con su estado de voto. Si la elección no existe, devuelve un error.
-        #[ink(message)]
-        pub fn obtener_votantes_eleccion_por_id(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,bool)>, String>
+        /// Verifica si existe una elección con el ID proporcionado. Si no existe, retorna Error::EleccionInexistente.
+        /// Verifica si el usuario ya está pendiente, es votante o es candidato en ella. Si lo está, retorna Error::YaInscriptoEnEleccion.
+        /// Verifica que la elección esté en la fase de inscripción. Si todavía no la abrió o la votación ya comenzó, retorna Error::InscripcionCerrada.
+        /// Si pasó la fecha final retorna Error::EleccionFinalizada, y si fue cancelada Error::EleccionCancelada.
+        /// Retorna la elección válida si todas las validaciones pasan.
+        fn validar_estado_eleccion(&self,eleccion_id:u64,block_timestamp:u64,id_usuario:AccountId) -> Result<Eleccion>
         {
-            self.obtener_votantes_eleccion_por_id_privado(eleccion_id)
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            if self.esta_inscripto_en_eleccion(eleccion_id, id_usuario) { return Err(Error::YaInscriptoEnEleccion); }
+            
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Inscripcion => Ok(eleccion),
+                EstadoEleccion::Borrador | EstadoEleccion::Votacion => Err(Error::InscripcionCerrada),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => Err(Error::EleccionFinalizada),
+                EstadoEleccion::Cancelada => Err(Error::EleccionCancelada),
+            }
         }
-        pub fn obtener_votantes_eleccion_por_id_privado(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,bool)>, String>
+        /// Verifica que la elección esté en la fase Finalizada, es decir que sus resultados sean definitivos.
+        /// Retorna Error::EleccionCancelada si fue cancelada y Error::EleccionNoFinalizada en cualquier otra fase.
+        fn validar_eleccion_finalizada(eleccion:&mut Eleccion, block_timestamp:u64) -> Result<()>
         {
-            if !self.es_generador_reportes() && !self.es_administrador() { return Err(String::from("No es el generador de reportes o no es el administrador!")); }
-            let block_timestamp = self.env().block_timestamp();
-            
-            let eleccion_option = self.obtener_eleccion_por_id(eleccion_id);
-            match eleccion_option {
-                Some(eleccion) => {
-                    if eleccion.fecha_final > block_timestamp {
-                        return Err(String::from("La elección no finalizó, no puedes obtener los datos."));
-                    }
-                    
-                    Ok(eleccion.votantes.iter().map(|votante| (votante.id, votante.voto_emitido)).collect())
-                },
-                None => Err(String::from("La eleccion enviada no existe!")),
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Finalizada => Ok(()),
+                EstadoEleccion::Cancelada => Err(Error::EleccionCancelada),
+                _ => Err(Error::EleccionNoFinalizada),
+            }
+        }
+        /// Verifica que todavía se puedan aceptar o rechazar usuarios pendientes de la elección: hasta que termine la votación.
+        /// Retorna Error::EleccionFinalizada o Error::EleccionCancelada si no.
+        fn validar_padron_abierto(eleccion:&mut Eleccion, block_timestamp:u64) -> Result<()>
+        {
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion | EstadoEleccion::Votacion => Ok(()),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => Err(Error::EleccionFinalizada),
+                EstadoEleccion::Cancelada => Err(Error::EleccionCancelada),
+            }
+        }
+        /// Verifica que la elección todavía no haya llegado a la votación, es decir que sus votantes y candidatos puedan retirarse.
+        /// Retorna Error::VotacionYaIniciada, Error::EleccionFinalizada o Error::EleccionCancelada si no.
+        fn validar_antes_de_votacion(eleccion:&mut Eleccion, block_timestamp:u64) -> Result<()>
+        {
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion => Ok(()),
+                EstadoEleccion::Votacion => Err(Error::VotacionYaIniciada),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => Err(Error::EleccionFinalizada),
+                EstadoEleccion::Cancelada => Err(Error::EleccionCancelada),
             }
         }
 
+        // ====-----==== METODOS PÚBLICOS ====----====
+        // ====-----==== METODOS PÚBLICOS ====----====
+        // ====-----==== METODOS PÚBLICOS ====----====
 
-        /// Utilizado por el generador de reportes asignado por el administrador.
-        /// Obtiene la lista de candidatos y sus votos para una elección específica.
-        /// eleccion_id: u64: ID de la elección.
-        /// Result<Vec<(AccountId,u32)>, String>: Vector con el ID de cada candidato y su total de votos, o un mensaje de error.
+    
+        /// Registra un nuevo usuario en el sistema.
+        /// - nombre: String - Nombre del usuario a registrar.
+        /// - apellido: String - Apellido del usuario a registrar.
+        /// - dni: String - DNI del usuario a registrar.
         /// Descripción:
-        /// La función verifica si el usuario es el generador de reportes. Si no lo es, devuelve un error. Obtiene la marca de tiempo del
-        /// bloque actual y verifica si la elección ha finalizado. Si la elección no ha terminado, devuelve un error. Si la elección existe
-        /// y ha finalizado, devuelve la lista de candidatos con sus votos. Si la elección no existe, devuelve un error.
+        /// Verifica si el registro está activado. Si no lo está, retorna Error::RegistroNoActivado.
+        /// Verifica si el llamador es el administrador. Si es así, retorna Error::AdministradorNoPuedeRegistrarse.
+        /// Verifica si el llamador ya ha sido rechazado anteriormente. Si es así, retorna Error::RegistroRechazado, salvo que haya apelado
+        /// el rechazo con `apelar_rechazo` y todavía no haya vuelto a enviar la solicitud.
+        /// Verifica si el llamador ya está registrado como usuario. Si es así, retorna Error::UsuarioYaRegistrado.
+        /// Verifica si el llamador ya está en la cola de usuarios pendientes. Si es así, retorna Error::UsuarioYaPendiente.
+        /// Si está activada la protección del DNI retorna Error::RequiereCompromisoDni: se usa `registrarse_con_compromiso_dni`.
+        /// Quita los espacios al principio y al final de los datos. Retorna Error::NombreInvalido si el nombre o el apellido quedan
+        /// vacíos o superan LARGO_MAXIMO_NOMBRE bytes, y Error::DniInvalido si el DNI no tiene solo dígitos o su largo
+        /// no está entre LARGO_MINIMO_DNI y LARGO_MAXIMO_DNI.
+        /// Verifica que ningún usuario registrado o pendiente tenga el mismo DNI. Si lo tiene, retorna Error::DniYaRegistrado.
+        /// Registra al usuario añadiéndolo a la cola de usuarios pendientes.
         #[ink(message)]
-        pub fn obtener_candidatos_eleccion_por_id(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,u32)>, String>
+        pub fn registrarse(&mut self, nombre:String, apellido:String, dni:String) -> Result<()>
         {
-            self.obtener_candidatos_eleccion_por_id_privado(eleccion_id)
+            self.registrarse_privado(nombre, apellido, dni)
         }
-        pub fn obtener_candidatos_eleccion_por_id_privado(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,u32)>, String>
+        fn registrarse_privado(&mut self, nombre:String, apellido:String, dni:String) -> Result<()>
         {
-            if !self.es_generador_reportes() && !self.es_administrador() { return Err(String::from("No es el generador de reportes o no es el administrador!")); }
-            let block_timestamp = self.env().block_timestamp();
-
-            match self.obtener_eleccion_por_id(eleccion_id){
-                Some(eleccion) => {
-                    if eleccion.fecha_final > block_timestamp {
-                        return Err(String::from("La elección no finalizó, no puedes obtener los datos."));
-                    }
-                    
-                    Ok(eleccion.candidatos.iter().map(|candidato| (candidato.id, candidato.votos_totales)).collect())
-                },
-                None => Err(String::from("La eleccion enviada no existe!")),
-            }
+            let id = self.validar_nuevo_registro()?;
+            if self.proteccion_dni { return Err(Error::RequiereCompromisoDni); }
+            let (nombre, apellido) = Self::normalizar_nombre_y_apellido(&nombre, &apellido)?;
+            let dni = Self::normalizar_dni(&dni)?;
+            if self.cuentas_por_dni.contains(&dni) { return Err(Error::DniYaRegistrado); }
+
+            self.cuentas_por_dni.insert(&dni, &id);
+            self.encolar_usuario(Usuario { id, nombre, apellido, dni, compromiso_dni: None, huella_dni: None })
         }
 
-        /// Obtiene los resultados de una elección específica.
-        /// eleccion_id: u64: ID de la elección.
-        /// Result<Vec<(AccountId,u32)>, String>: Vector con el ID de cada candidato y su total de votos, o un mensaje de error.
+        /// Registra un nuevo usuario guardando solo el compromiso de su DNI, cuando está activada la protección del DNI.
+        /// - nombre: String - Nombre del usuario a registrar.
+        /// - apellido: String - Apellido del usuario a registrar.
+        /// - compromiso_dni: Hash - Resultado de `calcular_compromiso_dni` con el DNI del usuario y una sal propia de 32 bytes.
         /// Descripción:
-        /// La función obtiene la marca de tiempo del bloque actual y busca la elección por su ID. Si no encuentra la elección,
-        /// devuelve un error. Luego, intenta obtener los resultados de la votación usando la marca de tiempo. Si los resultados
-        /// aún no están disponibles, devuelve un error. Si están disponibles, devuelve una copia de los resultados.
+        /// Hace las mismas verificaciones que `registrarse`. Retorna Error::ProteccionDniNoActivada si no está activada la protección.
+        /// El compromiso se calcula fuera de la cadena para que ni el DNI ni la sal queden en una transacción. La sal debe ser aleatoria
+        /// y secreta: un DNI tiene solo 7 u 8 dígitos, así que quien conozca la sal lo recupera probando todos los DNI posibles.
+        /// El usuario muestra su DNI y su sal a un operador de padrón, que debe confirmarlo con `confirmar_dni` antes de aceptarlo.
         #[ink(message)]
-        pub fn obtener_resultados(&mut self, eleccion_id:u64) -> Result<Resultados, String> 
+        pub fn registrarse_con_compromiso_dni(&mut self, nombre:String, apellido:String, compromiso_dni:Hash) -> Result<()>
         {
-            self.obtener_resultados_privado(eleccion_id)
+            self.registrarse_con_compromiso_dni_privado(nombre, apellido, compromiso_dni)
         }
-        fn obtener_resultados_privado(&mut self,eleccion_id: u64) -> Result<Resultados, String> 
+        fn registrarse_con_compromiso_dni_privado(&mut self, nombre:String, apellido:String, compromiso_dni:Hash) -> Result<()>
         {
-            let block_timestamp= self.env().block_timestamp();
-            let eleccion = match self.obtener_eleccion_por_id(eleccion_id){
-                Some(eleccion) => eleccion,
-                None => return Err(String::from("No se encontró una elección con ese id."))
-            };
-    
-            match eleccion.obtener_resultados_votacion(block_timestamp) {
-                None => Err(String::from("Todavía no están los resultados de la elección publicados.")),
-                Some(resultados) => Ok(resultados.clone())
-            }
-        }
-    }
+            let id = self.validar_nuevo_registro()?;
+            if !self.proteccion_dni { return Err(Error::ProteccionDniNoActivada); }
+            let (nombre, apellido) = Self::normalizar_nombre_y_apellido(&nombre, &apellido)?;
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use ink::codegen::Env;
-        use ink::env::test::advance_block;
-        use ink::env::test::{ default_accounts, set_block_timestamp, DefaultAccounts };
-        use ink::env::DefaultEnvironment;
-        
-        fn get_default_test_accounts() -> DefaultAccounts<ink::env::DefaultEnvironment> 
+            // Cada usuario usa su propia sal, así que un DNI repetido recién se detecta al confirmar su huella
+            self.encolar_usuario(Usuario { id, nombre, apellido, dni: String::new(), compromiso_dni: Some(compromiso_dni), huella_dni: None })
+        }
+        //Verifica que el llamador pueda pedir su registro y retorna su cuenta
+        fn validar_nuevo_registro(&self) -> Result<AccountId>
         {
-            default_accounts::<ink::env::DefaultEnvironment>()
+            if !self.registro_activado { return Err(Error::RegistroNoActivado); }
+            let id = self.env().caller();
+            if self.es_administrador() { return Err(Error::AdministradorNoPuedeRegistrarse); }
+            if self.usuarios_rechazados.contains(id) && !self.tiene_apelacion_presentada(id, None) { return Err(Error::RegistroRechazado); }
+            if self.es_usuario_registrado() { return Err(Error::UsuarioYaRegistrado); }
+            if self.es_usuario_pendiente() { return Err(Error::UsuarioYaPendiente); }
+            Ok(id)
         }
-    
-        fn set_caller(caller: AccountId) 
+        //Agrega al usuario al final de la cola de usuarios pendientes
+        fn encolar_usuario(&mut self, usuario:Usuario) -> Result<()>
         {
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+            let posicion = self.posiciones_usuarios_pendientes.encolar()?;
+            self.usar_apelacion(usuario.id, None);
+            self.registrar_historial(usuario.id, None, AccionHistorial::Solicitud);
+            self.usuarios_pendientes.insert(usuario.id, &usuario);
+            self.cola_usuarios_pendientes.insert(posicion, &usuario.id);
+            self.posiciones_en_cola_usuarios.insert(usuario.id, &posicion);
+            self.env().emit_event(UsuarioRegistrado { usuario: usuario.id });
+            Ok(())
         }
-
-        fn set_contract(caller: AccountId) 
+        //Quita los espacios sobrantes del nombre y apellido y verifica su largo
+        fn normalizar_nombre_y_apellido(nombre:&str, apellido:&str) -> Result<(String, String)>
         {
-            ink::env::test::set_contract::<ink::env::DefaultEnvironment>(caller);
+            let (nombre, apellido) = (nombre.trim(), apellido.trim());
+            let nombre_valido = |texto:&str| !texto.is_empty() && texto.len() <= LARGO_MAXIMO_NOMBRE;
+            if !nombre_valido(nombre) || !nombre_valido(apellido) { return Err(Error::NombreInvalido); }
+            Ok((nombre.to_string(), apellido.to_string()))
         }
-
-        fn setup_eleccion() -> Eleccion 
+        //Libera el DNI o la huella del DNI del usuario para que otra cuenta pueda registrarlo
+        fn liberar_dni(&mut self, usuario:&Usuario)
         {
-            Eleccion {
-                id: 1,
-                candidatos: Vec::new(),
-                votantes: Vec::new(),
-                usuarios_rechazados: Vec::new(),
-                usuarios_pendientes: Vec::new(),
-                votacion_iniciada: false,
-                fecha_inicio: 0,
-                fecha_final: 0,
-                resultados:None
+            match usuario.huella_dni {
+                Some(huella) => self.cuentas_por_huella_dni.remove(huella),
+                None => self.cuentas_por_dni.remove(&usuario.dni),
             }
         }
-
-        fn crear_usuario(id: AccountId, nombre: &str, apellido: &str, dni: &str) -> Usuario 
+        //Quita los espacios sobrantes del DNI y verifica que tenga solo dígitos y un largo válido
+        fn normalizar_dni(dni:&str) -> Result<String>
         {
-            Usuario {
-                id,
-                nombre: nombre.to_string(),
-                apellido: apellido.to_string(),
-                dni: dni.to_string(),
+            let dni = dni.trim();
+            if !(LARGO_MINIMO_DNI..=LARGO_MAXIMO_DNI).contains(&dni.len()) || !dni.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(Error::DniInvalido);
             }
+            Ok(dni.to_string())
         }
 
-        fn crear_trabajo_final(administrador: AccountId) -> SistemaElecciones 
+        /// Solicita cambiar el nombre y el apellido del usuario que llama, por ejemplo después de un cambio de nombre legal.
+        /// - nombre: String - Nuevo nombre.
+        /// - apellido: String - Nuevo apellido.
+        /// Descripción:
+        /// Verifica si el llamador está registrado. Si no, retorna Error::UsuarioNoRegistrado.
+        /// Quita los espacios al principio y al final de los datos y retorna Error::NombreInvalido si quedan vacíos o superan LARGO_MAXIMO_NOMBRE bytes.
+        /// El cambio queda pendiente hasta que un operador de padrón o un administrador lo procese con `procesar_cambio_de_datos`,
+        /// y mientras tanto el usuario conserva sus datos. Una nueva solicitud reemplaza a la pendiente. El DNI no se puede cambiar.
+        #[ink(message)]
+        pub fn solicitar_cambio_de_datos(&mut self, nombre:String, apellido:String) -> Result<()>
         {
-            SistemaElecciones {
-                administrador,
-                generador_reportes: None,
-                registro_activado: false,
-                usuarios: Vec::new(),
-                usuarios_pendientes: Vec::new(),
-                usuarios_rechazados: Vec::new(),
-                elecciones: Vec::new(),
+            self.solicitar_cambio_de_datos_privado(nombre, apellido)
+        }
+        fn solicitar_cambio_de_datos_privado(&mut self, nombre:String, apellido:String) -> Result<()>
+        {
+            if !self.es_usuario_registrado() { return Err(Error::UsuarioNoRegistrado); }
+            let id = self.env().caller();
+            let (nombre, apellido) = Self::normalizar_nombre_y_apellido(&nombre, &apellido)?;
+
+            let cambio = CambioDeDatos { nombre, apellido };
+            self.cambios_de_datos_pendientes.insert(id, &cambio);
+            self.registrar_historial(id, None, AccionHistorial::SolicitudCambioDeDatos(cambio));
+            self.env().emit_event(CambioDeDatosSolicitado { usuario: id });
+            Ok(())
+        }
+
+        /// Da de baja al usuario que llama: deja de estar registrado y sale de las elecciones que todavía no llegaron a la votación.
+        /// Descripción:
+        /// Verifica si el llamador está registrado. Si no, retorna Error::UsuarioNoRegistrado.
+        /// En las elecciones en fase Borrador o Inscripcion se descartan sus solicitudes pendientes y deja de ser votante o candidato,
+        /// como con `retirarse_como_votante` y `retirar_candidatura`.
+        /// Las elecciones en votación, en escrutinio, finalizadas o canceladas no se modifican, así que se conservan sus votos emitidos
+        /// y puede revelar con `revelar_voto` los que comprometió en una elección de voto secreto.
+        /// Se descarta su cambio de datos pendiente y su DNI (o la huella del DNI) queda libre. Su historial se conserva.
+        /// Si sigue siendo votante o candidato de alguna elección, se conservan su nombre, apellido y DNI enmascarado
+        /// para que `obtener_informacion_usuario` y `obtener_informacion_candidato_eleccion` lo sigan identificando.
+        /// Emite RetiradoDeEleccion por cada elección de la que sale y UsuarioDadoDeBaja.
+        #[ink(message)]
+        pub fn darse_de_baja(&mut self) -> Result<()>
+        {
+            self.darse_de_baja_privado()
+        }
+        fn darse_de_baja_privado(&mut self) -> Result<()>
+        {
+            let id = self.env().caller();
+            let usuario = self.obtener_usuario(id).ok_or(Error::UsuarioNoRegistrado)?;
+            let block_timestamp = self.env().block_timestamp();
+
+            // Solo se recorren las elecciones en las que pidió ingresar
+            for eleccion_id in self.elecciones_de_usuario.get(id).unwrap_or_default() {
+                let Some(mut eleccion) = self.obtener_eleccion_por_id(eleccion_id) else { continue };
+                if Self::validar_antes_de_votacion(&mut eleccion, block_timestamp).is_err() { continue; }
+                if self.retirar_de_eleccion(&mut eleccion, id)?.is_some() { self.guardar_eleccion(&eleccion); }
             }
+
+            // Si sigue siendo votante o candidato de una elección ya iniciada se conserva su nombre para los resultados y reportes
+            let sigue_participando = self.elecciones_de_usuario.get(id).unwrap_or_default().into_iter()
+                .any(|eleccion_id| self.votantes.contains((eleccion_id, id)) || self.candidatos_por_cuenta.contains((eleccion_id, id)));
+            if sigue_participando { self.usuarios_dados_de_baja.insert(id, &usuario.dado_de_baja()); }
+
+            self.liberar_dni(&usuario);
+            // Su posición en usuarios_por_indice queda sin usuario y se omite al listarlos, aunque vuelva a registrarse
+            self.usuarios.remove(id);
+            self.indices_usuarios.remove(id);
+            self.cambios_de_datos_pendientes.remove(id);
+            self.registrar_historial(id, None, AccionHistorial::Baja);
+            self.env().emit_event(UsuarioDadoDeBaja { usuario: id });
+            Ok(())
         }
-    
-        // ====================== INICIO TESTS ERRORES ======================
-        // ====================== INICIO TESTS ERRORES ======================
-        // ====================== INICIO TESTS ERRORES ======================
+        
+        // ====-----==== METODOS ADMINISTRADOR ====----====
 
-        #[test]
-        fn test_no_es_administrador() 
+        /// Obtiene la información del siguiente usuario pendiente en la lista.
+        /// Result<UsuarioInfo>: La información del usuario o Error::NoHayUsuariosPendientes si no hay usuarios pendientes.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón de todo el sistema. Si no, retorna Error::NoEsOperadorPadron.
+        /// Obtiene al siguiente usuario pendiente en la lista de usuarios pendientes.
+        /// Retorna el nombre, apellido y DNI del usuario pendiente si existe.
+        /// Si no hay usuarios pendientes, retorna Error::NoHayUsuariosPendientes.
+        #[ink(message)]
+        pub fn obtener_informacion_siguiente_usuario_pendiente(&self) -> Result<UsuarioInfo>
         {
-            let error = ERRORES::NO_ES_ADMINISTRADOR;
-            assert_eq!(error.to_string(), "No eres el administrador.");
+            self.obtener_informacion_siguiente_usuario_pendiente_privado()
         }
-    
-        #[test]
-        fn test_usuario_no_registrado() 
+        fn obtener_informacion_siguiente_usuario_pendiente_privado(&self) -> Result<UsuarioInfo>
         {
-            let error = ERRORES::USUARIO_NO_REGISTRADO;
-            assert_eq!(error.to_string(), "No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.");
+            if !self.es_operador_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            let usuario = self.siguiente_usuario_pendiente().ok_or(Error::NoHayUsuariosPendientes)?;
+            Ok(UsuarioInfo::from(usuario))
         }
-            
-        #[test]
-        fn test_error_usuario_no_registrado() 
+        //Retorna el primer usuario de la cola que sigue pendiente, sin sacarlo de la cola
+        fn siguiente_usuario_pendiente(&self) -> Option<Usuario>
         {
-            let error = ERRORES::USUARIO_NO_REGISTRADO;
-            assert_eq!(error.to_string(), "No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.");
+            let cola = self.posiciones_usuarios_pendientes;
+            (cola.inicio..cola.fin)
+                .filter_map(|posicion| self.cola_usuarios_pendientes.get(posicion))
+                .find_map(|id| self.usuarios_pendientes.get(id))
         }
-    
-        // ====================== INICIO TESTS ELECCION ======================
-        // ====================== INICIO TESTS ELECCION ======================
-        // ====================== INICIO TESTS ELECCION ======================
 
-        #[test]
-        fn test_obtener_resultados_votacion() 
+        /// Busca la cuenta del usuario registrado o pendiente que declaró un DNI.
+        /// - dni: String - DNI a buscar. Se ignoran los espacios al principio y al final.
+        /// Retorno:
+        /// Result<AccountId>: La cuenta del usuario o el error correspondiente.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón, auditor, generador de reportes o administrador. Si no, retorna Error::NoEsOperadorPadron.
+        /// Si ningún usuario registrado o pendiente tiene ese DNI, retorna Error::UsuarioInexistente.
+        /// Con la protección del DNI activada los DNI no se guardan: se busca por la huella con `obtener_cuenta_por_huella_dni`.
+        #[ink(message)]
+        pub fn obtener_cuenta_por_dni(&self, dni:String) -> Result<AccountId>
         {
-            let administrador: AccountId = AccountId::from([0x1; 32]);
-            set_caller(administrador);
-
-            let mut contrato = SistemaElecciones::new();
-            set_block_timestamp::<ink::env::DefaultEnvironment>(1675666400000);
+            self.obtener_cuenta_por_dni_privado(dni)
+        }
+        fn obtener_cuenta_por_dni_privado(&self, dni:String) -> Result<AccountId>
+        {
+            if !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            self.cuentas_por_dni.get(dni.trim()).ok_or(Error::UsuarioInexistente)
+        }
+
+        /// Busca la cuenta del usuario registrado o pendiente cuyo DNI se confirmó con esa huella.
+        /// - huella_dni: Hash - Huella del DNI calculada con la sal del padrón, como en `confirmar_dni`.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón, auditor, generador de reportes o administrador. Si no, retorna Error::NoEsOperadorPadron.
+        /// Si ningún usuario registrado o pendiente tiene esa huella, retorna Error::UsuarioInexistente.
+        #[ink(message)]
+        pub fn obtener_cuenta_por_huella_dni(&self, huella_dni:Hash) -> Result<AccountId>
+        {
+            if !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            self.cuentas_por_huella_dni.get(huella_dni).ok_or(Error::UsuarioInexistente)
+        }
+        /// Procesa el siguiente usuario pendiente, aceptándolo o rechazándolo.
+        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario pendiente.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón de todo el sistema. Si no, retorna Error::NoEsOperadorPadron.
+        /// Obtiene al siguiente usuario pendiente en la lista. Si no hay usuarios pendientes, retorna Error::NoHayUsuariosPendientes.
+        /// Si se acepta al usuario, lo mueve desde la lista de usuarios pendientes a la lista de usuarios.
+        /// Si se registró con el compromiso del DNI y todavía no se confirmó su DNI con `confirmar_dni`, retorna Error::DniNoConfirmado.
+        /// Si se rechaza al usuario, lo agrega a la lista de usuarios rechazados.
+        #[ink(message)]
+        pub fn procesar_siguiente_usuario_pendiente(&mut self, aceptar_usuario:bool) -> Result<()>
+        {
+            self.procesar_siguiente_usuario_pendiente_privado(aceptar_usuario)
+        }
+        fn procesar_siguiente_usuario_pendiente_privado(&mut self, aceptar_usuario:bool) -> Result<()>
+        {
+            if !self.es_operador_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            if aceptar_usuario {
+                let usuario = self.siguiente_usuario_pendiente().ok_or(Error::NoHayUsuariosPendientes)?;
+                Self::validar_dni_confirmado(&usuario)?;
+            }
+            // Las posiciones de usuarios ya procesados en un lote se descartan
+            let id = loop {
+                let posicion = self.posiciones_usuarios_pendientes.desencolar().ok_or(Error::NoHayUsuariosPendientes)?;
+                if let Some(id) = self.cola_usuarios_pendientes.take(posicion) {
+                    if self.usuarios_pendientes.contains(id) { break id; }
+                }
+            };
+            self.procesar_usuario_pendiente(id, aceptar_usuario)
+        }
+        //Acepta o rechaza a un usuario pendiente del sistema, esté o no primero en la cola. Su posición en la cola se descarta al llegar al principio
+        fn procesar_usuario_pendiente(&mut self, id:AccountId, aceptar_usuario:bool) -> Result<()>
+        {
+            if !aceptar_usuario { return self.rechazar_usuario_pendiente(id, MotivoRechazo::default()); }
+            let usuario = self.usuarios_pendientes.get(id).ok_or(Error::UsuarioNoPendiente)?;
+            Self::validar_dni_confirmado(&usuario)?;
+            self.quitar_usuario_pendiente(id);
+            let indice = self.cantidad_usuarios;
+            self.cantidad_usuarios = indice.checked_add(1).ok_or(Error::Overflow)?;
+            self.usuarios.insert(id, &usuario);
+            self.usuarios_por_indice.insert(indice, &id);
+            self.indices_usuarios.insert(id, &indice);
+            self.usuarios_dados_de_baja.remove(id);
+            self.registrar_historial(id, None, AccionHistorial::Aceptacion);
+            self.env().emit_event(UsuarioAceptado { usuario: id, eleccion_id: None, tipo: None });
+            Ok(())
+        }
+        //Quita al usuario de los pendientes del sistema junto con su posición en la cola, para que una nueva solicitud lo liste una sola vez
+        fn quitar_usuario_pendiente(&mut self, id:AccountId)
+        {
+            if let Some(posicion) = self.posiciones_en_cola_usuarios.take(id) {
+                self.cola_usuarios_pendientes.remove(posicion);
+            }
+            self.usuarios_pendientes.remove(id);
+        }
+        //Verifica que el padrón haya confirmado el DNI de un usuario que se registró con su compromiso
+        fn validar_dni_confirmado(usuario:&Usuario) -> Result<()>
+        {
+            if usuario.compromiso_dni.is_some() && usuario.huella_dni.is_none() { return Err(Error::DniNoConfirmado); }
+            Ok(())
+        }
+        //Rechaza a un usuario pendiente del sistema con el motivo indicado
+        fn rechazar_usuario_pendiente(&mut self, id:AccountId, motivo:MotivoRechazo) -> Result<()>
+        {
+            let usuario = self.usuarios_pendientes.get(id).ok_or(Error::UsuarioNoPendiente)?;
+            let indice = self.cantidad_usuarios_rechazados;
+            self.cantidad_usuarios_rechazados = indice.checked_add(1).ok_or(Error::Overflow)?;
+            // El DNI de una solicitud rechazada queda libre para que su titular se registre desde otra cuenta
+            self.liberar_dni(&usuario);
+            self.quitar_usuario_pendiente(id);
+            self.usuarios_rechazados.insert(id, &indice);
+            self.usuarios_rechazados_por_indice.insert(indice, &id);
+            self.env().emit_event(UsuarioRechazado { usuario: id, eleccion_id: None, codigo: motivo.codigo });
+            self.registrar_historial(id, None, AccionHistorial::Rechazo(motivo));
+            Ok(())
+        }
+
+        /// Acepta o rechaza en una sola llamada a los usuarios pendientes del sistema indicados.
+        /// - cuentas: Vec<AccountId> - Usuarios a procesar, en cualquier posición de la cola. Como máximo MAXIMO_USUARIOS_POR_LOTE.
+        /// - aceptar_usuario: bool - Indica si se aceptan (true) o se rechazan (false) todos los usuarios.
+        /// Retorno:
+        /// Result<ResultadosPorCuenta>: El resultado de cada cuenta, en el mismo orden, o el error que impidió procesar el lote.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón de todo el sistema. Si no, retorna Error::NoEsOperadorPadron.
+        /// Retorna Error::LoteDemasiadoGrande si se envían más cuentas de las permitidas.
+        /// Una cuenta que no está pendiente no impide procesar las demás: su resultado es Error::UsuarioNoPendiente.
+        #[ink(message)]
+        pub fn procesar_usuarios_pendientes(&mut self, cuentas:Vec<AccountId>, aceptar_usuario:bool) -> Result<ResultadosPorCuenta>
+        {
+            self.procesar_usuarios_pendientes_privado(cuentas, aceptar_usuario)
+        }
+        fn procesar_usuarios_pendientes_privado(&mut self, cuentas:Vec<AccountId>, aceptar_usuario:bool) -> Result<ResultadosPorCuenta>
+        {
+            if !self.es_operador_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            if cuentas.len() > MAXIMO_USUARIOS_POR_LOTE { return Err(Error::LoteDemasiadoGrande); }
+            Ok(cuentas.into_iter().map(|id| (id, self.procesar_usuario_pendiente(id, aceptar_usuario))).collect())
+        }
+
+        /// Obtiene el cambio de nombre y apellido que pidió un usuario y todavía no se procesó.
+        /// - cuenta: AccountId - Usuario que pidió el cambio.
+        /// Descripción:
+        /// Verifica si el llamador es el propio usuario, o un operador de padrón, auditor, generador de reportes o administrador.
+        /// Si no, retorna Error::NoEsOperadorPadron. Retorna Error::CambioDeDatosInexistente si no hay un cambio pendiente.
+        #[ink(message)]
+        pub fn obtener_cambio_de_datos(&self, cuenta:AccountId) -> Result<CambioDeDatos>
+        {
+            self.obtener_cambio_de_datos_privado(cuenta)
+        }
+        fn obtener_cambio_de_datos_privado(&self, cuenta:AccountId) -> Result<CambioDeDatos>
+        {
+            if self.env().caller() != cuenta && !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            self.cambios_de_datos_pendientes.get(cuenta).ok_or(Error::CambioDeDatosInexistente)
+        }
+
+        /// Acepta o rechaza el cambio de nombre y apellido que pidió un usuario.
+        /// - cuenta: AccountId - Usuario que pidió el cambio.
+        /// - aceptar_cambio: bool - Indica si se reemplazan sus datos (true) o se descarta el cambio (false).
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón de todo el sistema. Si no, retorna Error::NoEsOperadorPadron.
+        /// Retorna Error::CambioDeDatosInexistente si el usuario no tiene un cambio pendiente.
+        /// El resultado queda en el historial del usuario y se emite CambioDeDatosProcesado.
+        #[ink(message)]
+        pub fn procesar_cambio_de_datos(&mut self, cuenta:AccountId, aceptar_cambio:bool) -> Result<()>
+        {
+            self.procesar_cambio_de_datos_privado(cuenta, aceptar_cambio)
+        }
+        fn procesar_cambio_de_datos_privado(&mut self, cuenta:AccountId, aceptar_cambio:bool) -> Result<()>
+        {
+            if !self.es_operador_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            let cambio = self.cambios_de_datos_pendientes.get(cuenta).ok_or(Error::CambioDeDatosInexistente)?;
+            let mut usuario = self.obtener_usuario(cuenta).ok_or(Error::UsuarioInexistente)?;
+
+            if aceptar_cambio {
+                usuario.nombre = cambio.nombre;
+                usuario.apellido = cambio.apellido;
+                self.usuarios.insert(cuenta, &usuario);
+                self.registrar_historial(cuenta, None, AccionHistorial::CambioDeDatosAceptado);
+            } else {
+                self.registrar_historial(cuenta, None, AccionHistorial::CambioDeDatosRechazado);
+            }
+            self.cambios_de_datos_pendientes.remove(cuenta);
+            self.env().emit_event(CambioDeDatosProcesado { usuario: cuenta, aceptado: aceptar_cambio });
+            Ok(())
+        }
+
+
+        // ====-----==== METODOS ELECCIONES ====----====
+        // ====-----==== METODOS ELECCIONES ====----====
+        // ====-----==== METODOS ELECCIONES ====----====
+
+        // ====-----==== METODOS ADMINISTRADOR ====----====
+
+        /// Crea una nueva elección con las fechas especificadas.
+        /// - fecha_inicial: String - Fecha y hora de inicio: "dd-mm-YYYY hh:mm" o ISO-8601 ("2024-07-01T09:00"), en UTC salvo que tengan
+        ///   un desplazamiento ("01-07-2024 09:00 -03:00", "01-07-2024 09:00 Etc/GMT+3", "2024-07-01T09:00:00-03:00"), o milisegundos.
+        /// - fecha_final: String - Fecha y hora de finalización, en los mismos formatos.
+        /// - configuracion: ConfiguracionEleccion - Opciones de la elección, como el voto secreto y el sistema de votación.
+        /// Result<u64>: El ID de la elección creada o el error correspondiente.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Parsea las fechas iniciales y finales proporcionadas en formato específico. Retorna Error::FechaInicialInvalida o Error::FechaFinalInvalida si el formato no es válido.
+        /// Retorna Error::OrdenDeFechasInvalido si la fecha inicial no es anterior a la final, Error::DuracionInsuficiente si la votación dura
+        /// menos que DURACION_MINIMA_VOTACION y Error::FechaEnElPasado si la fecha inicial no es posterior al timestamp del bloque actual.
+        /// Si la configuración indica voto secreto, parsea la fecha de fin de revelación. Retorna Error::FechaRevelacionInvalida si no es válida o no es posterior a la fecha final.
+        /// Retorna Error::BancasInvalidas si no hay bancas, si el voto limitado no permite aprobar candidatos o si una elección por preferencia instantánea tiene más de una banca.
+        /// Parsea las ventanas de inscripción de candidatos y votantes. Retorna Error::VentanaInscripcionInvalida si alguna fecha no es válida,
+        /// si la apertura no es anterior a ambos cierres o si algún cierre es posterior a la fecha de inicio.
+        /// Retorna Error::MetadatosInvalidos si el título, la descripción, el cargo o la jurisdicción superan su largo máximo
+        /// (LARGO_MAXIMO_TITULO, LARGO_MAXIMO_DESCRIPCION, LARGO_MAXIMO_CARGO y LARGO_MAXIMO_JURISDICCION bytes).
+        /// Genera un nuevo ID para la elección a partir de la cantidad de elecciones creadas.
+        /// Crea una nueva instancia de `Eleccion` con los parámetros dados y la guarda.
+        #[ink(message)]
+        pub fn crear_eleccion(&mut self, fecha_inicial:String, fecha_final:String, configuracion:ConfiguracionEleccion) -> Result<u64>
+        {
+            self.crear_eleccion_privado(fecha_inicial, fecha_final, configuracion)
+        }
+        fn crear_eleccion_privado(&mut self, fecha_inicial: String, fecha_final: String, configuracion: ConfiguracionEleccion) -> Result<u64>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.crear_eleccion_en_sistema(fecha_inicial, fecha_final, configuracion)
+        }
+        //Crea la elección una vez verificados los permisos, ya sea por un administrador o por una propuesta multifirma
+        fn crear_eleccion_en_sistema(&mut self, fecha_inicial: String, fecha_final: String, configuracion: ConfiguracionEleccion) -> Result<u64>
+        {
+            let eleccion_id = self.cantidad_elecciones.checked_add(1).ok_or(Error::Overflow)?;
+            let eleccion = Self::armar_eleccion(eleccion_id, self.env().block_timestamp(), fecha_inicial, fecha_final, configuracion)?;
+            self.guardar_eleccion(&eleccion);
+            self.cantidad_elecciones = eleccion_id;
+            self.env().emit_event(EleccionCreada {
+                eleccion_id,
+                fecha_inicio: eleccion.fecha_inicio,
+                fecha_final: eleccion.fecha_final,
+                voto_secreto: eleccion.es_voto_secreto(),
+                sistema_votacion: eleccion.sistema_votacion,
+                bancas: eleccion.bancas,
+                titulo: eleccion.metadatos.titulo,
+            });
+    
+            Ok(eleccion_id)
+        }
+        //Parsea y valida las fechas y la configuración de una elección, usado al crearla y al editarla
+        fn armar_eleccion(eleccion_id:u64, ahora:u64, fecha_inicial: String, fecha_final: String, configuracion: ConfiguracionEleccion) -> Result<Eleccion>
+        {
+            let fecha_inicio = Self::parsear_fecha(&fecha_inicial).ok_or(Error::FechaInicialInvalida)?;
+            let fecha_final = Self::parsear_fecha(&fecha_final).ok_or(Error::FechaFinalInvalida)?;
+            Self::validar_fechas(fecha_inicio, fecha_final, ahora)?;
+            let fecha_fin_revelacion = match configuracion.fin_revelacion {
+                None => None,
+                Some(fin_revelacion) => {
+                    let fecha = Self::parsear_fecha(&fin_revelacion).ok_or(Error::FechaRevelacionInvalida)?;
+                    if fecha <= fecha_final { return Err(Error::FechaRevelacionInvalida); }
+                    Some(fecha)
+                }
+            };
+            let bancas_validas = match configuracion.sistema_votacion {
+                SistemaVotacion::PreferenciaInstantanea => configuracion.bancas == 1,
+                SistemaVotacion::Limitado(maximo) => configuracion.bancas >= 1 && maximo >= 1,
+                SistemaVotacion::Mayoria | SistemaVotacion::Aprobacion => configuracion.bancas >= 1,
+            };
+            if !bancas_validas { return Err(Error::BancasInvalidas); }
+            if !configuracion.metadatos.es_valido() { return Err(Error::MetadatosInvalidos); }
+            let inicio_inscripcion = Self::parsear_fecha_inscripcion(configuracion.inicio_inscripcion, 0)?;
+            let cierre_inscripcion_candidatos = Self::parsear_fecha_inscripcion(configuracion.cierre_inscripcion_candidatos, fecha_inicio)?;
+            let cierre_inscripcion_votantes = Self::parsear_fecha_inscripcion(configuracion.cierre_inscripcion_votantes, fecha_inicio)?;
+            if inicio_inscripcion >= cierre_inscripcion_candidatos.min(cierre_inscripcion_votantes)
+                || cierre_inscripcion_candidatos.max(cierre_inscripcion_votantes) > fecha_inicio {
+                return Err(Error::VentanaInscripcionInvalida);
+            }
+
+            let mut eleccion = Eleccion::nueva(eleccion_id, fecha_inicio, fecha_final);
+            eleccion.fecha_fin_revelacion = fecha_fin_revelacion;
+            eleccion.inicio_inscripcion = inicio_inscripcion;
+            eleccion.cierre_inscripcion_candidatos = cierre_inscripcion_candidatos;
+            eleccion.cierre_inscripcion_votantes = cierre_inscripcion_votantes;
+            eleccion.sistema_votacion = configuracion.sistema_votacion;
+            eleccion.bancas = configuracion.bancas;
+            eleccion.metadatos = configuracion.metadatos;
+            Ok(eleccion)
+        }
+        //Parsea una fecha opcional de las ventanas de inscripción, usando el valor por defecto si es None
+        fn parsear_fecha_inscripcion(fecha:Option<String>, por_defecto:u64) -> Result<u64>
+        {
+            match fecha {
+                None => Ok(por_defecto),
+                Some(fecha) => Self::parsear_fecha(&fecha).ok_or(Error::VentanaInscripcionInvalida),
+            }
+        }
+        //Verifica que la votación empiece después de `ahora`, que la fecha inicial sea anterior a la final y que dure al menos DURACION_MINIMA_VOTACION
+        fn validar_fechas(fecha_inicio:u64, fecha_final:u64, ahora:u64) -> Result<()>
+        {
+            if fecha_inicio >= fecha_final { return Err(Error::OrdenDeFechasInvalido); }
+            if fecha_final - fecha_inicio < DURACION_MINIMA_VOTACION { return Err(Error::DuracionInsuficiente); }
+            if fecha_inicio <= ahora { return Err(Error::FechaEnElPasado); }
+            Ok(())
+        }
+        /// Convierte una fecha a milisegundos desde el 01-01-1970 UTC. Los dos formatos de texto siguen la misma regla:
+        /// sin desplazamiento la hora se toma en UTC. Acepta:
+        /// - "dd-mm-YYYY hh:mm", opcionalmente seguida de un desplazamiento: "01-07-2024 09:00 -03:00",
+        ///   "01-07-2024 09:00 UTC-3" o el nombre IANA de un desplazamiento fijo, "01-07-2024 09:00 Etc/GMT+3".
+        /// - ISO-8601, opcionalmente con desplazamiento: "2024-07-01T09:00:00-03:00", "2024-07-01T12:00Z", "2024-07-01T12:00".
+        /// - Milisegundos: "1719835200000".
+        fn parsear_fecha(fecha:&str) -> Option<u64>
+        {
+            let fecha = fecha.trim();
+            if !fecha.is_empty() && fecha.bytes().all(|caracter| caracter.is_ascii_digit()) {
+                return fecha.parse().ok();
+            }
+            if let Ok(fecha) = chrono::DateTime::parse_from_rfc3339(fecha) {
+                return u64::try_from(fecha.timestamp_millis()).ok();
+            }
+            if let Ok(fecha) = chrono::DateTime::parse_from_str(fecha, "%Y-%m-%dT%H:%M%#z") {
+                return u64::try_from(fecha.timestamp_millis()).ok();
+            }
+            if let Ok(fecha) = chrono::NaiveDateTime::parse_from_str(fecha, "%Y-%m-%dT%H:%M:%S")
+                .or_else(|_| chrono::NaiveDateTime::parse_from_str(fecha, "%Y-%m-%dT%H:%M"))
+            {
+                return u64::try_from(fecha.and_utc().timestamp_millis()).ok();
+            }
+
+            let (dia, resto) = fecha.split_once(' ')?;
+            let resto = resto.trim_start();
+            let (hora, desplazamiento) = match resto.split_once(' ') {
+                Some((hora, desplazamiento)) => (hora, Self::parsear_desplazamiento(desplazamiento.trim())?),
+                None => (resto, 0),
+            };
+            let fecha = chrono::NaiveDate::parse_from_str(dia, "%d-%m-%Y").ok()?
+                .and_time(chrono::NaiveTime::parse_from_str(hora, "%H:%M").ok()?);
+            let milisegundos = fecha.and_utc().timestamp_millis().checked_sub(desplazamiento.checked_mul(1000)?)?;
+            u64::try_from(milisegundos).ok()
+        }
+        //Convierte un desplazamiento respecto de UTC ("-03:00", "+0530", "UTC-3", "Z" o "Etc/GMT+3") a segundos al este de UTC
+        fn parsear_desplazamiento(desplazamiento:&str) -> Option<i64>
+        {
+            // En los nombres IANA "Etc/GMT+3" el signo está invertido: equivale a UTC-03:00
+            if let Some(horas) = desplazamiento.strip_prefix("Etc/GMT") {
+                return Self::parsear_desplazamiento(horas).map(|segundos| -segundos);
+            }
+            let desplazamiento = desplazamiento.strip_prefix("UTC").or_else(|| desplazamiento.strip_prefix("GMT")).unwrap_or(desplazamiento);
+            if desplazamiento.is_empty() || desplazamiento == "Z" { return Some(0); }
+
+            // Se usa strip_prefix y split_at_checked para no cortar un carácter de varios bytes si el texto no es ASCII
+            let (signo, resto) = match (desplazamiento.strip_prefix('+'), desplazamiento.strip_prefix('-')) {
+                (Some(resto), _) => (1, resto),
+                (_, Some(resto)) => (-1, resto),
+                _ => return None,
+            };
+            let (horas, minutos) = match resto.split_once(':') {
+                Some(partes) => partes,
+                None if resto.len() == 4 => resto.split_at_checked(2)?,
+                None => (resto, "0"),
+            };
+            let horas:u32 = horas.parse().ok()?;
+            let minutos:u32 = minutos.parse().ok()?;
+            if horas > 14 || minutos > 59 { return None; }
+            Some(signo * i64::from(horas * 3600 + minutos * 60))
+        }
+
+        /// Permite al administrador iniciar una votación si la fecha actual es posterior a la fecha inicial establecida y la votación aún no ha comenzado.
+        /// Inicia la votación de una elección.
+        /// - eleccion_id: u64 - ID de la elección que se desea iniciar.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Obtiene la elección correspondiente al ID proporcionado y verifica su fase:
+        /// - Si la votación ya terminó, retorna Error::VotacionFinalizada, y si la elección fue cancelada Error::EleccionCancelada.
+        /// - Si la votación ya está iniciada, retorna Error::VotacionYaIniciada.
+        /// - Si todavía no es la fecha de inicio de la elección, retorna Error::VotacionNoIniciada.
+        /// Si todas las condiciones son satisfactorias, pasa la elección a la fase de votación.
+        #[ink(message)]
+        pub fn iniciar_votacion(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            self.iniciar_votacion_privado(eleccion_id)
+        }
+        pub fn iniciar_votacion_privado(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.iniciar_votacion_en_sistema(eleccion_id)
+        }
+        //Inicia la votación una vez verificados los permisos, ya sea por un administrador o por una propuesta multifirma
+        fn iniciar_votacion_en_sistema(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            let block_timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            let estado_anterior = eleccion.estado;
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion => return Err(Error::VotacionNoIniciada),
+                EstadoEleccion::Votacion if estado_anterior == EstadoEleccion::Votacion => return Err(Error::VotacionYaIniciada),
+                EstadoEleccion::Votacion => (),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => return Err(Error::VotacionFinalizada),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+            }
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(VotacionIniciada { eleccion_id });
+            Ok(())
+        }
+
+        /// Edita las fechas, la configuración y los metadatos de una elección que todavía no abrió la inscripción.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - fecha_inicial: String - Nueva fecha y hora de inicio, en los formatos que acepta `crear_eleccion`.
+        /// - fecha_final: String - Nueva fecha y hora de finalización, en los mismos formatos.
+        /// - configuracion: ConfiguracionEleccion - Nuevas opciones y metadatos de la elección.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Solo se puede editar en la fase de borrador; si no, retorna Error::EleccionNoEditable, o Error::EleccionCancelada si fue cancelada.
+        /// Valida las fechas y la configuración igual que `crear_eleccion` y emite el evento EleccionEditada.
+        #[ink(message)]
+        pub fn editar_eleccion(&mut self, eleccion_id:u64, fecha_inicial:String, fecha_final:String, configuracion:ConfiguracionEleccion) -> Result<()>
+        {
+            self.editar_eleccion_privado(eleccion_id, fecha_inicial, fecha_final, configuracion)
+        }
+        fn editar_eleccion_privado(&mut self, eleccion_id:u64, fecha_inicial:String, fecha_final:String, configuracion:ConfiguracionEleccion) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.editar_eleccion_en_sistema(eleccion_id, fecha_inicial, fecha_final, configuracion)
+        }
+        //Edita la elección una vez verificados los permisos, ya sea por un administrador o por una propuesta multifirma
+        fn editar_eleccion_en_sistema(&mut self, eleccion_id:u64, fecha_inicial:String, fecha_final:String, configuracion:ConfiguracionEleccion) -> Result<()>
+        {
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            match eleccion.actualizar_estado(self.env().block_timestamp()) {
+                EstadoEleccion::Borrador => (),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+                _ => return Err(Error::EleccionNoEditable),
+            }
+            // En borrador nadie pudo inscribirse ni votar, así que la elección se arma de nuevo con los datos editados
+            let eleccion = Self::armar_eleccion(eleccion_id, self.env().block_timestamp(), fecha_inicial, fecha_final, configuracion)?;
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(EleccionEditada {
+                eleccion_id,
+                fecha_inicio: eleccion.fecha_inicio,
+                fecha_final: eleccion.fecha_final,
+            });
+            Ok(())
+        }
+
+        /// Posterga una elección que todavía no empezó la votación.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - fecha_inicial: String - Nueva fecha y hora de inicio, en los formatos que acepta `crear_eleccion`.
+        /// - fecha_final: String - Nueva fecha y hora de finalización, en los mismos formatos.
+        /// - motivo: String - Motivo de la postergación, se publica en el evento.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Retorna Error::VotacionYaIniciada si la votación ya empezó, Error::EleccionFinalizada si ya terminó y Error::EleccionCancelada si fue cancelada.
+        /// Retorna Error::FechaInicialInvalida o Error::FechaFinalInvalida si el formato no es válido, y Error::PostergacionInvalida
+        /// si alguna de las nuevas fechas es anterior a la que reemplaza. Las nuevas fechas se validan igual que en `crear_eleccion`.
+        /// Los cierres de inscripción se corren lo mismo que la fecha de inicio, y el fin de la revelación lo mismo que la fecha final.
+        /// Emite el evento EleccionPostergada.
+        #[ink(message)]
+        pub fn postergar_eleccion(&mut self, eleccion_id:u64, fecha_inicial:String, fecha_final:String, motivo:String) -> Result<()>
+        {
+            self.postergar_eleccion_privado(eleccion_id, fecha_inicial, fecha_final, motivo)
+        }
+        fn postergar_eleccion_privado(&mut self, eleccion_id:u64, fecha_inicial:String, fecha_final:String, motivo:String) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.postergar_eleccion_en_sistema(eleccion_id, fecha_inicial, fecha_final, motivo)
+        }
+        //Posterga la elección una vez verificados los permisos, ya sea por un administrador o por una propuesta multifirma
+        fn postergar_eleccion_en_sistema(&mut self, eleccion_id:u64, fecha_inicial:String, fecha_final:String, motivo:String) -> Result<()>
+        {
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            match eleccion.actualizar_estado(self.env().block_timestamp()) {
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion => (),
+                EstadoEleccion::Votacion => return Err(Error::VotacionYaIniciada),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => return Err(Error::EleccionFinalizada),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+            }
+            let fecha_inicio = Self::parsear_fecha(&fecha_inicial).ok_or(Error::FechaInicialInvalida)?;
+            let fecha_final = Self::parsear_fecha(&fecha_final).ok_or(Error::FechaFinalInvalida)?;
+            if fecha_inicio < eleccion.fecha_inicio || fecha_final < eleccion.fecha_final {
+                return Err(Error::PostergacionInvalida);
+            }
+            Self::validar_fechas(fecha_inicio, fecha_final, self.env().block_timestamp())?;
+
+            let corrimiento_inicio = fecha_inicio - eleccion.fecha_inicio;
+            let corrimiento_final = fecha_final - eleccion.fecha_final;
+            eleccion.cierre_inscripcion_candidatos = eleccion.cierre_inscripcion_candidatos.checked_add(corrimiento_inicio).ok_or(Error::Overflow)?;
+            eleccion.cierre_inscripcion_votantes = eleccion.cierre_inscripcion_votantes.checked_add(corrimiento_inicio).ok_or(Error::Overflow)?;
+            eleccion.fecha_fin_revelacion = eleccion.fecha_fin_revelacion
+                .map(|fin_revelacion| fin_revelacion.checked_add(corrimiento_final).ok_or(Error::Overflow))
+                .transpose()?;
+            eleccion.fecha_inicio = fecha_inicio;
+            eleccion.fecha_final = fecha_final;
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(EleccionPostergada { eleccion_id, fecha_inicio, fecha_final, motivo });
+            Ok(())
+        }
+
+        /// Cancela una elección que todavía no finalizó. Una vez cancelada no se puede ingresar, votar ni obtener sus resultados.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - motivo: String - Motivo de la cancelación, se publica en el evento.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Retorna Error::EleccionFinalizada si la elección ya finalizó y Error::EleccionCancelada si ya fue cancelada.
+        /// Emite el evento EleccionCancelada.
+        #[ink(message)]
+        pub fn cancelar_eleccion(&mut self, eleccion_id:u64, motivo:String) -> Result<()>
+        {
+            self.cancelar_eleccion_privado(eleccion_id, motivo)
+        }
+        fn cancelar_eleccion_privado(&mut self, eleccion_id:u64, motivo:String) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.cancelar_eleccion_en_sistema(eleccion_id, motivo)
+        }
+        //Cancela la elección una vez verificados los permisos, ya sea por un administrador o por una propuesta multifirma
+        fn cancelar_eleccion_en_sistema(&mut self, eleccion_id:u64, motivo:String) -> Result<()>
+        {
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            match eleccion.actualizar_estado(self.env().block_timestamp()) {
+                EstadoEleccion::Finalizada => return Err(Error::EleccionFinalizada),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+                _ => (),
+            }
+            eleccion.cancelar();
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(EleccionCancelada { eleccion_id, motivo });
+            Ok(())
+        }
+
+        /// Permite al administrador obtener información del siguiente usuario pendiente en una elección específica.
+        /// Obtiene información del siguiente usuario pendiente en una elección.
+        /// - eleccion_id: u64 - ID de la elección de la cual se desea obtener el siguiente usuario pendiente
+        /// Result<SolicitudPendiente>: La cuenta del usuario pendiente y el tipo con el que se inscribió, o el error correspondiente.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón del sistema o de la elección. Si no, retorna Error::NoEsOperadorPadron.
+        /// Obtiene la elección correspondiente al ID proporcionado y luego obtiene el primer usuario pendiente
+        /// en la cola de usuarios pendientes de esa elección, indicando si desea ser un VOTANTE o un CANDIDATO.
+        /// Retorna Error::NoHayUsuariosPendientes si no hay usuarios pendientes.
+        #[ink(message)]
+        pub fn obtener_siguiente_usuario_pendiente_en_una_eleccion(&self, eleccion_id:u64) -> Result<SolicitudPendiente>
+        {
+            self.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(eleccion_id)
+        }
+        pub fn obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(&self, eleccion_id:u64) -> Result<SolicitudPendiente>
+        {
+            if !self.es_operador_padron(Some(eleccion_id)) { return Err(Error::NoEsOperadorPadron); }
+            let eleccion_elegida = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            let cola = eleccion_elegida.cola_pendientes;
+            (cola.inicio..cola.fin)
+                .filter_map(|posicion| self.cola_pendientes_eleccion.get((eleccion_id, posicion)))
+                .find_map(|usuario| self.pendientes_eleccion.get((eleccion_id, usuario)).map(|tipo| SolicitudPendiente { usuario, tipo }))
+                .ok_or(Error::NoHayUsuariosPendientes)
+        }
+        
+        /// Permite al administrador procesar el siguiente usuario pendiente en una elección específica.
+        /// Parámetros:
+        /// - eleccion_id: u64 - ID de la elección en la que se procesarán los usuarios pendientes.
+        /// - aceptar_usuario: bool - Indica si se acepta (true) o se rechaza (false) al usuario pendiente.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón del sistema o de la elección. Si no, retorna Error::NoEsOperadorPadron.
+        /// Obtiene la elección correspondiente al ID proporcionado. Luego, procesa el siguiente usuario pendiente
+        /// en la elección según la acción indicada (aceptar o rechazar) y retorna el resultado del procesamiento.
+        #[ink(message)]
+        pub fn procesar_usuarios_en_una_eleccion(&mut self, eleccion_id:u64,aceptar_usuario:bool) -> Result<()>
+        {
+            self.procesar_usuarios_en_una_eleccion_privado(eleccion_id,aceptar_usuario)
+        }
+        pub fn procesar_usuarios_en_una_eleccion_privado(&mut self, eleccion_id:u64,aceptar_usuario:bool) -> Result<()>
+        {
+            if !self.es_operador_padron(Some(eleccion_id)) { return Err(Error::NoEsOperadorPadron); }
+            
+            let mut eleccion_elegida = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_padron_abierto(&mut eleccion_elegida, self.env().block_timestamp())?;
+            let (usuario, tipo) = self.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion_elegida, aceptar_usuario)?;
+            self.guardar_eleccion(&eleccion_elegida);
+            self.emitir_usuario_procesado_en_eleccion(eleccion_id, usuario, tipo, aceptar_usuario);
+            Ok(())
+        }
+        fn emitir_usuario_procesado_en_eleccion(&self, eleccion_id:u64, usuario:AccountId, tipo:TIPO_DE_USUARIO, aceptar_usuario:bool)
+        {
+            if aceptar_usuario {
+                self.env().emit_event(UsuarioAceptado { usuario, eleccion_id: Some(eleccion_id), tipo: Some(tipo) });
+            } else {
+                self.env().emit_event(UsuarioRechazado { usuario, eleccion_id: Some(eleccion_id), codigo: CodigoRechazo::SinEspecificar });
+            }
+        }
+
+        /// Acepta o rechaza en una sola llamada a los usuarios pendientes de una elección indicados.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - cuentas: Vec<AccountId> - Usuarios a procesar, en cualquier posición de la cola. Como máximo MAXIMO_USUARIOS_POR_LOTE.
+        /// - aceptar_usuario: bool - Indica si se aceptan (true) o se rechazan (false) todos los usuarios.
+        /// Retorno:
+        /// Result<ResultadosPorCuenta>: El resultado de cada cuenta, en el mismo orden, o el error que impidió procesar el lote.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón del sistema o de la elección. Si no, retorna Error::NoEsOperadorPadron.
+        /// Retorna Error::EleccionInexistente si la elección no existe y Error::LoteDemasiadoGrande si se envían más cuentas de las permitidas.
+        /// Una cuenta que no está pendiente en la elección no impide procesar las demás: su resultado es Error::UsuarioNoPendiente.
+        #[ink(message)]
+        pub fn procesar_usuarios_pendientes_en_eleccion(&mut self, eleccion_id:u64, cuentas:Vec<AccountId>, aceptar_usuario:bool) -> Result<ResultadosPorCuenta>
+        {
+            self.procesar_usuarios_pendientes_en_eleccion_privado(eleccion_id, cuentas, aceptar_usuario)
+        }
+        fn procesar_usuarios_pendientes_en_eleccion_privado(&mut self, eleccion_id:u64, cuentas:Vec<AccountId>, aceptar_usuario:bool) -> Result<ResultadosPorCuenta>
+        {
+            if !self.es_operador_padron(Some(eleccion_id)) { return Err(Error::NoEsOperadorPadron); }
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_padron_abierto(&mut eleccion, self.env().block_timestamp())?;
+            if cuentas.len() > MAXIMO_USUARIOS_POR_LOTE { return Err(Error::LoteDemasiadoGrande); }
+
+            let mut resultados = Vec::with_capacity(cuentas.len());
+            for usuario in cuentas {
+                let resultado = self.procesar_usuario_pendiente_en_eleccion(&mut eleccion, usuario, aceptar_usuario)
+                    .map(|tipo| self.emitir_usuario_procesado_en_eleccion(eleccion_id, usuario, tipo, aceptar_usuario));
+                resultados.push((usuario, resultado));
+            }
+            self.guardar_eleccion(&eleccion);
+            Ok(resultados)
+        }
+
+        /// Rechaza a un usuario pendiente indicando el motivo, que queda en su historial.
+        /// - cuenta: AccountId - Usuario a rechazar, en cualquier posición de la cola.
+        /// - eleccion_id: Option<u64> - None para rechazar su registro en el sistema, o la elección en la que está pendiente.
+        /// - motivo: MotivoRechazo - Código y detalle del rechazo.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón del sistema o, si se indica, de la elección. Si no, retorna Error::NoEsOperadorPadron.
+        /// Retorna Error::MotivoInvalido si el detalle supera LARGO_MAXIMO_MOTIVO bytes y Error::UsuarioNoPendiente si la cuenta no está pendiente.
+        /// En una elección retorna Error::EleccionInexistente si no existe, y Error::EleccionFinalizada o Error::EleccionCancelada
+        /// si ya no se puede modificar su padrón.
+        /// Los rechazos de los demás mensajes de procesamiento quedan registrados con CodigoRechazo::SinEspecificar.
+        #[ink(message)]
+        pub fn rechazar_usuario(&mut self, cuenta:AccountId, eleccion_id:Option<u64>, motivo:MotivoRechazo) -> Result<()>
+        {
+            self.rechazar_usuario_privado(cuenta, eleccion_id, motivo)
+        }
+        fn rechazar_usuario_privado(&mut self, cuenta:AccountId, eleccion_id:Option<u64>, motivo:MotivoRechazo) -> Result<()>
+        {
+            if !self.es_operador_padron(eleccion_id) { return Err(Error::NoEsOperadorPadron); }
+            if motivo.detalle.len() > LARGO_MAXIMO_MOTIVO { return Err(Error::MotivoInvalido); }
+            let eleccion_id = match eleccion_id {
+                None => return self.rechazar_usuario_pendiente(cuenta, motivo),
+                Some(eleccion_id) => eleccion_id,
+            };
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_padron_abierto(&mut eleccion, self.env().block_timestamp())?;
+            if !self.pendientes_eleccion.contains((eleccion_id, cuenta)) { return Err(Error::UsuarioNoPendiente); }
+            let codigo = motivo.codigo;
+            self.registrar_rechazo_en_eleccion(&mut eleccion, cuenta, motivo)?;
+            self.quitar_pendiente_eleccion(eleccion_id, cuenta);
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(UsuarioRechazado { usuario: cuenta, eleccion_id: Some(eleccion_id), codigo });
+            Ok(())
+        }
+
+        /// Utilizado por un administrador.
+        /// Rehabilita a una cuenta rechazada: deja de estar rechazada y puede volver a enviar su solicitud como si no la hubieran rechazado.
+        /// - cuenta: AccountId - Cuenta rechazada.
+        /// - eleccion_id: Option<u64> - None para rehabilitar su registro en el sistema, o la elección en la que fue rechazada.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::EleccionInexistente si la elección no existe, Error::UsuarioNoRechazado si la cuenta no está rechazada
+        /// y Error::UsuarioBloqueado si fue bloqueada. La cuenta recupera su apelación y el historial conserva los rechazos anteriores.
+        #[ink(message)]
+        pub fn rehabilitar_usuario(&mut self, cuenta:AccountId, eleccion_id:Option<u64>) -> Result<()>
+        {
+            self.rehabilitar_usuario_privado(cuenta, eleccion_id)
+        }
+        fn rehabilitar_usuario_privado(&mut self, cuenta:AccountId, eleccion_id:Option<u64>) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            self.validar_rechazo(cuenta, eleccion_id)?;
+            if self.reconsideraciones.get((eleccion_id, cuenta)) == Some(Reconsideracion::Bloqueado) { return Err(Error::UsuarioBloqueado); }
+
+            self.quitar_rechazo(cuenta, eleccion_id);
+            self.reconsideraciones.remove((eleccion_id, cuenta));
+            self.registrar_historial(cuenta, eleccion_id, AccionHistorial::Rehabilitacion);
+            self.env().emit_event(UsuarioRehabilitado { usuario: cuenta, eleccion_id });
+            Ok(())
+        }
+
+        /// Utilizado por un administrador.
+        /// Bloquea definitivamente a una cuenta rechazada: no puede apelar ni ser rehabilitada.
+        /// - cuenta: AccountId - Cuenta rechazada.
+        /// - eleccion_id: Option<u64> - None para bloquear su registro en el sistema, o la elección en la que fue rechazada.
+        /// - motivo: MotivoRechazo - Código y detalle del bloqueo.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::MotivoInvalido si el detalle supera LARGO_MAXIMO_MOTIVO bytes, Error::EleccionInexistente si la elección no existe,
+        /// Error::UsuarioNoRechazado si la cuenta no está rechazada y Error::UsuarioBloqueado si ya fue bloqueada.
+        /// Una cuenta que volvió a enviar su solicitud tras apelar primero debe ser rechazada otra vez.
+        #[ink(message)]
+        pub fn bloquear_usuario(&mut self, cuenta:AccountId, eleccion_id:Option<u64>, motivo:MotivoRechazo) -> Result<()>
+        {
+            self.bloquear_usuario_privado(cuenta, eleccion_id, motivo)
+        }
+        fn bloquear_usuario_privado(&mut self, cuenta:AccountId, eleccion_id:Option<u64>, motivo:MotivoRechazo) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if motivo.detalle.len() > LARGO_MAXIMO_MOTIVO { return Err(Error::MotivoInvalido); }
+            self.validar_rechazo(cuenta, eleccion_id)?;
+            if self.reconsideraciones.get((eleccion_id, cuenta)) == Some(Reconsideracion::Bloqueado) { return Err(Error::UsuarioBloqueado); }
+
+            let codigo = motivo.codigo;
+            self.reconsideraciones.insert((eleccion_id, cuenta), &Reconsideracion::Bloqueado);
+            self.registrar_historial(cuenta, eleccion_id, AccionHistorial::Bloqueo(motivo));
+            self.env().emit_event(UsuarioBloqueado { usuario: cuenta, eleccion_id, codigo });
+            Ok(())
+        }
+        //Verifica que la cuenta esté rechazada en el sistema o en la elección indicada
+        fn validar_rechazo(&self, cuenta:AccountId, eleccion_id:Option<u64>) -> Result<()>
+        {
+            let rechazado = match eleccion_id {
+                None => self.usuarios_rechazados.contains(cuenta),
+                Some(eleccion_id) => {
+                    if !self.existe_eleccion(eleccion_id) { return Err(Error::EleccionInexistente); }
+                    self.rechazados_eleccion.contains((eleccion_id, cuenta))
+                },
+            };
+            if rechazado { Ok(()) } else { Err(Error::UsuarioNoRechazado) }
+        }
+        //Saca a la cuenta de los rechazados. Su posición en la lista de rechazados queda sin usuario y se omite al listarla
+        fn quitar_rechazo(&mut self, cuenta:AccountId, eleccion_id:Option<u64>)
+        {
+            match eleccion_id {
+                None => self.usuarios_rechazados.remove(cuenta),
+                Some(eleccion_id) => self.rechazados_eleccion.remove((eleccion_id, cuenta)),
+            }
+        }
+        //Verifica si la cuenta apeló su rechazo y todavía no volvió a enviar la solicitud
+        fn tiene_apelacion_presentada(&self, cuenta:AccountId, eleccion_id:Option<u64>) -> bool
+        {
+            self.reconsideraciones.get((eleccion_id, cuenta)) == Some(Reconsideracion::ApelacionPresentada)
+        }
+        //Al volver a enviar la solicitud tras apelar, la cuenta deja de estar rechazada y ya no puede volver a apelar
+        fn usar_apelacion(&mut self, cuenta:AccountId, eleccion_id:Option<u64>)
+        {
+            if !self.tiene_apelacion_presentada(cuenta, eleccion_id) { return; }
+            self.quitar_rechazo(cuenta, eleccion_id);
+            self.reconsideraciones.insert((eleccion_id, cuenta), &Reconsideracion::ApelacionUsada);
+        }
+        //Agrega una entrada al historial de la cuenta, con el llamador como autor
+        fn registrar_historial(&mut self, cuenta:AccountId, eleccion_id:Option<u64>, accion:AccionHistorial)
+        {
+            let indice = self.cantidad_historial_usuarios.get(cuenta).unwrap_or(0);
+            let registro = RegistroHistorial { eleccion_id, accion, autor: self.env().caller(), fecha: self.env().block_timestamp() };
+            self.historial_usuarios.insert((cuenta, indice), &registro);
+            self.cantidad_historial_usuarios.insert(cuenta, &indice.saturating_add(1));
+        }
+
+        // ====-----==== METODOS PÚBLICOS ====----====
+
+        /// Ingresa al usuario actual a una elección como pendiente de aprobación.
+        /// - eleccion_id: u64 - ID de la elección a la que se desea ingresar.
+        /// - tipo: TIPO_DE_USUARIO - Tipo de usuario que desea ingresar (VOTANTE o CANDIDATO).
+        /// Descripción:
+        /// Verifica si el usuario está registrado. Obtiene el ID del usuario actual y el timestamp actual del bloque.
+        /// Valida el estado actual de la elección y verifica si el usuario ya ha sido rechazado previamente o si ya está inscripto.
+        /// Un usuario rechazado puede volver a ingresar una vez si apeló el rechazo con `apelar_rechazo`.
+        /// Si ya cerró la ventana de inscripción del tipo solicitado (candidatos o votantes), retorna Error::InscripcionCerrada.
+        /// Luego, agrega al usuario como pendiente en la elección. Los candidatos que ingresan así tienen un perfil vacío;
+        /// para presentarse con partido, lista y plataforma se usa `ingresar_como_candidato`.
+        #[ink(message)]
+        pub fn ingresar_a_eleccion(&mut self, eleccion_id:u64, tipo:TIPO_DE_USUARIO) -> Result<()>
+        {
+            self.ingresar_a_eleccion_privado(eleccion_id,tipo)
+        }
+        fn ingresar_a_eleccion_privado(&mut self, eleccion_id:u64, tipo:TIPO_DE_USUARIO) -> Result<()>
+        {
+            self.solicitar_ingreso_a_eleccion(eleccion_id, tipo, PerfilCandidato::default())
+        }
+        //Agrega la elección a las del usuario, si todavía no está
+        fn agregar_eleccion_de_usuario(&mut self, cuenta:AccountId, eleccion_id:u64)
+        {
+            let mut elecciones = self.elecciones_de_usuario.get(cuenta).unwrap_or_default();
+            if !elecciones.contains(&eleccion_id) {
+                elecciones.push(eleccion_id);
+                self.elecciones_de_usuario.insert(cuenta, &elecciones);
+            }
+        }
+        //Agrega al llamador como pendiente en la elección. El perfil solo se guarda si se postula como candidato
+        fn solicitar_ingreso_a_eleccion(&mut self, eleccion_id:u64, tipo:TIPO_DE_USUARIO, perfil:PerfilCandidato) -> Result<()>
+        {
+            if !self.es_usuario_registrado() { return Err(Error::UsuarioNoRegistrado); }
+            let id = self.env().caller();
+
+            let block_timestamp = self.env().block_timestamp();
+            let mut eleccion = self.validar_estado_eleccion(eleccion_id, block_timestamp, id)?;
+            if block_timestamp >= eleccion.cierre_inscripcion(&tipo) { return Err(Error::InscripcionCerrada); }
+            //Validar que un usuario que ya ha sido rechazado en la misma eleccion no intente volver a ponerse como pendiente, salvo que haya apelado
+            if self.rechazados_eleccion.contains((eleccion_id, id)) && !self.tiene_apelacion_presentada(id, Some(eleccion_id)) {
+                return Err(Error::RechazadoEnEleccion);
+            }
+
+            let posicion = eleccion.cola_pendientes.encolar()?;
+            self.usar_apelacion(id, Some(eleccion_id));
+            self.registrar_historial(id, Some(eleccion_id), AccionHistorial::Solicitud);
+            self.cola_pendientes_eleccion.insert((eleccion_id, posicion), &id);
+            self.posiciones_pendientes_eleccion.insert((eleccion_id, id), &posicion);
+            self.pendientes_eleccion.insert((eleccion_id, id), &tipo);
+            self.agregar_eleccion_de_usuario(id, eleccion_id);
+            if tipo == TIPO_DE_USUARIO::CANDIDATO {
+                self.perfiles_candidatos.insert((eleccion_id, id), &perfil);
+            }
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(IngresoAEleccionSolicitado { usuario: id, eleccion_id, tipo });
+            Ok(())
+        }
+
+        /// Ingresa al usuario actual a una elección como candidato pendiente de aprobación, junto con su perfil.
+        /// - eleccion_id: u64 - ID de la elección a la que se desea ingresar.
+        /// - perfil: PerfilCandidato - Partido, número de lista, nombre en la boleta, plataforma y hash del contenido fuera de la cadena.
+        /// Descripción:
+        /// Hace las mismas validaciones que `ingresar_a_eleccion` con TIPO_DE_USUARIO::CANDIDATO y además retorna
+        /// Error::PerfilCandidatoInvalido si algún texto del perfil supera su largo máximo (LARGO_MAXIMO_PARTIDO, LARGO_MAXIMO_NOMBRE_BOLETA
+        /// y LARGO_MAXIMO_PLATAFORMA bytes).
+        /// El perfil se publica recién cuando un operador de padrón o administrador acepta la candidatura; si la rechaza, se descarta.
+        #[ink(message)]
+        pub fn ingresar_como_candidato(&mut self, eleccion_id:u64, perfil:PerfilCandidato) -> Result<()>
+        {
+            self.ingresar_como_candidato_privado(eleccion_id, perfil)
+        }
+        fn ingresar_como_candidato_privado(&mut self, eleccion_id:u64, perfil:PerfilCandidato) -> Result<()>
+        {
+            if !perfil.es_valido() { return Err(Error::PerfilCandidatoInvalido); }
+            self.solicitar_ingreso_a_eleccion(eleccion_id, TIPO_DE_USUARIO::CANDIDATO, perfil)
+        }
+
+        /// Retira la candidatura del llamador en una elección, antes de que empiece la votación.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// Descripción:
+        /// Retorna Error::EleccionInexistente si la elección no existe. Si la votación ya comenzó retorna Error::VotacionYaIniciada,
+        /// si ya terminó Error::EleccionFinalizada y si fue cancelada Error::EleccionCancelada.
+        /// Retorna Error::NoEsCandidato si el llamador no es candidato ni tiene una candidatura pendiente en la elección.
+        /// Una candidatura pendiente se descarta. Un candidato aceptado conserva su candidato_id, que no se reasigna ni corre
+        /// los IDs de los demás candidatos, pero deja de aparecer en los listados y en los resultados. Emite RetiradoDeEleccion.
+        #[ink(message)]
+        pub fn retirar_candidatura(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            self.retirar_candidatura_privado(eleccion_id)
+        }
+        fn retirar_candidatura_privado(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            self.retirarse_de_eleccion(eleccion_id, TIPO_DE_USUARIO::CANDIDATO)
+        }
+
+        /// Retira al llamador del padrón de una elección, antes de que empiece la votación.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// Descripción:
+        /// Hace las mismas validaciones de la elección que `retirar_candidatura`. Retorna Error::NoRegistradoEnEleccion si el llamador
+        /// no es votante ni tiene una solicitud pendiente como votante en la elección.
+        /// Se revocan las delegaciones que recibió, así que cada votante que había delegado en él recupera su voto, y la suya.
+        /// Emite RetiradoDeEleccion.
+        #[ink(message)]
+        pub fn retirarse_como_votante(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            self.retirarse_como_votante_privado(eleccion_id)
+        }
+        fn retirarse_como_votante_privado(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            self.retirarse_de_eleccion(eleccion_id, TIPO_DE_USUARIO::VOTANTE)
+        }
+        //Saca al llamador de la elección si participa, aceptado o pendiente, con el tipo indicado
+        fn retirarse_de_eleccion(&mut self, eleccion_id:u64, tipo:TIPO_DE_USUARIO) -> Result<()>
+        {
+            let id = self.env().caller();
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_antes_de_votacion(&mut eleccion, self.env().block_timestamp())?;
+            let (inscripto, error) = match tipo {
+                TIPO_DE_USUARIO::VOTANTE => (self.votantes.contains((eleccion_id, id)), Error::NoRegistradoEnEleccion),
+                TIPO_DE_USUARIO::CANDIDATO => (self.candidatos_por_cuenta.contains((eleccion_id, id)), Error::NoEsCandidato),
+            };
+            if !inscripto && self.pendientes_eleccion.get((eleccion_id, id)) != Some(tipo) { return Err(error); }
+
+            self.retirar_de_eleccion(&mut eleccion, id)?;
+            self.guardar_eleccion(&eleccion);
+            Ok(())
+        }
+
+        /// Apela el rechazo del llamador en el sistema o en una elección.
+        /// - eleccion_id: Option<u64> - None para apelar el rechazo del registro en el sistema, o la elección en la que fue rechazado.
+        /// - descargo: String - Explicación del usuario, de hasta LARGO_MAXIMO_MOTIVO bytes.
+        /// Descripción:
+        /// Retorna Error::MotivoInvalido si el descargo es demasiado largo, Error::EleccionInexistente si la elección no existe
+        /// y Error::UsuarioNoRechazado si el llamador no está rechazado. Cada cuenta puede apelar una sola vez su rechazo en el sistema
+        /// y una vez el de cada elección: si ya apeló retorna Error::ApelacionYaPresentada, y si fue bloqueada Error::UsuarioBloqueado.
+        /// La apelación permite volver a enviar la solicitud con los datos corregidos: con `registrarse` o `registrarse_con_compromiso_dni`
+        /// en el sistema, o con `ingresar_a_eleccion` o `ingresar_como_candidato` en la elección. La nueva solicitud vuelve a la cola
+        /// de pendientes y, si se rechaza otra vez, solo un administrador puede rehabilitar la cuenta.
+        #[ink(message)]
+        pub fn apelar_rechazo(&mut self, eleccion_id:Option<u64>, descargo:String) -> Result<()>
+        {
+            self.apelar_rechazo_privado(eleccion_id, descargo)
+        }
+        fn apelar_rechazo_privado(&mut self, eleccion_id:Option<u64>, descargo:String) -> Result<()>
+        {
+            if descargo.len() > LARGO_MAXIMO_MOTIVO { return Err(Error::MotivoInvalido); }
+            let id = self.env().caller();
+            self.validar_rechazo(id, eleccion_id)?;
+            match self.reconsideraciones.get((eleccion_id, id)) {
+                Some(Reconsideracion::Bloqueado) => return Err(Error::UsuarioBloqueado),
+                Some(_) => return Err(Error::ApelacionYaPresentada),
+                None => (),
+            }
+
+            self.reconsideraciones.insert((eleccion_id, id), &Reconsideracion::ApelacionPresentada);
+            self.registrar_historial(id, eleccion_id, AccionHistorial::Apelacion(descargo));
+            self.env().emit_event(RechazoApelado { usuario: id, eleccion_id });
+            Ok(())
+        }
+
+        /// Obtiene el perfil de un candidato en una elección.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - cuenta: AccountId - Cuenta del candidato.
+        /// Result<PerfilCandidato>: El perfil del candidato o el error correspondiente.
+        /// Descripción:
+        /// El perfil de un candidato aceptado es público. El de una candidatura pendiente solo lo pueden ver los operadores de padrón
+        /// de la elección, auditores, generadores de reportes y administradores, para decidir si aprobarla.
+        /// En cualquier otro caso retorna Error::CandidatoInexistente.
+        #[ink(message)]
+        pub fn obtener_perfil_candidato(&self, eleccion_id:u64, cuenta:AccountId) -> Result<PerfilCandidato>
+        {
+            self.obtener_perfil_candidato_privado(eleccion_id, cuenta)
+        }
+        fn obtener_perfil_candidato_privado(&self, eleccion_id:u64, cuenta:AccountId) -> Result<PerfilCandidato>
+        {
+            let visible = self.candidatos_por_cuenta.contains((eleccion_id, cuenta)) || self.puede_consultar_padron(Some(eleccion_id));
+            if !visible { return Err(Error::CandidatoInexistente); }
+            self.perfiles_candidatos.get((eleccion_id, cuenta)).ok_or(Error::CandidatoInexistente)
+        }
+        
+        /// Realiza el voto a un candidato en una elección específica.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - candidato_id: u32 - ID del candidato, VOTO_EN_BLANCO (0) para votar en blanco o VOTO_NULO (u32::MAX) para votar nulo.
+        /// Descripción:
+        /// Verifica si el usuario está registrado. Obtiene el ID del votante y el timestamp actual del bloque.
+        /// Luego, verifica si la elección existe y si la votación está activa y en el período correcto.
+        /// Finalmente, registra el voto con emitir_voto y retorna su resultado. La elección debe ser por mayoría; si no, retorna Error::TipoDeVotoInvalido.
+        /// Los votos en blanco y nulos se aceptan en cualquier sistema de votación.
+        #[ink(message)]
+        pub fn votar_a_candidato(&mut self, eleccion_id:u64, candidato_id:u32) -> Result<()>
+        {
+            self.votar_a_candidato_privado(eleccion_id, candidato_id)
+        }
+        fn votar_a_candidato_privado(&mut self, eleccion_id:u64, candidato_id:u32) -> Result<()>
+        {
+            let voto = match candidato_id {
+                VOTO_EN_BLANCO => Voto::EnBlanco,
+                VOTO_NULO => Voto::Nulo,
+                candidato_id => Voto::Candidato(candidato_id),
+            };
+            self.votar_privado(eleccion_id, voto)
+        }
+
+        /// Realiza el voto en una elección por preferencia instantánea.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - preferencias: Vec<u32> - IDs de los candidatos ordenados de mayor a menor preferencia.
+        /// Descripción:
+        /// Igual que votar_a_candidato(), pero la elección debe ser por preferencia instantánea. Si no, retorna Error::TipoDeVotoInvalido.
+        /// Las preferencias deben incluir al menos un candidato existente y no pueden repetir candidatos.
+        #[ink(message)]
+        pub fn votar_con_preferencias(&mut self, eleccion_id:u64, preferencias:Vec<u32>) -> Result<()>
+        {
+            self.votar_con_preferencias_privado(eleccion_id, preferencias)
+        }
+        fn votar_con_preferencias_privado(&mut self, eleccion_id:u64, preferencias:Vec<u32>) -> Result<()>
+        {
+            self.votar_privado(eleccion_id, Voto::Preferencias(preferencias))
+        }
+
+        /// Realiza el voto en una elección por aprobación o de voto limitado.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - aprobados: Vec<u32> - IDs de los candidatos aprobados, sin importar el orden.
+        /// Descripción:
+        /// Igual que votar_a_candidato(), pero la elección debe ser por aprobación o de voto limitado. Si no, retorna Error::TipoDeVotoInvalido.
+        /// Los candidatos aprobados deben existir y no pueden repetirse. Retorna Error::AprobacionInvalida si no se cumple.
+        /// En el voto limitado, retorna Error::DemasiadosCandidatosAprobados si se aprueban más candidatos de los permitidos.
+        #[ink(message)]
+        pub fn votar_aprobando(&mut self, eleccion_id:u64, aprobados:Vec<u32>) -> Result<()>
+        {
+            self.votar_aprobando_privado(eleccion_id, aprobados)
+        }
+        fn votar_aprobando_privado(&mut self, eleccion_id:u64, aprobados:Vec<u32>) -> Result<()>
+        {
+            self.votar_privado(eleccion_id, Voto::Aprobacion(aprobados))
+        }
+        fn votar_privado(&mut self, eleccion_id:u64, voto:Voto) -> Result<()>
+        {
+            if !self.es_usuario_registrado() { return Err(Error::UsuarioNoRegistrado); }
+            let id = self.env().caller();
+            let block_timestamp = self.env().block_timestamp();
+    
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            if eleccion.es_voto_secreto() { return Err(Error::EleccionConVotoSecreto); }
+            let votacion_iniciada_ahora = Self::validar_periodo_votacion(&mut eleccion, block_timestamp)?;
+    
+            self.emitir_voto(&mut eleccion, id, voto)?;
+            self.guardar_eleccion(&eleccion);
+            if votacion_iniciada_ahora {
+                self.env().emit_event(VotacionIniciada { eleccion_id });
+            }
+            self.env().emit_event(VotoEmitido { votante: id, eleccion_id });
+            Ok(())
+        }
+        /// Verifica que la elección esté en la fase de votación.
+        /// Si la votación todavía no estaba iniciada la pasa a esa fase y retorna true, para que quien llama emita el evento.
+        fn validar_periodo_votacion(eleccion:&mut Eleccion, block_timestamp:u64) -> Result<bool>
+        {
+            let estado_anterior = eleccion.estado;
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Votacion => Ok(estado_anterior != EstadoEleccion::Votacion),
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion => Err(Error::VotacionNoIniciada),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => Err(Error::VotacionFinalizada),
+                EstadoEleccion::Cancelada => Err(Error::EleccionCancelada),
+            }
+        }
+
+        /// Envía el compromiso del voto en una elección de voto secreto.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - compromiso: Hash - Hash de (elección, votante, voto, sal), calculado con SistemaElecciones::calcular_compromiso().
+        /// Descripción:
+        /// Verifica si el usuario está registrado, si la elección existe y es de voto secreto, y si la votación está activa y en el período correcto.
+        /// Guarda el compromiso del votante sin revelar el candidato elegido. El voto se cuenta recién cuando se revela con revelar_voto().
+        #[ink(message)]
+        pub fn comprometer_voto(&mut self, eleccion_id:u64, compromiso:Hash) -> Result<()>
+        {
+            self.comprometer_voto_privado(eleccion_id, compromiso)
+        }
+        fn comprometer_voto_privado(&mut self, eleccion_id:u64, compromiso:Hash) -> Result<()>
+        {
+            if !self.es_usuario_registrado() { return Err(Error::UsuarioNoRegistrado); }
+            let id = self.env().caller();
+            let block_timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            if !eleccion.es_voto_secreto() { return Err(Error::EleccionSinVotoSecreto); }
+            let votacion_iniciada_ahora = Self::validar_periodo_votacion(&mut eleccion, block_timestamp)?;
+
+            self.comprometer_voto_en_eleccion(&mut eleccion, id, compromiso)?;
+            self.guardar_eleccion(&eleccion);
+            if votacion_iniciada_ahora {
+                self.env().emit_event(VotacionIniciada { eleccion_id });
+            }
+            self.env().emit_event(VotoEmitido { votante: id, eleccion_id });
+            Ok(())
+        }
+
+        /// Revela el voto comprometido en una elección de voto secreto y lo cuenta.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - voto: Voto - Voto que se usó para calcular el compromiso.
+        /// - sal: [u8; 32] - Sal que se usó para calcular el compromiso.
+        /// Descripción:
+        /// Verifica si la elección existe y es de voto secreto. No exige que el llamador siga registrado: un votante que se dio de baja
+        /// después de votar puede revelar su voto, y si no es votante de la elección retorna Error::NoRegistradoEnEleccion.
+        /// Solo se puede revelar en la fase de escrutinio, después de la fecha final y antes del fin de revelación;
+        /// si no, retorna Error::RevelacionNoIniciada o Error::RevelacionFinalizada.
+        /// Verifica que el hash de (elección, llamador, voto, sal) coincida con el compromiso enviado y cuenta el voto.
+        #[ink(message)]
+        pub fn revelar_voto(&mut self, eleccion_id:u64, voto:Voto, sal:[u8; 32]) -> Result<()>
+        {
+            self.revelar_voto_privado(eleccion_id, voto, sal)
+        }
+        fn revelar_voto_privado(&mut self, eleccion_id:u64, voto:Voto, sal:[u8; 32]) -> Result<()>
+        {
+            let id = self.env().caller();
+            let block_timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            if !eleccion.es_voto_secreto() { return Err(Error::EleccionSinVotoSecreto); }
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Escrutinio => (),
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion | EstadoEleccion::Votacion => return Err(Error::RevelacionNoIniciada),
+                EstadoEleccion::Finalizada => return Err(Error::RevelacionFinalizada),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+            }
+
+            self.revelar_voto_en_eleccion(&mut eleccion, id, voto, sal)?;
+            self.guardar_eleccion(&eleccion);
+            self.env().emit_event(VotoRevelado { votante: id, eleccion_id });
+            Ok(())
+        }
+
+        /// Delega el voto en otro votante de la misma elección, que votará con el peso de ambos.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - delegado: AccountId - Votante de la elección en quien se delega el voto.
+        /// Descripción:
+        /// Verifica si el usuario está registrado y si la elección existe. Solo se puede delegar antes de que empiece la votación;
+        /// si no, retorna Error::VotacionYaIniciada.
+        /// Si el delegado también delegó su voto, el voto pasa al final de la cadena de delegaciones. Retorna Error::DelegacionCircular
+        /// si se delega en uno mismo o en alguien que, directa o indirectamente, delegó su voto en el llamador.
+        /// Quien delega ya no puede votar, salvo que revoque la delegación con revocar_delegacion().
+        #[ink(message)]
+        pub fn delegar_voto(&mut self, eleccion_id:u64, delegado:AccountId) -> Result<()>
+        {
+            self.delegar_voto_privado(eleccion_id, delegado)
+        }
+        fn delegar_voto_privado(&mut self, eleccion_id:u64, delegado:AccountId) -> Result<()>
+        {
+            if !self.es_usuario_registrado() { return Err(Error::UsuarioNoRegistrado); }
+            let id = self.env().caller();
+            let block_timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion => (),
+                EstadoEleccion::Votacion | EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => return Err(Error::VotacionYaIniciada),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+            }
+
+            self.delegar_voto_en_eleccion(eleccion_id, id, delegado)?;
+            self.env().emit_event(VotoDelegado { votante: id, delegado, eleccion_id });
+            Ok(())
+        }
+
+        /// Revoca la delegación del voto en una elección, para que el llamador pueda votar por sí mismo.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// Descripción:
+        /// Verifica si el usuario está registrado, si la elección existe y si la votación no finalizó.
+        /// Se puede revocar mientras el delegado final no haya votado; si ya votó, retorna Error::DelegadoYaVoto.
+        #[ink(message)]
+        pub fn revocar_delegacion(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            self.revocar_delegacion_privado(eleccion_id)
+        }
+        fn revocar_delegacion_privado(&mut self, eleccion_id:u64) -> Result<()>
+        {
+            if !self.es_usuario_registrado() { return Err(Error::UsuarioNoRegistrado); }
+            let id = self.env().caller();
+            let block_timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            match eleccion.actualizar_estado(block_timestamp) {
+                EstadoEleccion::Borrador | EstadoEleccion::Inscripcion | EstadoEleccion::Votacion => (),
+                EstadoEleccion::Escrutinio | EstadoEleccion::Finalizada => return Err(Error::VotacionFinalizada),
+                EstadoEleccion::Cancelada => return Err(Error::EleccionCancelada),
+            }
+
+            self.revocar_delegacion_en_eleccion(eleccion_id, id)?;
+            self.env().emit_event(DelegacionRevocada { votante: id, eleccion_id });
+            Ok(())
+        }
+
+        /// Calcula el compromiso de un voto secreto: el hash Blake2x256 de (eleccion_id, votante, voto, sal) codificados en SCALE.
+        /// Los votantes deben calcularlo fuera de la cadena y guardar la sal para poder revelar el voto.
+        /// Incluir la elección y el votante impide que otro votante copie un compromiso ajeno, o que se reuse en otra elección,
+        /// y luego lo revele con el voto y la sal que publique su autor.
+        pub fn calcular_compromiso(eleccion_id:u64, votante:AccountId, voto:&Voto, sal:[u8; 32]) -> Hash
+        {
+            let mut salida = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&(eleccion_id, votante, voto, sal), &mut salida);
+            Hash::from(salida)
+        }
+
+        /// Obtiene la información de un candidato en una elección específica.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - candidato_id: u32 - ID del candidato.
+        /// Result<CandidatoInfo>: Información del candidato o el error correspondiente.
+        /// Descripción:
+        /// Busca la elección por su ID y luego obtiene la información del candidato.
+        /// Retorna la cuenta, el nombre, apellido, DNI y perfil del candidato si está registrado;
+        /// de lo contrario, retorna Error::EleccionInexistente o Error::CandidatoInexistente.
+        /// Si el candidato se dio de baja del sistema después de que empezara la votación, retorna Error::UsuarioInexistente.
+        #[ink(message)]
+        pub fn obtener_informacion_candidato_eleccion(&self, eleccion_id:u64, candidato_id:u32) -> Result<CandidatoInfo>
+        {
+            self.obtener_informacion_candidato_eleccion_privado(eleccion_id, candidato_id)
+        }
+        fn obtener_informacion_candidato_eleccion_privado(&self, eleccion_id:u64, candidato_id:u32) -> Result<CandidatoInfo>
+        {
+            let eleccion_elegida = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            let candidato_info = self.obtener_informacion_candidato(&eleccion_elegida, candidato_id).ok_or(Error::CandidatoInexistente)?;
+
+            let info = self.obtener_usuario_o_dado_de_baja(candidato_info.id).ok_or(Error::UsuarioInexistente)?;
+            let perfil = self.perfiles_candidatos.get((eleccion_id, info.id)).unwrap_or_default();
+            let dni = info.identificador_dni();
+            Ok(CandidatoInfo { candidato_id, id: info.id, nombre: info.nombre, apellido: info.apellido, dni, perfil })
+        }
+
+        // ====-----==== METODOS USADOS POR EL ADMINISTRADOR ====----====
+        // ====-----==== METODOS USADOS POR EL ADMINISTRADOR ====----====
+        // ====-----==== METODOS USADOS POR EL ADMINISTRADOR ====----====
+
+        /// Utilizado por un administrador.
+        /// Activa el registro de usuarios.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Luego, verifica si el registro ya está activado. Si es así, retorna Error::RegistroYaActivado. Si no, activa el registro.
+        #[ink(message)]
+        pub fn activar_registro(&mut self) -> Result<()>
+        {
+            self.activar_registro_privado()
+        }
+        fn activar_registro_privado(&mut self) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.registro_activado { return Err(Error::RegistroYaActivado); }
+            self.registro_activado = true;
+            Ok(())
+        }
+
+        /// Utilizado por un administrador.
+        /// Desactiva el registro de usuarios si no está activo el registro.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Luego, verifica si el registro ya está desactivado. Si es así, retorna Error::RegistroYaDesactivado. Si no, desactiva el registro.
+        #[ink(message)]
+        pub fn desactivar_registro(&mut self) -> Result<()>
+        {
+            self.desactivar_registro_privado()
+        }
+        fn desactivar_registro_privado(&mut self) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if !self.registro_activado { return Err(Error::RegistroYaDesactivado); }
+            self.registro_activado = false;
+            Ok(())
+        }
+
+        /// Utilizado por un administrador.
+        /// Activa la protección del DNI: desde entonces los usuarios se registran con `registrarse_con_compromiso_dni`
+        /// y en el contrato se guarda el compromiso de su DNI y la huella que confirma el padrón, no el DNI.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::ProteccionDniYaActivada si ya está activada, y Error::ProteccionDniNoDisponible si ya hay usuarios
+        /// registrados o pendientes, porque sus DNI ya están guardados en texto plano. La protección no se puede desactivar.
+        /// Todo lo que se guarda en el contrato es público, así que ninguna sal se guarda en él: la de cada usuario la conoce
+        /// solo el usuario y el operador que confirma su DNI, y la del padrón la conocen solo los operadores de padrón.
+        /// Como un DNI tiene solo 7 u 8 dígitos, quien conozca alguna de esas sales puede recuperar los DNI probando todos los posibles.
+        #[ink(message)]
+        pub fn activar_proteccion_dni(&mut self) -> Result<()>
+        {
+            self.activar_proteccion_dni_privado()
+        }
+        fn activar_proteccion_dni_privado(&mut self) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.proteccion_dni { return Err(Error::ProteccionDniYaActivada); }
+            let cola = self.posiciones_usuarios_pendientes;
+            if self.cantidad_usuarios > 0 || cola.fin > cola.inicio { return Err(Error::ProteccionDniNoDisponible); }
+            self.proteccion_dni = true;
+            Ok(())
+        }
+
+        /// Retorna true si está activada la protección del DNI.
+        #[ink(message)]
+        pub fn proteccion_dni_activada(&self) -> bool
+        {
+            self.proteccion_dni
+        }
+
+        /// Calcula el compromiso de un DNI: el hash Blake2x256 de (sal, dni) codificados en SCALE.
+        /// Sirve para el compromiso que envía el usuario, con su propia sal, y para la huella que confirma el padrón, con la sal del padrón.
+        /// El DNI debe estar normalizado: solo dígitos, sin espacios. Se calcula fuera de la cadena para no revelar el DNI ni la sal.
+        pub fn calcular_compromiso_dni(dni:&str, sal:[u8; 32]) -> Hash
+        {
+            let mut salida = <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&(sal, dni), &mut salida);
+            Hash::from(salida)
+        }
+
+        /// Verifica si el DNI que declara un usuario coincide con el que se guardó al registrarse.
+        /// - cuenta: AccountId - Usuario registrado o pendiente.
+        /// - dni: String - DNI declarado. Se ignoran los espacios al principio y al final.
+        /// - sal: Option<[u8; 32]> - Sal que muestra el usuario si se registró con el compromiso del DNI.
+        /// Retorno:
+        /// Result<bool>: true si coincide con el DNI o con su compromiso, o el error correspondiente.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón de todo el sistema. Si no, retorna Error::NoEsOperadorPadron.
+        /// Retorna Error::UsuarioInexistente si la cuenta no está registrada ni pendiente.
+        /// Con la protección del DNI activada debe llamarse como consulta a un nodo propio, para que el DNI y la sal no queden en la cadena.
+        #[ink(message)]
+        pub fn verificar_dni(&self, cuenta:AccountId, dni:String, sal:Option<[u8; 32]>) -> Result<bool>
+        {
+            self.verificar_dni_privado(cuenta, dni, sal)
+        }
+        fn verificar_dni_privado(&self, cuenta:AccountId, dni:String, sal:Option<[u8; 32]>) -> Result<bool>
+        {
+            if !self.es_operador_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            let usuario = self.obtener_usuario(cuenta).or_else(|| self.usuarios_pendientes.get(cuenta)).ok_or(Error::UsuarioInexistente)?;
+            let dni = dni.trim();
+            Ok(match (usuario.compromiso_dni, sal) {
+                (Some(compromiso), Some(sal)) => Self::calcular_compromiso_dni(dni, sal) == compromiso,
+                (Some(_), None) => false,
+                (None, _) => usuario.dni == dni,
+            })
+        }
+
+        /// Confirma el DNI de un usuario pendiente que se registró con el compromiso del DNI, y guarda su huella.
+        /// - cuenta: AccountId - Usuario pendiente.
+        /// - huella_dni: Hash - Resultado de `calcular_compromiso_dni` con el DNI del usuario y la sal secreta del padrón.
+        /// Descripción:
+        /// Verifica si el llamador es administrador u operador de padrón de todo el sistema. Si no, retorna Error::NoEsOperadorPadron.
+        /// Retorna Error::ProteccionDniNoActivada si la protección no está activada y Error::UsuarioNoPendiente si la cuenta no está pendiente.
+        /// Antes de llamarla, el operador comprueba el documento del usuario y, con `verificar_dni`, que el DNI y la sal que muestra
+        /// coinciden con su compromiso. Ningún usuario se acepta sin esta confirmación.
+        /// Todos los operadores usan la misma sal del padrón, así que la huella identifica al DNI: retorna Error::DniYaRegistrado
+        /// si otro usuario registrado o pendiente ya tiene esa huella. Una nueva confirmación reemplaza a la anterior.
+        #[ink(message)]
+        pub fn confirmar_dni(&mut self, cuenta:AccountId, huella_dni:Hash) -> Result<()>
+        {
+            self.confirmar_dni_privado(cuenta, huella_dni)
+        }
+        fn confirmar_dni_privado(&mut self, cuenta:AccountId, huella_dni:Hash) -> Result<()>
+        {
+            if !self.es_operador_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            if !self.proteccion_dni { return Err(Error::ProteccionDniNoActivada); }
+            let mut usuario = self.usuarios_pendientes.get(cuenta).ok_or(Error::UsuarioNoPendiente)?;
+            if self.cuentas_por_huella_dni.get(huella_dni).is_some_and(|otra| otra != cuenta) { return Err(Error::DniYaRegistrado); }
+
+            if let Some(anterior) = usuario.huella_dni { self.cuentas_por_huella_dni.remove(anterior); }
+            usuario.huella_dni = Some(huella_dni);
+            self.cuentas_por_huella_dni.insert(huella_dni, &cuenta);
+            self.usuarios_pendientes.insert(cuenta, &usuario);
+            Ok(())
+        }
+
+        /// Utilizado por un administrador.
+        /// Propone transferir el rol de administrador del llamador a otra cuenta, que debe aceptarlo con aceptar_administrador().
+        /// id: AccountId: ID del nuevo administrador.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Retorna Error::RolYaOtorgado si la cuenta ya es administrador.
+        /// Reemplaza a la propuesta pendiente, si la hay. Si está configurado un vencimiento, la propuesta solo se puede aceptar
+        /// durante esa cantidad de bloques. El llamador sigue siendo administrador hasta que se acepte la transferencia.
+        #[ink(message)]
+        pub fn transferir_administrador(&mut self, id:AccountId) -> Result<()>
+        {
+            self.transferir_administrador_privado(id)
+        }
+        pub fn transferir_administrador_privado(&mut self, id:AccountId) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.proponer_transferencia_administrador(self.env().caller(), id)
+        }
+        //Guarda la transferencia pendiente del rol de administrador de proponente a id
+        fn proponer_transferencia_administrador(&mut self, proponente:AccountId, id:AccountId) -> Result<()>
+        {
+            if self.tiene_rol(id, Rol::Administrador) { return Err(Error::RolYaOtorgado); }
+            let vence_en_bloque = match self.bloques_vencimiento_transferencia {
+                None => None,
+                Some(bloques) => Some(self.env().block_number().checked_add(bloques).ok_or(Error::Overflow)?),
+            };
+            self.transferencia_administrador = Some(PropuestaAdministrador { proponente, nuevo: id, vence_en_bloque });
+            self.env().emit_event(TransferenciaAdministradorPropuesta { proponente, nuevo: id, vence_en_bloque });
+            Ok(())
+        }
+
+        /// Utilizado por la cuenta propuesta como nuevo administrador.
+        /// Acepta la transferencia de administrador pendiente.
+        /// Descripción:
+        /// Retorna Error::SinTransferenciaPendiente si no hay una propuesta, Error::NoEsAdministradorPropuesto si el llamador
+        /// no es la cuenta propuesta y Error::TransferenciaVencida si pasó el bloque de vencimiento.
+        /// Otorga el rol de administrador al llamador y se lo quita a quien propuso la transferencia, si todavía lo tiene.
+        /// Emite el evento AdministradorTransferido además de los eventos de los roles.
+        #[ink(message)]
+        pub fn aceptar_administrador(&mut self) -> Result<()>
+        {
+            self.aceptar_administrador_privado()
+        }
+        fn aceptar_administrador_privado(&mut self) -> Result<()>
+        {
+            let propuesta = self.transferencia_administrador.ok_or(Error::SinTransferenciaPendiente)?;
+            if self.env().caller() != propuesta.nuevo { return Err(Error::NoEsAdministradorPropuesto); }
+            if propuesta.vence_en_bloque.is_some_and(|bloque| self.env().block_number() > bloque) { return Err(Error::TransferenciaVencida); }
+
+            self.transferencia_administrador = None;
+            self.agregar_rol(propuesta.nuevo, Rol::Administrador, None)?;
+            if self.tiene_rol(propuesta.proponente, Rol::Administrador) {
+                self.quitar_rol(propuesta.proponente, Rol::Administrador, None, false)?;
+            }
+            self.env().emit_event(AdministradorTransferido { anterior: propuesta.proponente, nuevo: propuesta.nuevo });
+            Ok(())
+        }
+
+        /// Utilizado por un administrador o por la cuenta propuesta.
+        /// Cancela la transferencia de administrador pendiente.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador o la cuenta propuesta. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::SinTransferenciaPendiente si no hay una propuesta.
+        #[ink(message)]
+        pub fn cancelar_transferencia_administrador(&mut self) -> Result<()>
+        {
+            self.cancelar_transferencia_administrador_privado()
+        }
+        fn cancelar_transferencia_administrador_privado(&mut self) -> Result<()>
+        {
+            let propuesta = self.transferencia_administrador.ok_or(Error::SinTransferenciaPendiente)?;
+            if !self.es_administrador() && self.env().caller() != propuesta.nuevo { return Err(Error::NoEsAdministrador); }
+
+            self.transferencia_administrador = None;
+            self.env().emit_event(TransferenciaAdministradorCancelada { proponente: propuesta.proponente, nuevo: propuesta.nuevo });
+            Ok(())
+        }
+
+        /// Utilizado por un administrador.
+        /// Configura durante cuántos bloques se pueden aceptar las próximas transferencias de administrador.
+        /// - bloques: Option<u32> - Cantidad de bloques, o None para que las propuestas no venzan.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// No cambia el vencimiento de la propuesta pendiente.
+        #[ink(message)]
+        pub fn configurar_vencimiento_transferencia(&mut self, bloques:Option<u32>) -> Result<()>
+        {
+            self.configurar_vencimiento_transferencia_privado(bloques)
+        }
+        fn configurar_vencimiento_transferencia_privado(&mut self, bloques:Option<u32>) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.configurar_vencimiento_transferencia_en_sistema(bloques)
+        }
+        fn configurar_vencimiento_transferencia_en_sistema(&mut self, bloques:Option<u32>) -> Result<()>
+        {
+            self.bloques_vencimiento_transferencia = bloques;
+            Ok(())
+        }
+
+        /// Retorna la transferencia de administrador pendiente, si la hay.
+        #[ink(message)]
+        pub fn obtener_transferencia_administrador(&self) -> Option<PropuestaAdministrador>
+        {
+            self.transferencia_administrador
+        }
+        
+        /// Utilizado por un administrador.
+        /// Permite a un administrador asignar un generador de reportes. Equivale a otorgar_rol(id, Rol::GeneradorReportes, None).
+        /// id: AccountId: ID del nuevo generador de reportes.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Otorga el rol de generador de reportes a la cuenta indicada. Retorna Error::RolYaOtorgado si ya lo tiene.
+        #[ink(message)]
+        pub fn asignar_generador_reportes(&mut self, id:AccountId) -> Result<()>
+        {
+            self.asignar_generador_reportes_privado(id)
+        }
+        pub fn asignar_generador_reportes_privado(&mut self, id:AccountId) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.agregar_rol(id, Rol::GeneradorReportes, None)
+        }
+
+        // ====-----==== METODOS ROLES ====----====
+        // ====-----==== METODOS ROLES ====----====
+        // ====-----==== METODOS ROLES ====----====
+
+        /// Utilizado por un administrador.
+        /// Otorga un rol a una cuenta.
+        /// - cuenta: AccountId - Cuenta que recibe el rol.
+        /// - rol: Rol - Rol a otorgar.
+        /// - eleccion_id: Option<u64> - Si es Some, el rol vale solo para esa elección. Solo se permite para Rol::OperadorPadron.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Retorna Error::RolSinAlcancePorEleccion si se indica una elección para otro rol, Error::EleccionInexistente si la elección no existe
+        /// y Error::RolYaOtorgado si la cuenta ya tiene el rol. Emite el evento RolOtorgado.
+        #[ink(message)]
+        pub fn otorgar_rol(&mut self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
+        {
+            self.otorgar_rol_privado(cuenta, rol, eleccion_id)
+        }
+        fn otorgar_rol_privado(&mut self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.agregar_rol(cuenta, rol, eleccion_id)
+        }
+
+        /// Utilizado por un administrador.
+        /// Revoca un rol de una cuenta.
+        /// - cuenta: AccountId - Cuenta a la que se le quita el rol.
+        /// - rol: Rol - Rol a revocar.
+        /// - eleccion_id: Option<u64> - Elección para la que se había otorgado el rol, o None si valía para todo el sistema.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::RequiereMultifirma si la aprobación multifirma está activada: en ese caso se usa `proponer_accion`.
+        /// Retorna Error::RolNoOtorgado si la cuenta no tiene el rol y Error::UltimoAdministrador si es el único administrador.
+        /// Emite el evento RolRevocado.
+        #[ink(message)]
+        pub fn revocar_rol(&mut self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
+        {
+            self.revocar_rol_privado(cuenta, rol, eleccion_id)
+        }
+        fn revocar_rol_privado(&mut self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.quitar_rol(cuenta, rol, eleccion_id, false)
+        }
+
+        /// Renuncia a un rol del llamador.
+        /// - rol: Rol - Rol al que se renuncia.
+        /// - eleccion_id: Option<u64> - Elección para la que se había otorgado el rol, o None si valía para todo el sistema.
+        /// Descripción:
+        /// Retorna Error::RolNoOtorgado si el llamador no tiene el rol y Error::UltimoAdministrador si es el único administrador.
+        /// Emite el evento RolRevocado indicando que fue una renuncia.
+        #[ink(message)]
+        pub fn renunciar_rol(&mut self, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
+        {
+            self.renunciar_rol_privado(rol, eleccion_id)
+        }
+        fn renunciar_rol_privado(&mut self, rol:Rol, eleccion_id:Option<u64>) -> Result<()>
+        {
+            self.quitar_rol(self.env().caller(), rol, eleccion_id, true)
+        }
+
+        /// Consulta si una cuenta tiene un rol.
+        /// - eleccion_id: Option<u64> - Si es Some, consulta el rol de operador de padrón de esa elección.
+        /// Retorno:
+        /// bool: true si la cuenta tiene el rol. Para una elección solo se consideran los operadores de esa elección,
+        /// no los de todo el sistema.
+        #[ink(message)]
+        pub fn consultar_rol(&self, cuenta:AccountId, rol:Rol, eleccion_id:Option<u64>) -> bool
+        {
+            match eleccion_id {
+                Some(eleccion_id) => rol == Rol::OperadorPadron && self.operadores_eleccion.contains((eleccion_id, cuenta)),
+                None => self.tiene_rol(cuenta, rol),
+            }
+        }
+
+        // ====-----==== METODOS MULTIFIRMA ====----====
+        // ====-----==== METODOS MULTIFIRMA ====----====
+        // ====-----==== METODOS MULTIFIRMA ====----====
+
+        /// Utilizado por un administrador.
+        /// Configura la aprobación multifirma de las acciones críticas.
+        /// - umbral: u32 - Cantidad de administradores que deben confirmar una acción. Con 1 cada administrador las ejecuta solo.
+        /// - bloques_vencimiento: u32 - Cantidad de bloques durante los que se puede confirmar una propuesta.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Si la multifirma ya está activada retorna Error::RequiereMultifirma: el cambio se propone como AccionAdministrativa::ConfigurarMultifirma.
+        /// Retorna Error::UmbralInvalido si el umbral es 0 o supera la cantidad de administradores, o si bloques_vencimiento es 0.
+        #[ink(message)]
+        pub fn configurar_multifirma(&mut self, umbral:u32, bloques_vencimiento:u32) -> Result<()>
+        {
+            self.configurar_multifirma_privado(umbral, bloques_vencimiento)
+        }
+        fn configurar_multifirma_privado(&mut self, umbral:u32, bloques_vencimiento:u32) -> Result<()>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            if self.requiere_multifirma() { return Err(Error::RequiereMultifirma); }
+            self.configurar_multifirma_en_sistema(umbral, bloques_vencimiento)
+        }
+        fn configurar_multifirma_en_sistema(&mut self, umbral:u32, bloques_vencimiento:u32) -> Result<()>
+        {
+            if umbral == 0 || umbral > self.cantidad_administradores || bloques_vencimiento == 0 { return Err(Error::UmbralInvalido); }
+            self.umbral_aprobacion = umbral;
+            self.bloques_vencimiento_propuestas = bloques_vencimiento;
+            Ok(())
+        }
+
+        /// Retorna el umbral de confirmaciones y la cantidad de bloques durante los que se puede confirmar una propuesta.
+        #[ink(message)]
+        pub fn obtener_configuracion_multifirma(&self) -> (u32, u32)
+        {
+            (self.umbral_aprobacion, self.bloques_vencimiento_propuestas)
+        }
+
+        /// Utilizado por un administrador.
+        /// Propone una acción crítica para que la confirmen los demás administradores.
+        /// - accion: AccionAdministrativa - Acción a ejecutar al alcanzar el umbral.
+        /// Retorno:
+        /// Result<u32>: El ID de la propuesta o el error correspondiente.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// La propuesta cuenta como la primera confirmación, así que con umbral 1 la acción se ejecuta enseguida.
+        /// Si la acción falla al ejecutarse se retorna su error y la propuesta no se guarda.
+        /// Emite los eventos AccionPropuesta y, si se ejecuta, AccionEjecutada.
+        #[ink(message)]
+        pub fn proponer_accion(&mut self, accion:AccionAdministrativa) -> Result<u32>
+        {
+            self.proponer_accion_privado(accion)
+        }
+        fn proponer_accion_privado(&mut self, accion:AccionAdministrativa) -> Result<u32>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            let proponente = self.env().caller();
+            let propuesta_id = self.cantidad_propuestas.checked_add(1).ok_or(Error::Overflow)?;
+            let vence_en_bloque = self.env().block_number().checked_add(self.bloques_vencimiento_propuestas).ok_or(Error::Overflow)?;
+            let mut propuesta = PropuestaAccion { id: propuesta_id, proponente, accion, confirmaciones: 1, confirmantes: vec![proponente], vence_en_bloque, ejecutada: false };
+
+            let ejecutar = propuesta.confirmaciones >= self.umbral_aprobacion;
+            if ejecutar {
+                self.ejecutar_accion(&propuesta)?;
+                propuesta.ejecutada = true;
+            }
+            self.cantidad_propuestas = propuesta_id;
+            self.propuestas.insert(propuesta_id, &propuesta);
+            self.env().emit_event(AccionPropuesta { propuesta_id, proponente, accion: propuesta.accion, vence_en_bloque });
+            if ejecutar { self.env().emit_event(AccionEjecutada { propuesta_id }); }
+            Ok(propuesta_id)
+        }
+
+        /// Utilizado por un administrador.
+        /// Confirma una propuesta pendiente y, si se alcanza el umbral, ejecuta su acción.
+        /// - propuesta_id: u32 - ID de la propuesta.
+        /// Retorno:
+        /// Result<bool>: true si la acción se ejecutó con esta confirmación, o el error correspondiente.
+        /// Descripción:
+        /// Verifica si el llamador es un administrador. Si no, retorna Error::NoEsAdministrador.
+        /// Retorna Error::PropuestaInexistente, Error::PropuestaYaEjecutada, Error::PropuestaVencida si pasó el bloque de vencimiento
+        /// y Error::PropuestaYaConfirmada si el llamador ya la confirmó.
+        /// Solo cuentan las confirmaciones de quienes siguen siendo administradores: las de un administrador revocado se descartan.
+        /// Si la acción falla al ejecutarse se retorna su error y la confirmación no se guarda.
+        /// Emite los eventos AccionConfirmada y, si se ejecuta, AccionEjecutada.
+        #[ink(message)]
+        pub fn confirmar_accion(&mut self, propuesta_id:u32) -> Result<bool>
+        {
+            self.confirmar_accion_privado(propuesta_id)
+        }
+        fn confirmar_accion_privado(&mut self, propuesta_id:u32) -> Result<bool>
+        {
+            if !self.es_administrador() { return Err(Error::NoEsAdministrador); }
+            let confirmante = self.env().caller();
+            let mut propuesta = self.propuestas.get(propuesta_id).ok_or(Error::PropuestaInexistente)?;
+            if propuesta.ejecutada { return Err(Error::PropuestaYaEjecutada); }
+            if self.env().block_number() > propuesta.vence_en_bloque { return Err(Error::PropuestaVencida); }
+            if propuesta.confirmantes.contains(&confirmante) { return Err(Error::PropuestaYaConfirmada); }
+
+            propuesta.confirmantes.push(confirmante);
+            propuesta.confirmaciones = self.contar_confirmaciones(&propuesta);
+            let ejecutar = propuesta.confirmaciones >= self.umbral_aprobacion;
+            if ejecutar {
+                self.ejecutar_accion(&propuesta)?;
+                propuesta.ejecutada = true;
+            }
+            self.propuestas.insert(propuesta_id, &propuesta);
+            self.env().emit_event(AccionConfirmada { propuesta_id, confirmante, confirmaciones: propuesta.confirmaciones });
+            if ejecutar { self.env().emit_event(AccionEjecutada { propuesta_id }); }
+            Ok(ejecutar)
+        }
+        //Cuenta las confirmaciones de la propuesta que siguen valiendo: las de quienes todavía son administradores
+        fn contar_confirmaciones(&self, propuesta:&PropuestaAccion) -> u32
+        {
+            let vigentes = propuesta.confirmantes.iter().filter(|cuenta| self.tiene_rol(**cuenta, Rol::Administrador)).count();
+            u32::try_from(vigentes).unwrap_or(u32::MAX)
+        }
+        //Ejecuta la acción de una propuesta que alcanzó el umbral, sin volver a verificar los permisos del llamador
+        fn ejecutar_accion(&mut self, propuesta:&PropuestaAccion) -> Result<()>
+        {
+            match propuesta.accion.clone() {
+                AccionAdministrativa::CrearEleccion { fecha_inicial, fecha_final, configuracion } => {
+                    self.crear_eleccion_en_sistema(fecha_inicial, fecha_final, configuracion).map(|_| ())
+                },
+                AccionAdministrativa::IniciarVotacion { eleccion_id } => self.iniciar_votacion_en_sistema(eleccion_id),
+                AccionAdministrativa::TransferirAdministrador { id } => self.proponer_transferencia_administrador(propuesta.proponente, id),
+                AccionAdministrativa::AsignarGeneradorReportes { id } => self.agregar_rol(id, Rol::GeneradorReportes, None),
+                AccionAdministrativa::OtorgarRol { cuenta, rol, eleccion_id } => self.agregar_rol(cuenta, rol, eleccion_id),
+                AccionAdministrativa::RevocarRol { cuenta, rol, eleccion_id } => self.quitar_rol(cuenta, rol, eleccion_id, false),
+                AccionAdministrativa::ConfigurarVencimientoTransferencia { bloques } => self.configurar_vencimiento_transferencia_en_sistema(bloques),
+                AccionAdministrativa::ConfigurarMultifirma { umbral, bloques_vencimiento } => {
+                    self.configurar_multifirma_en_sistema(umbral, bloques_vencimiento)
+                },
+                AccionAdministrativa::EditarEleccion { eleccion_id, fecha_inicial, fecha_final, configuracion } => {
+                    self.editar_eleccion_en_sistema(eleccion_id, fecha_inicial, fecha_final, configuracion)
+                },
+                AccionAdministrativa::PostergarEleccion { eleccion_id, fecha_inicial, fecha_final, motivo } => {
+                    self.postergar_eleccion_en_sistema(eleccion_id, fecha_inicial, fecha_final, motivo)
+                },
+                AccionAdministrativa::CancelarEleccion { eleccion_id, motivo } => self.cancelar_eleccion_en_sistema(eleccion_id, motivo),
+            }
+        }
+
+        /// Retorna las propuestas que todavía se pueden confirmar: no ejecutadas y no vencidas, de la más antigua a la más nueva.
+        #[ink(message)]
+        pub fn obtener_propuestas_pendientes(&self) -> Vec<PropuestaAccion>
+        {
+            let bloque_actual = self.env().block_number();
+            (1..=self.cantidad_propuestas)
+                .filter_map(|propuesta_id| self.propuestas.get(propuesta_id))
+                .filter(|propuesta| !propuesta.ejecutada && bloque_actual <= propuesta.vence_en_bloque)
+                .map(|propuesta| PropuestaAccion { confirmaciones: self.contar_confirmaciones(&propuesta), ..propuesta })
+                .collect()
+        }
+
+        /// Consulta si un administrador ya confirmó una propuesta.
+        #[ink(message)]
+        pub fn consultar_confirmacion(&self, propuesta_id:u32, cuenta:AccountId) -> bool
+        {
+            self.propuestas.get(propuesta_id).is_some_and(|propuesta| propuesta.confirmantes.contains(&cuenta))
+        }
+
+        /// Retorna la fase actual de una elección según sus fechas, o Error::EleccionInexistente si no existe.
+        #[ink(message)]
+        pub fn obtener_estado_eleccion(&self, eleccion_id:u64) -> Result<EstadoEleccion>
+        {
+            let eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Ok(eleccion.estado_en(self.env().block_timestamp()))
+        }
+
+        /// Retorna las fechas, la configuración, la fase y los metadatos de una elección, para que los usuarios sepan a qué ingresan.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// Retorno:
+        /// Result<EleccionInfo>: La información de la elección, o Error::EleccionInexistente si no existe.
+        #[ink(message)]
+        pub fn obtener_informacion_eleccion(&self, eleccion_id:u64) -> Result<EleccionInfo>
+        {
+            let eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Ok(eleccion.informacion(self.env().block_timestamp()))
+        }
+
+        // ====-----==== METODOS DE CONSULTA PAGINADA ====----====
+        // ====-----==== METODOS DE CONSULTA PAGINADA ====----====
+        // ====-----==== METODOS DE CONSULTA PAGINADA ====----====
+
+        /// Arma una página con los elementos de los índices [offset, offset + limit) de una lista de `total` elementos.
+        /// El límite se acota a MAXIMO_ELEMENTOS_POR_PAGINA. Los índices para los que `obtener` retorna None se omiten,
+        /// por lo que una página puede tener menos elementos que el límite sin ser la última.
+        fn paginar<T>(offset:u32, limit:u32, total:u32, obtener:impl FnMut(u32) -> Option<T>) -> Pagina<T>
+        {
+            let fin = offset.saturating_add(limit.min(MAXIMO_ELEMENTOS_POR_PAGINA)).min(total);
+            let elementos = (offset..fin).filter_map(obtener).collect();
+            Pagina { elementos, siguiente: (fin < total).then_some(fin) }
+        }
+
+        /// Lista los usuarios registrados en el orden en que fueron aceptados.
+        /// - offset: u32 - Cantidad de usuarios a saltear.
+        /// - limit: u32 - Cantidad máxima de usuarios a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón, auditor, generador de reportes o administrador. Si no, retorna Error::NoEsOperadorPadron.
+        #[ink(message)]
+        pub fn listar_usuarios(&self, offset:u32, limit:u32) -> Result<Pagina<UsuarioInfo>>
+        {
+            self.listar_usuarios_privado(offset, limit)
+        }
+        fn listar_usuarios_privado(&self, offset:u32, limit:u32) -> Result<Pagina<UsuarioInfo>>
+        {
+            if !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            Ok(Self::paginar(offset, limit, self.cantidad_usuarios, |indice| {
+                self.usuarios_por_indice.get(indice).filter(|id| self.indices_usuarios.get(id) == Some(indice))
+                    .and_then(|id| self.obtener_usuario(id)).map(UsuarioInfo::from)
+            }))
+        }
+
+        /// Lista los usuarios pendientes de aceptación en el orden de la cola.
+        /// - offset: u32 - Posiciones de la cola a saltear, contando desde el principio.
+        /// - limit: u32 - Cantidad máxima de posiciones a recorrer, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón, auditor, generador de reportes o administrador. Si no, retorna Error::NoEsOperadorPadron.
+        /// Se omiten las posiciones de usuarios ya procesados en un lote. Como el principio de la cola avanza al procesar usuarios,
+        /// los offsets solo valen mientras no se procese la cola.
+        #[ink(message)]
+        pub fn listar_usuarios_pendientes(&self, offset:u32, limit:u32) -> Result<Pagina<UsuarioInfo>>
+        {
+            self.listar_usuarios_pendientes_privado(offset, limit)
+        }
+        fn listar_usuarios_pendientes_privado(&self, offset:u32, limit:u32) -> Result<Pagina<UsuarioInfo>>
+        {
+            if !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            let cola = self.posiciones_usuarios_pendientes;
+            Ok(Self::paginar(offset, limit, cola.fin - cola.inicio, |indice| {
+                self.cola_usuarios_pendientes.get(cola.inicio + indice)
+                    .and_then(|id| self.usuarios_pendientes.get(id))
+                    .map(UsuarioInfo::from)
+            }))
+        }
+
+        /// Lista las cuentas cuya solicitud de registro fue rechazada, en el orden en que se rechazaron.
+        /// - offset: u32 - Cantidad de cuentas a saltear.
+        /// - limit: u32 - Cantidad máxima de cuentas a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón, auditor, generador de reportes o administrador. Si no, retorna Error::NoEsOperadorPadron.
+        /// Se omiten las cuentas rehabilitadas o que volvieron a enviar su solicitud tras apelar, y cada cuenta aparece en la posición de su último rechazo.
+        #[ink(message)]
+        pub fn listar_usuarios_rechazados(&self, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            self.listar_usuarios_rechazados_privado(offset, limit)
+        }
+        fn listar_usuarios_rechazados_privado(&self, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            if !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            Ok(Self::paginar(offset, limit, self.cantidad_usuarios_rechazados, |indice| {
+                self.usuarios_rechazados_por_indice.get(indice).filter(|id| self.usuarios_rechazados.get(id) == Some(indice))
+            }))
+        }
+
+        /// Lista las elecciones ordenadas por ID, con sus fechas, su estado y sus metadatos.
+        /// - offset: u32 - Cantidad de elecciones a saltear.
+        /// - limit: u32 - Cantidad máxima de elecciones a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        #[ink(message)]
+        pub fn listar_elecciones(&self, offset:u32, limit:u32) -> Pagina<EleccionInfo>
+        {
+            let block_timestamp = self.env().block_timestamp();
+            let total = u32::try_from(self.cantidad_elecciones).unwrap_or(u32::MAX);
+            Self::paginar(offset, limit, total, |indice| {
+                self.obtener_eleccion_por_id(u64::from(indice) + 1).map(|eleccion| eleccion.informacion(block_timestamp))
+            })
+        }
+
+        /// Lista las solicitudes pendientes de una elección en el orden de su cola.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - offset: u32 - Posiciones de la cola a saltear, contando desde el principio.
+        /// - limit: u32 - Cantidad máxima de posiciones a recorrer, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón del sistema o de la elección, auditor, generador de reportes o administrador.
+        /// Si no, retorna Error::NoEsOperadorPadron. Retorna Error::EleccionInexistente si la elección no existe.
+        /// Se omiten las posiciones de usuarios ya procesados en un lote.
+        #[ink(message)]
+        pub fn listar_pendientes_eleccion(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<SolicitudPendiente>>
+        {
+            self.listar_pendientes_eleccion_privado(eleccion_id, offset, limit)
+        }
+        fn listar_pendientes_eleccion_privado(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<SolicitudPendiente>>
+        {
+            if !self.puede_consultar_padron(Some(eleccion_id)) { return Err(Error::NoEsOperadorPadron); }
+            let cola = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?.cola_pendientes;
+            Ok(Self::paginar(offset, limit, cola.fin - cola.inicio, |indice| {
+                let usuario = self.cola_pendientes_eleccion.get((eleccion_id, cola.inicio + indice))?;
+                let tipo = self.pendientes_eleccion.get((eleccion_id, usuario))?;
+                Some(SolicitudPendiente { usuario, tipo })
+            }))
+        }
+
+        /// Lista los votantes aceptados en una elección, en el orden en que fueron aceptados. Se omiten los que se retiraron.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - offset: u32 - Cantidad de votantes a saltear.
+        /// - limit: u32 - Cantidad máxima de votantes a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón del sistema o de la elección, auditor, generador de reportes o administrador.
+        /// Si no, retorna Error::NoEsOperadorPadron. Retorna Error::EleccionInexistente si la elección no existe.
+        #[ink(message)]
+        pub fn listar_votantes_eleccion(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            self.listar_votantes_eleccion_privado(eleccion_id, offset, limit)
+        }
+        fn listar_votantes_eleccion_privado(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            if !self.puede_consultar_padron(Some(eleccion_id)) { return Err(Error::NoEsOperadorPadron); }
+            let eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Ok(Self::paginar(offset, limit, eleccion.cantidad_votantes, |indice| self.votantes_por_indice.get((eleccion_id, indice))))
+        }
+
+        /// Lista los candidatos aceptados en una elección, ordenados por candidato_id (el offset 0 corresponde al candidato 1).
+        /// Los candidatos retirados se omiten, así que una página puede tener menos elementos que el límite.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - offset: u32 - Cantidad de candidatos a saltear.
+        /// - limit: u32 - Cantidad máxima de candidatos a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Retorna Error::EleccionInexistente si la elección no existe.
+        #[ink(message)]
+        pub fn listar_candidatos_eleccion(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            let eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Ok(Self::paginar(offset, limit, eleccion.cantidad_candidatos, |indice| {
+                self.obtener_informacion_candidato(&eleccion, indice + 1).map(|candidato| candidato.id)
+            }))
+        }
+
+        /// Lista los usuarios rechazados en una elección, en el orden en que se rechazaron.
+        /// - eleccion_id: u64 - ID de la elección.
+        /// - offset: u32 - Cantidad de usuarios a saltear.
+        /// - limit: u32 - Cantidad máxima de usuarios a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Verifica si el llamador es operador de padrón del sistema o de la elección, auditor, generador de reportes o administrador.
+        /// Si no, retorna Error::NoEsOperadorPadron. Retorna Error::EleccionInexistente si la elección no existe.
+        /// Se omiten los usuarios rehabilitados o que volvieron a solicitar el ingreso tras apelar, y cada usuario aparece en la posición de su último rechazo.
+        #[ink(message)]
+        pub fn listar_rechazados_eleccion(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            self.listar_rechazados_eleccion_privado(eleccion_id, offset, limit)
+        }
+        fn listar_rechazados_eleccion_privado(&self, eleccion_id:u64, offset:u32, limit:u32) -> Result<Pagina<AccountId>>
+        {
+            if !self.puede_consultar_padron(Some(eleccion_id)) { return Err(Error::NoEsOperadorPadron); }
+            let eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Ok(Self::paginar(offset, limit, eleccion.cantidad_rechazados, |indice| {
+                self.rechazados_eleccion_por_indice.get((eleccion_id, indice))
+                    .filter(|usuario| self.rechazados_eleccion.get((eleccion_id, *usuario)) == Some(indice))
+            }))
+        }
+
+        /// Lista el historial de una cuenta: sus solicitudes, aceptaciones, rechazos, apelaciones, rehabilitaciones y bloqueos,
+        /// en el sistema y en todas las elecciones, del más antiguo al más nuevo.
+        /// - cuenta: AccountId - Cuenta a consultar.
+        /// - offset: u32 - Cantidad de entradas a saltear.
+        /// - limit: u32 - Cantidad máxima de entradas a retornar, como mucho MAXIMO_ELEMENTOS_POR_PAGINA.
+        /// Descripción:
+        /// Cada usuario puede consultar su propio historial. Para consultar el de otra cuenta el llamador debe ser operador de padrón,
+        /// auditor, generador de reportes o administrador. Si no, retorna Error::NoEsOperadorPadron.
+        #[ink(message)]
+        pub fn obtener_historial_usuario(&self, cuenta:AccountId, offset:u32, limit:u32) -> Result<Pagina<RegistroHistorial>>
+        {
+            self.obtener_historial_usuario_privado(cuenta, offset, limit)
+        }
+        fn obtener_historial_usuario_privado(&self, cuenta:AccountId, offset:u32, limit:u32) -> Result<Pagina<RegistroHistorial>>
+        {
+            if cuenta != self.env().caller() && !self.puede_consultar_padron(None) { return Err(Error::NoEsOperadorPadron); }
+            let total = self.cantidad_historial_usuarios.get(cuenta).unwrap_or(0);
+            Ok(Self::paginar(offset, limit, total, |indice| self.historial_usuarios.get((cuenta, indice))))
+        }
+
+        // ====-----==== METODOS PARA EL GENERADOR DE REPORTES ====----====
+        // ====-----==== METODOS PARA EL GENERADOR DE REPORTES ====----====
+        // ====-----==== METODOS PARA EL GENERADOR DE REPORTES ====----====
+
+
+        /// Utilizado por el generador de reportes asignado por el administrador.
+        /// Obtiene la informacion del usuario especificado por AccountId
+        /// user_id: AccountId: ID del usuario.
+        /// Result<(String, String, String)>: Tupla con nombre, apellido y DNI del usuario, o el error correspondiente.
+        /// Verifica si el llamador tiene permiso para generar reportes. Si no, retorna Error::NoEsGeneradorReportes. Luego, busca el usuario por su ID en la lista de usuarios.
+        /// Si el usuario es encontrado, retorna su nombre, apellido y DNI como una tupla. Si no, retorna Error::UsuarioInexistente.
+        /// Si está activada la protección del DNI, en lugar del DNI retorna un identificador derivado de su compromiso.
+        #[ink(message)]
+        pub fn obtener_informacion_usuario(&self, user_id: AccountId) -> Result<(String, String, String)>
+        {
+            self.obtener_informacion_usuario_privado(user_id)
+        }
+        pub fn obtener_informacion_usuario_privado(&self, user_id: AccountId) -> Result<(String, String, String)>
+        {
+            if !self.puede_consultar_reportes() { return Err(Error::NoEsGeneradorReportes); }
+
+            let usuario = self.obtener_usuario_o_dado_de_baja(user_id).ok_or(Error::UsuarioInexistente)?;
+            Ok((usuario.nombre.clone(), usuario.apellido.clone(), usuario.identificador_dni()))
+        }
+
+        /// Utilizado por el generador de reportes asignado por el administrador.
+        /// Obtiene la lista de votantes y su estado de voto para una elección específica.
+        /// eleccion_id: u64: ID de la elección.
+        /// Result<Vec<(AccountId,bool)>>: Vector con el ID de cada votante y si emitió su voto, o el error correspondiente.
+        /// Descripción:
+        /// La función verifica si el usuario es el generador de reportes. Si no lo es, devuelve un error. Obtiene la marca de tiempo
+        ///  del bloque actual y verifica si la elección ha finalizado. Si la elección no ha terminado, devuelve un error. Si la elección
+        ///  existe y ha finalizado, devuelve la lista de votantes con su estado de voto. Si la elección no existe, devuelve un error.
+        #[ink(message)]
+        pub fn obtener_votantes_eleccion_por_id(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,bool)>>
+        {
+            self.obtener_votantes_eleccion_por_id_privado(eleccion_id)
+        }
+        pub fn obtener_votantes_eleccion_por_id_privado(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,bool)>>
+        {
+            if !self.puede_consultar_reportes() { return Err(Error::NoEsGeneradorReportes); }
+            let block_timestamp = self.env().block_timestamp();
+            
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_eleccion_finalizada(&mut eleccion, block_timestamp)?;
+            Ok(self.obtener_votantes(&eleccion).iter().map(|votante| (votante.id, votante.voto_emitido)).collect())
+        }
+
+
+        /// Utilizado por el generador de reportes asignado por el administrador.
+        /// Obtiene la lista de candidatos y sus votos para una elección específica.
+        /// eleccion_id: u64: ID de la elección.
+        /// Result<Vec<(AccountId,u32)>>: Vector con el ID de cada candidato y su total de votos, o el error correspondiente.
+        /// Descripción:
+        /// La función verifica si el usuario es el generador de reportes. Si no lo es, devuelve un error. Obtiene la marca de tiempo del
+        /// bloque actual y verifica si la elección ha finalizado. Si la elección no ha terminado, devuelve un error. Si la elección existe
+        /// y ha finalizado, devuelve la lista de candidatos con sus votos. Si la elección no existe, devuelve un error.
+        /// En las elecciones por preferencia instantánea, los votos de cada candidato son los de la última ronda en que siguió en competencia.
+        #[ink(message)]
+        pub fn obtener_candidatos_eleccion_por_id(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,u32)>>
+        {
+            self.obtener_candidatos_eleccion_por_id_privado(eleccion_id)
+        }
+        pub fn obtener_candidatos_eleccion_por_id_privado(&mut self, eleccion_id: u64) -> Result<Vec<(AccountId,u32)>>
+        {
+            if !self.puede_consultar_reportes() { return Err(Error::NoEsGeneradorReportes); }
+            let block_timestamp = self.env().block_timestamp();
+
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_eleccion_finalizada(&mut eleccion, block_timestamp)?;
+            if eleccion.sistema_votacion == SistemaVotacion::PreferenciaInstantanea {
+                // Los votos de cada candidato salen del conteo por rondas
+                let resultados = self.obtener_resultados_privado(eleccion_id)?;
+                return Ok(resultados.votos_candidatos.iter().map(|(id, votos)| (*id, *votos as u32)).collect());
+            }
+            Ok(self.obtener_candidatos(&eleccion).iter().map(|candidato| (candidato.id, candidato.votos_totales)).collect())
+        }
+
+        /// Obtiene los resultados de una elección específica.
+        /// eleccion_id: u64: ID de la elección.
+        /// Result<Resultados>: Los resultados de la elección, o el error correspondiente.
+        /// Descripción:
+        /// La función obtiene la marca de tiempo del bloque actual y busca la elección por su ID. Si no encuentra la elección,
+        /// devuelve Error::EleccionInexistente. Luego, intenta obtener los resultados de la votación usando la marca de tiempo. Si los resultados
+        /// aún no están disponibles, devuelve Error::EleccionNoFinalizada, o Error::EleccionCancelada si la elección fue cancelada.
+        /// Si están disponibles, devuelve una copia de los resultados.
+        #[ink(message)]
+        pub fn obtener_resultados(&mut self, eleccion_id:u64) -> Result<Resultados>
+        {
+            self.obtener_resultados_privado(eleccion_id)
+        }
+        fn obtener_resultados_privado(&mut self,eleccion_id: u64) -> Result<Resultados>
+        {
+            let block_timestamp= self.env().block_timestamp();
+            let mut eleccion = self.obtener_eleccion_por_id(eleccion_id).ok_or(Error::EleccionInexistente)?;
+            Self::validar_eleccion_finalizada(&mut eleccion, block_timestamp)?;
+            let calculados = eleccion.resultados.is_none();
+            let resultados = self.obtener_resultados_votacion(&mut eleccion, block_timestamp).ok_or(Error::EleccionNoFinalizada)?;
+            if calculados { self.guardar_eleccion(&eleccion); }
+            Ok(resultados)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::codegen::Env;
+        use ink::env::test::advance_block;
+        use ink::env::test::{ default_accounts, set_block_timestamp, DefaultAccounts };
+        use ink::env::DefaultEnvironment;
+        
+        fn get_default_test_accounts() -> DefaultAccounts<ink::env::DefaultEnvironment> 
+        {
+            default_accounts::<ink::env::DefaultEnvironment>()
+        }
+    
+        fn set_caller(caller: AccountId) 
+        {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn set_contract(caller: AccountId) 
+        {
+            ink::env::test::set_contract::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn setup_eleccion() -> Eleccion 
+        {
+            Eleccion::nueva(1, 0, 0)
+        }
+
+        fn crear_usuario(id: AccountId, nombre: &str, apellido: &str, dni: &str) -> Usuario 
+        {
+            Usuario {
+                id,
+                nombre: nombre.to_string(),
+                apellido: apellido.to_string(),
+                dni: dni.to_string(),
+                compromiso_dni: None,
+                huella_dni: None,
+            }
+        }
+
+        // Crea una elección cuyas fechas pueden ser anteriores al timestamp actual del bloque, como si se hubiera creado antes
+        fn crear_eleccion_anterior(contrato: &mut SistemaElecciones, fecha_inicial: &str, fecha_final: &str, configuracion: ConfiguracionEleccion) -> u64
+        {
+            let ahora = ink::env::block_timestamp::<DefaultEnvironment>();
+            set_block_timestamp::<DefaultEnvironment>(0);
+            let eleccion_id = contrato.crear_eleccion_privado(fecha_inicial.to_string(), fecha_final.to_string(), configuracion).unwrap();
+            set_block_timestamp::<DefaultEnvironment>(ahora);
+            eleccion_id
+        }
+
+        fn crear_trabajo_final(administrador: AccountId) -> SistemaElecciones 
+        {
+            let mut contrato = SistemaElecciones::new();
+            cambiar_administrador(&mut contrato, administrador);
+            contrato
+        }
+
+        // Deja a `administrador` como único administrador, quitándole el rol a las cuentas de prueba que hayan creado el contrato
+        fn cambiar_administrador(contrato: &mut SistemaElecciones, administrador: AccountId)
+        {
+            let accounts = get_default_test_accounts();
+            for cuenta in [accounts.alice, accounts.bob, accounts.charlie, accounts.django, accounts.eve, accounts.frank] {
+                contrato.roles.remove((cuenta, Rol::Administrador));
+            }
+            contrato.roles.insert((administrador, Rol::Administrador), &());
+            contrato.cantidad_administradores = 1;
+        }
+
+        // Guarda una elección armada a mano, como si hubiera sido creada con crear_eleccion
+        fn insertar_eleccion(contrato: &mut SistemaElecciones, eleccion: Eleccion)
+        {
+            if eleccion.id > contrato.cantidad_elecciones {
+                contrato.cantidad_elecciones = eleccion.id;
+            }
+            contrato.guardar_eleccion(&eleccion);
+        }
+
+        // Agrega un usuario al final de la cola de usuarios pendientes del sistema
+        fn agregar_usuario_pendiente(contrato: &mut SistemaElecciones, usuario: Usuario)
+        {
+            let posicion = contrato.posiciones_usuarios_pendientes.encolar().unwrap();
+            contrato.cola_usuarios_pendientes.insert(posicion, &usuario.id);
+            contrato.posiciones_en_cola_usuarios.insert(usuario.id, &posicion);
+            contrato.usuarios_pendientes.insert(usuario.id, &usuario);
+        }
+
+        // Agrega un usuario al final de la cola de usuarios pendientes de una elección
+        fn agregar_usuario_pendiente_en_eleccion(contrato: &mut SistemaElecciones, eleccion_id: u64, id: AccountId, tipo: TIPO_DE_USUARIO)
+        {
+            let mut eleccion = contrato.obtener_eleccion_por_id(eleccion_id).unwrap();
+            let posicion = eleccion.cola_pendientes.encolar().unwrap();
+            contrato.cola_pendientes_eleccion.insert((eleccion_id, posicion), &id);
+            contrato.posiciones_pendientes_eleccion.insert((eleccion_id, id), &posicion);
+            contrato.pendientes_eleccion.insert((eleccion_id, id), &tipo);
+            contrato.agregar_eleccion_de_usuario(id, eleccion_id);
+            contrato.guardar_eleccion(&eleccion);
+        }
+
+        // Inscribe y acepta directamente a un usuario en una elección
+        fn agregar_participante(contrato: &mut SistemaElecciones, eleccion_id: u64, id: AccountId, tipo: TIPO_DE_USUARIO)
+        {
+            agregar_usuario_pendiente_en_eleccion(contrato, eleccion_id, id, tipo);
+            let mut eleccion = contrato.obtener_eleccion_por_id(eleccion_id).unwrap();
+            contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, true).unwrap();
+            contrato.guardar_eleccion(&eleccion);
+        }
+
+        fn cambiar_estado(contrato: &mut SistemaElecciones, eleccion_id: u64, estado: EstadoEleccion)
+        {
+            let mut eleccion = contrato.obtener_eleccion_por_id(eleccion_id).unwrap();
+            eleccion.estado = estado;
+            contrato.guardar_eleccion(&eleccion);
+        }
+
+        type Event = <SistemaElecciones as ink::reflect::ContractEventBase>::Type;
+
+        fn eventos_emitidos() -> Vec<Event>
+        {
+            ink::env::test::recorded_events()
+                .map(|evento| <Event as scale::Decode>::decode(&mut &evento.data[..]).expect("No se pudo decodificar el evento."))
+                .collect()
+        }
+    
+        // ====================== INICIO TESTS ERRORES ======================
+        // ====================== INICIO TESTS ERRORES ======================
+        // ====================== INICIO TESTS ERRORES ======================
+
+        #[test]
+        fn test_no_es_administrador() 
+        {
+            let error = Error::NoEsAdministrador;
+            assert_eq!(error.to_string(), "No eres el administrador.");
+        }
+    
+        #[test]
+        fn test_usuario_no_registrado() 
+        {
+            let error = Error::UsuarioNoRegistrado;
+            assert_eq!(error.to_string(), "No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.");
+        }
+            
+        #[test]
+        fn test_error_usuario_no_registrado() 
+        {
+            let error = Error::UsuarioNoRegistrado;
+            assert_eq!(error.to_string(), "No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.");
+        }
+
+        #[test]
+        fn test_error_codificacion_scale()
+        {
+            // Los contratos que llaman a SistemaElecciones reciben el error codificado y deben poder decodificarlo
+            let error = Error::VotoYaEmitido;
+            let codificado = scale::Encode::encode(&error);
+            let decodificado: Error = scale::Decode::decode(&mut &codificado[..]).unwrap();
+            assert_eq!(decodificado, Error::VotoYaEmitido);
+        }
+    
+        // ====================== INICIO TESTS ELECCION ======================
+        // ====================== INICIO TESTS ELECCION ======================
+        // ====================== INICIO TESTS ELECCION ======================
+
+        #[test]
+        fn test_parsear_fecha()
+        {
+            // Todas representan el 01-07-2024 a las 12:00 UTC. Sin desplazamiento ambos formatos se toman en UTC
+            for fecha in [
+                "01-07-2024 12:00",
+                "01-07-2024 09:00 -03:00",
+                "01-07-2024 09:00 UTC-3",
+                "01-07-2024 09:00 Etc/GMT+3",
+                "01-07-2024 17:30 +0530",
+                "2024-07-01T09:00:00-03:00",
+                "2024-07-01T12:00Z",
+                "2024-07-01T12:00",
+                "2024-07-01T12:00:00",
+                "1719835200000",
+            ] {
+                assert_eq!(SistemaElecciones::parsear_fecha(fecha), Some(1719835200000), "{fecha}");
+            }
+            for fecha in [
+                "", "01-07-2024", "01-07-2024 12:00 +25:00", "01-07-2024 12:00 America/Argentina", "2024-07-01T12", "2024-07-01 12:00", "31-06-2024 12:00",
+                "01-07-2024 09:00 ñ03", "01-07-2024 09:00 -0ñ3",
+            ] {
+                assert_eq!(SistemaElecciones::parsear_fecha(fecha), None, "{fecha}");
+            }
+        }
+
+        #[ink::test]
+        fn test_crear_eleccion_valida_fechas()
+        {
+            set_caller(get_default_test_accounts().alice);
+            let mut contrato = SistemaElecciones::new();
+            set_block_timestamp::<DefaultEnvironment>(1719835200000);
+
+            let mut crear = |inicio: &str, fin: &str| contrato.crear_eleccion_privado(inicio.to_string(), fin.to_string(), ConfiguracionEleccion::default());
+            assert_eq!(crear("02-07-2024 12:00", "01-07-2024 12:00"), Err(Error::OrdenDeFechasInvalido));
+            assert_eq!(crear("02-07-2024 12:00", "02-07-2024 12:59"), Err(Error::DuracionInsuficiente));
+            assert_eq!(crear("01-07-2024 12:00", "02-07-2024 12:00"), Err(Error::FechaEnElPasado));
+            assert_eq!(crear("02-07-2024 12:00", "02-07-2024 13:00"), Ok(1));
+            assert_eq!(crear("2024-07-02T09:00:00-03:00", "02-07-2024 18:00 -03:00"), Ok(2));
+            assert_eq!(contrato.obtener_eleccion_por_id(2).unwrap().fecha_inicio, 1719921600000);
+        }
+
+        #[ink::test]
+        fn test_obtener_resultados_votacion() 
+        {
+            let administrador: AccountId = AccountId::from([0x1; 32]);
+            set_caller(administrador);
+
+            let mut contrato = SistemaElecciones::new();
+
+            set_contract(administrador);
+
+            let _ = contrato.activar_registro();
+            let _ = contrato.crear_eleccion_privado(
+                "01-01-2023 12:00".to_string(),
+                "31-01-2025 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            let _ = contrato.crear_eleccion_privado(
+                "01-01-2023 12:00".to_string(),
+                "31-01-2023 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            set_block_timestamp::<ink::env::DefaultEnvironment>(1675666400000);
+
+            let mut eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert!(contrato.obtener_resultados_votacion(&mut eleccion, 1675666400000).is_none()); // No obtiene resultados porque la elección no finalizó
+
+            let mut eleccion = contrato.obtener_eleccion_por_id(2).unwrap();
+            let resultados = Resultados {votos_totales:0, votos_candidatos:Vec::new(), votos_realizados: 0, votos_delegados: 0, votos_sin_revelar: 0, votos_en_blanco: 0, votos_nulos: 0, rondas: Vec::new(), ganadores: Vec::new(), empatados_en_corte: Vec::new() };
+            assert_eq!(contrato.obtener_resultados_votacion(&mut eleccion, 1675666400000), Some(resultados.clone())); // Obtiene resultados, procesandolos por ser la primera vez
+            assert_eq!(contrato.obtener_resultados_votacion(&mut eleccion, 1675666400000), Some(resultados)); // Obtiene de nuevo los resultados, pero no los procesa porque ya los tiene
+        }
+
+        #[ink::test]
+        fn test_desactivar_registro() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]); // Ejemplo de ID de administrador
+
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
+
+            // Establece el llamante como administrador (reemplaza con el AccountId real)
+            set_caller(id_administrador);
+
+            // Llama a la función del contrato que requiere env().caller()
+            let result = contrato.desactivar_registro();
+
+            // Asegúrate de que el resultado sea el esperado
+            assert_eq!(result, Err(Error::RegistroYaDesactivado));
+        }
+
+        #[ink::test]
+        fn test_obtener_resultados_privado_exito() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]); 
+            let id_candidato_1 = AccountId::from([0x06; 32]);
+            let id_candidato_2 = AccountId::from([0x06; 32]);
+            let id_candidato_3 = AccountId::from([0x06; 32]);
+            let id_candidato_4 = AccountId::from([0x06; 32]);
+
+            let vector_votos_candidatos = vec![
+                (id_candidato_1, 13),
+                (id_candidato_4, 7),
+                (id_candidato_2, 3),
+                (id_candidato_3, 2)
+            ];
+
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
+
+            // Simular una elección válida con usuarios pendientes
+            insertar_eleccion(&mut contrato, Eleccion {
+                resultados: Some(Resultados { 
+                    votos_totales: 30, 
+                    votos_realizados: 25,
+                    votos_delegados: 0,
+                    votos_sin_revelar: 0,
+                    votos_en_blanco: 0,
+                    votos_nulos: 0,
+                    votos_candidatos: vector_votos_candidatos,
+                    rondas: vec![],
+                    ganadores: vec![id_candidato_1],
+                    empatados_en_corte: vec![],
+                }),
+                ..Eleccion::nueva(1, 50, 100)
+            });
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(705);
+
+            let result_obtener = contrato.obtener_resultados_privado(1);
+            
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_ok(), "Error al obtener los resultados de la elección");
+        }
+
+        #[ink::test]
+        fn test_obtener_resultados_privado_fallo_eleccion_inexistente() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]);
+
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
+
+            // Simular una elección 
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 0, 100));
+
+            let result_obtener = contrato.obtener_resultados_privado(10);
+
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_err(), "Se esperaba un error al obtener los resultados de la elección (no se encontró elección)");
+        }
+
+        #[ink::test]
+        fn test_obtener_resultados_privado_fallo_no_finalizo() 
+        {
+            // Configuración inicial del contrato y del contexto de prueba
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]); // Ejemplo de ID de administrador
+
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
+
+            // Simular una elección válida sin usuarios pendientes
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 50, 100));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(75);
+
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_resultados_privado(1);
+
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_err(), "Se esperaba un error al obtener el siguiente usuario pendiente en la elección (la eleccion no finalizo)");
+        }
+
+        #[ink::test]
+        fn test_obtener_informacion_usuario_privado()
+        {
+            let generador_reportes = AccountId::from([0; 32]);
+            let administrador = AccountId::from([1; 32]);
+            let user_id = AccountId::from([2; 32]);
+            let random_user = AccountId::from([12; 32]);
+            set_caller(administrador);
+            let mut contrato = SistemaElecciones::new();
+            assert!(contrato.asignar_generador_reportes(generador_reportes).is_ok());
+
+            set_caller(generador_reportes);
+            //Usuario no existente
+            let resultado = contrato.obtener_informacion_usuario_privado(user_id);
+            assert_eq!(resultado, Err(Error::UsuarioInexistente));
+            
+            let user = Usuario { id: user_id, nombre: "Joaquin".to_string(), apellido: "Fontana".to_string(), dni: "22222".to_string(), compromiso_dni: None, huella_dni: None };
+            let nombre = user.nombre.clone();
+            let apellido = user.apellido.clone();
+            let dni = user.dni.clone();
+            contrato.usuarios.insert(user_id, &user);
+            
+            //Intentar llamar sin ser el generador reporte o admin
+            set_caller(random_user);
+            assert!(contrato.obtener_informacion_usuario_privado(user_id).is_err());
+            
+            set_caller(administrador);
+            assert!(contrato.obtener_informacion_usuario_privado(user_id).is_ok_and(|tupla| tupla.0 == nombre && tupla.1 == apellido && tupla.2 == dni));
+            
+            set_caller(generador_reportes);
+            assert!(contrato.obtener_informacion_usuario_privado(user_id).is_ok_and(|tupla| tupla.0 == nombre && tupla.1 == apellido && tupla.2 == dni));
+        }
+
+        #[ink::test]
+        fn test_obtener_votantes_eleccion_por_id_privado() 
+        {
+            let administrador: AccountId = AccountId::from([0x1; 32]);
+            set_caller(administrador);
+
+            let mut contrato = SistemaElecciones::new();
 
             set_contract(administrador);
+            
+            let _ = contrato.activar_registro();
+            let _ = contrato.crear_eleccion_privado(
+                "01-01-2023 12:00".to_string(),
+                "31-01-2025 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            let _ = contrato.crear_eleccion_privado(
+                "01-01-2023 12:00".to_string(),
+                "31-01-2023 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            set_block_timestamp::<ink::env::DefaultEnvironment>(1675666400000);
+
+            assert!(contrato.obtener_votantes_eleccion_por_id_privado(1).is_err()); // No se obtienen resultados porque no es el generador de reportes
+            let _ = contrato.asignar_generador_reportes_privado(administrador);
+            assert!(contrato.obtener_votantes_eleccion_por_id_privado(1).is_err()); // No se obtienen resultados porque la elección no finalizó
+            
+            let err = contrato.obtener_votantes_eleccion_por_id_privado(2); // Se obtienen resultados porque es el generador de reportes y finalizó la elección
+            assert!(err.is_ok());
+
+            let err = contrato.obtener_votantes_eleccion_por_id_privado(3); // No se obtienen resultados porque no existe la elección
+            assert!(err.is_err());
+        }
+
+        #[ink::test]
+        fn test_contiene_usuario_pendiente() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let accounts: DefaultAccounts<DefaultEnvironment> = get_default_test_accounts(); // Cuentas predeterminadas utilizadas para tests
+            insertar_eleccion(&mut contrato, setup_eleccion());
+            
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::VOTANTE);
+            
+            assert!(contrato.contiene_usuario_pendiente(1, accounts.alice));
+            assert!(!contrato.contiene_usuario_pendiente(1, accounts.bob));
+        }
+    
+        #[ink::test]
+        fn test_existe_candidato() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let accounts = get_default_test_accounts();
+            insertar_eleccion(&mut contrato, setup_eleccion());
+            
+            agregar_participante(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::CANDIDATO);
+    
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert!(!contrato.existe_candidato(&eleccion, 0));
+            assert!(contrato.existe_candidato(&eleccion, 1));
+            assert!(!contrato.existe_candidato(&eleccion, 2));
+        }
+    
+        #[test]
+        fn test_cola() 
+        {
+            let mut cola = Cola::default();
+            assert!(cola.esta_vacia());
+            assert_eq!(cola.desencolar(), None);
+
+            assert_eq!(cola.encolar(), Ok(0));
+            assert_eq!(cola.encolar(), Ok(1));
+            assert_eq!(cola.desencolar(), Some(0));
+            assert_eq!(cola.desencolar(), Some(1));
+            assert!(cola.esta_vacia());
+
+            // Las posiciones no se reutilizan para no pisar entradas de la cola
+            assert_eq!(cola.encolar(), Ok(2));
+
+            let mut cola_llena = Cola { inicio: u32::MAX, fin: u32::MAX };
+            assert_eq!(cola_llena.encolar(), Err(Error::Overflow));
+        }
+    
+        #[ink::test]
+        fn test_votar_candidato() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let accounts = get_default_test_accounts();
+            insertar_eleccion(&mut contrato, setup_eleccion());
+    
+            agregar_participante(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_participante(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::VOTANTE);
+    
+            let mut eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            let result = contrato.emitir_voto(&mut eleccion, accounts.bob, Voto::Candidato(1));
+            assert_eq!(result, Ok(()));
+            assert_eq!(eleccion.votos_emitidos, 1);
+            assert!(contrato.votantes.get((1, accounts.bob)).unwrap().voto_emitido);
+            assert_eq!(contrato.candidatos.get((1, 1)).unwrap().votos_totales, 1);
+        }
+    
+        #[ink::test]
+        fn test_procesar_siguiente_usuario_pendiente() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let accounts = get_default_test_accounts();
+            insertar_eleccion(&mut contrato, setup_eleccion());
+    
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::VOTANTE);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
+            let mut eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+    
+            let result = contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, true);
+            assert_eq!(result, Ok((accounts.alice, TIPO_DE_USUARIO::VOTANTE)));
+            assert_eq!(eleccion.cantidad_votantes, 1);
+            assert_eq!(eleccion.cantidad_candidatos, 0);
+    
+            let result = contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, true);
+            assert_eq!(result, Ok((accounts.bob, TIPO_DE_USUARIO::CANDIDATO)));
+            assert_eq!(eleccion.cantidad_votantes, 1);
+            assert_eq!(eleccion.cantidad_candidatos, 1);
+            assert!(!contrato.contiene_usuario_pendiente(1, accounts.bob));
+    
+            let result = contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, false);
+            assert_eq!(result, Err(Error::NoHayUsuariosPendientes));
+        }
+    
+        #[ink::test]
+        fn test_obtener_informacion_candidato() 
+        {
+            let administrador: AccountId = AccountId::from([0x1; 32]);
+            let bob: AccountId = AccountId::from([0x2; 32]);
+            set_caller(administrador);
+
+            let mut contrato = SistemaElecciones::new();
+
+            let _ = contrato.activar_registro();
+
+            let _ = contrato.crear_eleccion(
+                String::from("01-07-2024 12:00"),
+                String::from("31-12-2024 12:00"), ConfiguracionEleccion::default()
+            );
+
+            set_caller(bob);
+
+            let _ = contrato.registrarse(String::from("Bob"), String::from("Bob"), String::from("12345678"));
+
+            set_caller(administrador);
+
+            let _ = contrato.procesar_siguiente_usuario_pendiente(true);
+
+            set_caller(bob);
+
+            let _ = contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO);
+
+            set_caller(administrador);
+
+            let _ = contrato.procesar_usuarios_en_una_eleccion_privado(1, true);
+
+            set_caller(bob);
+
+            // El código de arriba simúla la activación del registro, creación de una elección, registro de una persona, 
+            // aceptación del registro de una persona, registro de una persona a una elección y aceptación de ese registro en la elección
+
+            let res = contrato.obtener_informacion_candidato_eleccion(1, 1);
+            assert!(res.is_ok()); // Se obtiene la información del candidato porque existe la elección y existe el candidato
+            let candidato = res.unwrap();
+            assert_eq!((candidato.candidato_id, candidato.id), (1, bob));
+
+            let res = contrato.obtener_informacion_candidato_eleccion(2, 1);
+            assert!(res.is_err()); // No se obtiene la información porque la elección no existe
+
+            let res = contrato.obtener_informacion_candidato_eleccion(1, 2);
+            assert!(res.is_err()); // No se obtiene la información porque el candidato no existe
+        }
+
+        #[ink::test]
+        fn test_procesar_siguiente_usuario_pendiente_eleccion() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let accounts = default_accounts::<DefaultEnvironment>();
+            insertar_eleccion(&mut contrato, setup_eleccion());
+    
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::VOTANTE);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
+            let mut eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+    
+            let result = contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, true);
+            assert_eq!(result, Ok((accounts.alice, TIPO_DE_USUARIO::VOTANTE)));
+            assert_eq!(eleccion.cantidad_votantes, 1);
+            assert_eq!(eleccion.cantidad_candidatos, 0);
+    
+            let result = contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, true);
+            assert_eq!(result, Ok((accounts.bob, TIPO_DE_USUARIO::CANDIDATO)));
+            assert_eq!(eleccion.cantidad_votantes, 1);
+            assert_eq!(eleccion.cantidad_candidatos, 1);
+            assert!(!contrato.contiene_usuario_pendiente(1, accounts.bob));
+    
+            let result = contrato.procesar_siguiente_usuario_pendiente_en_eleccion(&mut eleccion, false);
+            assert_eq!(result, Err(Error::NoHayUsuariosPendientes));
+        }
+
+        #[ink::test]
+        fn test_votar_a_candidato_privado() 
+        {
+            let accounts = get_default_test_accounts();
+            let alice = accounts.alice;
+            let charlie = accounts.charlie;
+            let bob = accounts.bob;
+
+            set_caller(alice);
+            let mut contrato = SistemaElecciones::new();
+            let nueva_eleccion = contrato.crear_eleccion_privado(
+                String::from("01-07-2024 12:00"),
+                String::from("31-12-2024 12:00"), ConfiguracionEleccion::default()
+            );
+            assert_eq!(nueva_eleccion, Ok(1) );
+            contrato.activar_registro_privado().unwrap();
+
+            // Usuarios se registran
+            set_caller(bob);
+            let result = contrato.registrarse_privado("Bob".to_string(), "Asd".to_string(), "12345678".to_string() );
+            assert_eq!(result, Ok(()) );
+            set_caller(charlie);
+            contrato.registrarse_privado("Charlie".to_string(), "Dsa".to_string(), "87654321".to_string() ).unwrap();
+            assert_eq!(result, Ok(()) );
+
+            // Alice aprueba registro en sistema
+            set_caller(alice);
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+        
+            // Usuarios se registran en eleccion
+            set_caller(bob);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO).unwrap();
+            set_caller(charlie);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE).unwrap();
+
+            set_caller(alice);
+            let result = contrato.procesar_usuarios_en_una_eleccion_privado(1, true);
+            assert_eq!(result, Ok(()));
+            let result = contrato.procesar_usuarios_en_una_eleccion_privado(1, true);
+            assert_eq!(result, Ok(()));
+
+            // Vota en eleccion usuario no registrado
+            set_caller(alice);
+            let result = contrato.votar_a_candidato_privado(1, 1);
+            assert_eq!(result, Err(Error::UsuarioNoRegistrado) );
+
+            set_caller(charlie);
+            // Vota en eleccion que todavia no inicio
+            let result = contrato.votar_a_candidato_privado(1, 1);
+            assert_eq!(result, Err(Error::VotacionNoIniciada) );
+
+            cambiar_estado(&mut contrato, 1, EstadoEleccion::Votacion);
+            // Voto a candidato inexistente
+            let result = contrato.votar_a_candidato_privado(1, 2);
+            assert_eq!(result, Err(Error::CandidatoInexistente) );
 
-            let _ = contrato.activar_registro();
-            let _ = contrato.crear_eleccion_privado(
-                "01-01-2023 12:00".to_string(),
-                "31-01-2025 12:00".to_string()
-            );
-            let _ = contrato.crear_eleccion_privado(
-                "01-01-2023 12:00".to_string(),
-                "31-01-2023 12:00".to_string()
-            );
+            // Voto exitoso
+            let result = contrato.votar_a_candidato_privado(1, 1);
+            assert_eq!(result, Ok(()));
+
+            // Volver a votar en una eleccion que ya voto
+            let result = contrato.votar_a_candidato_privado(1, 1);
+            assert_eq!(result, Err(Error::VotoYaEmitido));
+
+            // Vota eleccion inexistente
+            let result = contrato.votar_a_candidato_privado(7, 1);
+            assert_eq!(result, Err(Error::EleccionInexistente) );
+            
+            // Usuario no registrado como votante trata de votar
+            set_caller(bob);
+            let result = contrato.votar_a_candidato_privado(1, 1);
+            assert_eq!(result, Err(Error::NoRegistradoEnEleccion) );
+        }
+
+        #[ink::test]
+        fn test_obtener_informacion_siguiente_usuario_pendiente_privado() {
+            let accounts = get_default_test_accounts();
+            let alice = accounts.alice;
+            let charlie = accounts.charlie;
+            let bob = accounts.bob;
+
+            set_caller(alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
+            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
+            assert_eq!(result, Err(Error::NoHayUsuariosPendientes) );
+            
+            set_caller(bob);
+            contrato.registrarse_privado("Bob".to_string(), "Asd".to_string(), "12345678".to_string()).unwrap();
+            assert!(contrato.usuarios_pendientes.contains(bob));
+            
+            set_caller(alice);
+            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
+            let info_bob = UsuarioInfo { id: bob, nombre: "Bob".to_string(), apellido: "Asd".to_string(), dni: "12345678".to_string() };
+            assert_eq!(result, Ok(info_bob) );
+
+            set_caller(charlie);
+            contrato.registrarse_privado("Charlie".to_string(), "Asd".to_string(), "87654321".to_string()).unwrap();
+            assert!(contrato.usuarios_pendientes.contains(charlie));
+
+            set_caller(alice);
+            // Aprobamos a Bob para ver la informacion de Charlie
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+
+            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
+            let info_charlie = UsuarioInfo { id: charlie, nombre: "Charlie".to_string(), apellido: "Asd".to_string(), dni: "87654321".to_string() };
+            assert_eq!(result, Ok(info_charlie) );
+
+            // Aprobamos a todos los usuarios pendientes y volvemos a consultar
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
+            assert_eq!(result, Err(Error::NoHayUsuariosPendientes) );
+        }
+
+        // ====================== FIN TESTS ELECCION ======================
+        // ====================== FIN TESTS ELECCION ======================
+        // ====================== FIN TESTS ELECCION ======================
+    
+        // ====================== INICIO TESTS SISTEMA ELECCIONES ======================
+        // ====================== INICIO TESTS SISTEMA ELECCIONES ======================
+        // ====================== INICIO TESTS SISTEMA ELECCIONES ======================
+        
+        #[ink::test]
+        fn test_constructor() 
+        {
+            let accounts = get_default_test_accounts();
+            let alice = accounts.alice;
+            let charlie = accounts.charlie;
+            set_caller(alice);
+    
+            let sistema_elecciones = SistemaElecciones::new();
+            assert!(!sistema_elecciones.registro_activado);
+            assert!(sistema_elecciones.tiene_rol(alice, Rol::Administrador));
+            assert!(!sistema_elecciones.tiene_rol(charlie, Rol::Administrador));
+        }
 
+        #[ink::test]
+        fn test_obtener_informacion_candidato_eleccion() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let accounts = default_accounts::<DefaultEnvironment>();
+            insertar_eleccion(&mut contrato, setup_eleccion());
+            agregar_participante(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_participante(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
             let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
-            assert!(eleccion.obtener_resultados_votacion(1675666400000).is_none()); // No obtiene resultados porque la elección no finalizó
 
-            let eleccion = contrato.obtener_eleccion_por_id(2).unwrap();
-            let resultados = Resultados {votos_totales:0, votos_candidatos:Vec::new(), votos_realizados: 0 };
-            assert_eq!(eleccion.obtener_resultados_votacion(1675666400000), Some(&resultados)); // Obtiene resultados, procesandolos por ser la primera vez
-            assert_eq!(eleccion.obtener_resultados_votacion(1675666400000), Some(&resultados)); // Obtiene de nuevo los resultados, pero no los procesa porque ya los tiene
+            let candidato_info = contrato.obtener_informacion_candidato(&eleccion, 1);
+            assert!(candidato_info.is_some());
+            assert_eq!(candidato_info.unwrap().id, accounts.alice);
+
+            let candidato_info = contrato.obtener_informacion_candidato(&eleccion, 2);
+            assert!(candidato_info.is_some());
+            assert_eq!(candidato_info.unwrap().id, accounts.bob);
+    
+            let candidato_info = contrato.obtener_informacion_candidato(&eleccion, 3);
+            assert!(candidato_info.is_none());
         }
 
         #[ink::test]
-        fn test_desactivar_registro() 
+        fn test_obtener_informacion_siguiente_usuario_pendiente() 
         {
+            let administrador: AccountId = AccountId::from([0x1; 32]);
+            let otro_usuario: AccountId = AccountId::from([0x2; 32]);
+            set_caller(administrador);
+
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]); // Ejemplo de ID de administrador
+            
+            let usuario = Usuario { id: (otro_usuario), nombre: ("Joaquin".to_string()), apellido: ("Fontana".to_string()), dni: ("22222222".to_string()), compromiso_dni: None, huella_dni: None };
+            let info = UsuarioInfo::from(usuario.clone());
+            //Intentar obtener informacion sin usuarios pendientes
+            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
+            assert!(result.is_err());
+            
+            agregar_usuario_pendiente(&mut contrato, usuario);
 
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
+            assert_eq!(result, Ok(info));
+        }
 
-            // Establece el llamante como administrador (reemplaza con el AccountId real)
-            set_caller(id_administrador);
+        #[ink::test]
+        fn test_transferir_administrador() 
+        {
+            let accounts = get_default_test_accounts();
+            let alice = accounts.alice;
+            let charlie = accounts.charlie;
+            let bob = accounts.bob;
+            set_caller(alice);
+    
+            let mut sistema_elecciones = SistemaElecciones::new();
+    
+            let result = sistema_elecciones.transferir_administrador_privado(charlie);
+            assert!(result.is_ok());
+            // Alice sigue siendo administradora hasta que Charlie acepte
+            assert!(sistema_elecciones.tiene_rol(alice, Rol::Administrador));
+            assert!(!sistema_elecciones.tiene_rol(charlie, Rol::Administrador));
 
-            // Llama a la función del contrato que requiere env().caller()
-            let result = contrato.desactivar_registro();
+            set_caller(bob);
+            assert_eq!(sistema_elecciones.aceptar_administrador_privado(), Err(Error::NoEsAdministradorPropuesto));
+            set_caller(charlie);
+            assert_eq!(sistema_elecciones.aceptar_administrador_privado(), Ok(()));
+            assert_eq!(sistema_elecciones.obtener_transferencia_administrador(), None);
+            assert!(!sistema_elecciones.tiene_rol(alice, Rol::Administrador));
+            assert!(sistema_elecciones.tiene_rol(charlie, Rol::Administrador));
+    
+            set_caller(bob);
+            let result = sistema_elecciones.transferir_administrador_privado(charlie);
+            assert!(result.is_err());
+            assert!(!sistema_elecciones.tiene_rol(alice, Rol::Administrador));
+            assert!(sistema_elecciones.tiene_rol(charlie, Rol::Administrador));
+        }
 
-            // Asegúrate de que el resultado sea el esperado
-            assert_eq!(result, Err("El registro ya está desactivado.".to_string()));
+        #[ink::test]
+        fn test_obtener_candidatos_eleccion_por_id()
+        {
+            let administrador: AccountId = AccountId::from([0x1; 32]);
+            let generador_reportes: AccountId = AccountId::from([0x2; 32]);
+            set_caller(administrador);
+            let mut contrato = SistemaElecciones::new();
+            assert!(contrato.asignar_generador_reportes(generador_reportes).is_ok());
+            assert!(contrato.tiene_rol(generador_reportes, Rol::GeneradorReportes));
+            let mut eleccion = setup_eleccion();
+            let eleccion_id = eleccion.id;
+            eleccion.fecha_final = contrato.env().block_timestamp();
+            insertar_eleccion(&mut contrato, eleccion);
+            advance_block::<ink::env::DefaultEnvironment>();
+            set_caller(generador_reportes);
+            let resultado = contrato.obtener_candidatos_eleccion_por_id_privado(eleccion_id);
+
+            // Verificar el resultado y manejar el error
+            assert!(
+                resultado.is_ok(),
+                "Error al obtener candidatos para la elección: {:?}",
+                resultado.unwrap_err().to_string()
+            );
+        }
+    
+        #[ink::test]
+        fn test_obtener_usuario() 
+        {
+            let accounts = get_default_test_accounts();
+            let id: AccountId = accounts.alice;
+            let mut trabajo_final = SistemaElecciones::new();
+            let usuario = crear_usuario(id, "Juan", "Perez", "12345678");
+            trabajo_final.usuarios.insert(id, &usuario);
+    
+            let result = trabajo_final.obtener_usuario(id);
+            assert!(result.is_some());
+            let user = result.unwrap();
+            assert_eq!(user.nombre, "Juan");
+            assert_eq!(user.apellido, "Perez");
+            assert_eq!(user.dni, "12345678");
+        }
+    
+        #[ink::test]
+        fn test_es_usuario_registrado() 
+        {
+            let accounts = get_default_test_accounts();
+            let id: AccountId = accounts.alice;
+            let mut trabajo_final = SistemaElecciones::new();
+            let usuario = crear_usuario(id, "Juan", "Perez", "12345678");
+            trabajo_final.usuarios.insert(id, &usuario);
+    
+            assert!(trabajo_final.es_usuario_registrado());
+        }
+    
+        #[ink::test]
+        fn test_es_usuario_pendiente() 
+        {
+            let accounts = get_default_test_accounts();
+            let id: AccountId = accounts.alice;
+            let mut trabajo_final = SistemaElecciones::new();
+            let usuario = crear_usuario(id, "Juan", "Perez", "12345678");
+            agregar_usuario_pendiente(&mut trabajo_final, usuario);
+    
+            assert!(trabajo_final.es_usuario_pendiente());
+        }
+    
+        #[ink::test]
+        fn test_existe_eleccion() 
+        {
+            let id: AccountId = [0; 32].into();
+            let mut trabajo_final = crear_trabajo_final(id);
+            insertar_eleccion(&mut trabajo_final, setup_eleccion());
+    
+            assert!(trabajo_final.existe_eleccion(1));
+            assert!(!trabajo_final.existe_eleccion(2));
+        }
+    
+        #[ink::test]
+        fn test_obtener_eleccion_por_id() 
+        {
+            let id: AccountId = [0; 32].into();
+            let mut trabajo_final = crear_trabajo_final(id);
+            insertar_eleccion(&mut trabajo_final, setup_eleccion());
+    
+            let result = trabajo_final.obtener_eleccion_por_id(1);
+            assert!(result.is_some());
+            let eleccion_obtenida = result.unwrap();
+            assert_eq!(eleccion_obtenida.id, 1);
+    
+            assert!(trabajo_final.obtener_eleccion_por_id(2).is_none());
         }
 
+
         #[ink::test]
-        fn test_obtener_resultados_privado_exito() 
+        fn test_activar_registro() 
+        {
+            let mut contract = SistemaElecciones::new();
+
+            let res = contract.activar_registro_privado();
+            assert_eq!(res, Ok(()));
+            assert!(contract.registro_activado);
+
+            let res = contract.activar_registro_privado();
+            assert_eq!(res, Err(Error::RegistroYaActivado));
+        }
+
+        #[ink::test]
+        fn test_registro_usuario() 
         {
+            let administrador: AccountId = AccountId::from([0x1; 32]);
+            let otro_usuario: AccountId = AccountId::from([0x2; 32]);
+
+            set_caller(administrador);
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]); 
-            let id_candidato_1 = AccountId::from([0x06; 32]);
-            let id_candidato_2 = AccountId::from([0x06; 32]);
-            let id_candidato_3 = AccountId::from([0x06; 32]);
-            let id_candidato_4 = AccountId::from([0x06; 32]);
 
-            let vector_votos_candidatos = vec![
-                (id_candidato_1, 13),
-                (id_candidato_4, 7),
-                (id_candidato_2, 3),
-                (id_candidato_3, 2)
-            ];
+            contrato.activar_registro_privado().unwrap();
 
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+            set_caller(otro_usuario);
 
-            // Simular una elección válida con usuarios pendientes
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 50, 
-                fecha_final: 100, 
-                resultados: Some(Resultados { 
-                    votos_totales: 30, 
-                    votos_realizados: 25,
-                    votos_candidatos: vector_votos_candidatos,
-                }),
-            });
+            let resultado = contrato.registrarse_privado("John".to_string(), "Doe".to_string(), "12345678".to_string());
+            assert_eq!(resultado, Ok(()));
+        }
 
-            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(705);
+        #[ink::test]
+        fn test_registro_valida_datos_y_dni_unico()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
 
-            let result_obtener = contrato.obtener_resultados_privado(1);
-            
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_ok(), "Error al obtener los resultados de la elección");
+            set_caller(accounts.bob);
+            let registrar = |contrato:&mut SistemaElecciones, nombre:&str, apellido:&str, dni:&str| {
+                contrato.registrarse_privado(nombre.to_string(), apellido.to_string(), dni.to_string())
+            };
+            assert_eq!(registrar(&mut contrato, "   ", "Doe", "12345678"), Err(Error::NombreInvalido));
+            assert_eq!(registrar(&mut contrato, "John", &"a".repeat(LARGO_MAXIMO_NOMBRE + 1), "12345678"), Err(Error::NombreInvalido));
+            // El largo se mide en bytes: cada "ñ" ocupa dos
+            assert_eq!(registrar(&mut contrato, "John", &"ñ".repeat(LARGO_MAXIMO_NOMBRE / 2 + 1), "12345678"), Err(Error::NombreInvalido));
+            assert_eq!(registrar(&mut contrato, "John", "Doe", "12.345.678"), Err(Error::DniInvalido));
+            assert_eq!(registrar(&mut contrato, "John", "Doe", "123456"), Err(Error::DniInvalido));
+            assert_eq!(registrar(&mut contrato, "John", "Doe", "123456789"), Err(Error::DniInvalido));
+            assert!(!contrato.usuarios_pendientes.contains(accounts.bob));
+
+            // Se guardan los datos sin los espacios sobrantes
+            assert_eq!(registrar(&mut contrato, " John ", "Doe", " 12345678 "), Ok(()));
+            let usuario = contrato.usuarios_pendientes.get(accounts.bob).unwrap();
+            assert_eq!((usuario.nombre.as_str(), usuario.dni.as_str()), ("John", "12345678"));
+
+            // El DNI no se puede repetir en otra cuenta, ni pendiente ni registrada
+            set_caller(accounts.charlie);
+            assert_eq!(registrar(&mut contrato, "Otro", "Doe", "12345678"), Err(Error::DniYaRegistrado));
+            assert_eq!(contrato.obtener_cuenta_por_dni_privado("12345678".to_string()), Err(Error::NoEsOperadorPadron));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_cuenta_por_dni_privado("12345678".to_string()), Ok(accounts.bob));
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(registrar(&mut contrato, "Otro", "Doe", "12345678"), Err(Error::DniYaRegistrado));
+
+            // Si se rechaza la solicitud, el DNI queda libre
+            assert_eq!(registrar(&mut contrato, "Charlie", "Doe", "7654321"), Ok(()));
+            set_caller(accounts.alice);
+            contrato.procesar_siguiente_usuario_pendiente_privado(false).unwrap();
+            assert_eq!(contrato.obtener_cuenta_por_dni_privado("7654321".to_string()), Err(Error::UsuarioInexistente));
+            set_caller(accounts.django);
+            assert_eq!(registrar(&mut contrato, "Charlie", "Doe", "7654321"), Ok(()));
         }
 
         #[ink::test]
-        fn test_obtener_resultados_privado_fallo_eleccion_inexistente() 
+        fn test_proteccion_dni()
         {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]);
-
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
-
-            // Simular una elección 
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 0, 
-                fecha_final: 100, 
-                resultados: None,
-            });
-
-            let result_obtener = contrato.obtener_resultados_privado(10);
+            contrato.activar_registro_privado().unwrap();
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_err(), "Se esperaba un error al obtener los resultados de la elección (no se encontró elección)");
+            set_caller(accounts.bob);
+            assert_eq!(contrato.activar_proteccion_dni_privado(), Err(Error::NoEsAdministrador));
+            assert_eq!(contrato.registrarse_con_compromiso_dni_privado("Bob".to_string(), "Doe".to_string(), Hash::from([0x01; 32])), Err(Error::ProteccionDniNoActivada));
+
+            set_caller(accounts.alice);
+            assert_eq!(contrato.activar_proteccion_dni_privado(), Ok(()));
+            assert_eq!(contrato.activar_proteccion_dni_privado(), Err(Error::ProteccionDniYaActivada));
+            assert!(contrato.proteccion_dni_activada());
+
+            // Cada usuario calcula el compromiso con su propia sal, así que el mismo DNI da compromisos distintos
+            let (sal_bob, sal_charlie, sal_padron) = ([0x0b; 32], [0x0c; 32], [0xaa; 32]);
+            let compromiso_bob = SistemaElecciones::calcular_compromiso_dni("12345678", sal_bob);
+            let compromiso_charlie = SistemaElecciones::calcular_compromiso_dni("12345678", sal_charlie);
+            assert_ne!(compromiso_bob, compromiso_charlie);
+            set_caller(accounts.bob);
+            assert_eq!(contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()), Err(Error::RequiereCompromisoDni));
+            assert_eq!(contrato.registrarse_con_compromiso_dni_privado("Bob".to_string(), "Doe".to_string(), compromiso_bob), Ok(()));
+            let usuario = contrato.usuarios_pendientes.get(accounts.bob).unwrap();
+            assert_eq!((usuario.dni.as_str(), usuario.compromiso_dni, usuario.huella_dni), ("", Some(compromiso_bob), None));
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.registrarse_con_compromiso_dni_privado("Charlie".to_string(), "Doe".to_string(), compromiso_charlie), Ok(()));
+
+            // El padrón verifica el DNI y la sal que muestra el usuario contra su compromiso
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, "12345678".to_string(), Some(sal_bob)), Err(Error::NoEsOperadorPadron));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, "12345678".to_string(), Some(sal_bob)), Ok(true));
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, "12345678".to_string(), Some(sal_charlie)), Ok(false));
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, "87654321".to_string(), Some(sal_bob)), Ok(false));
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, "12345678".to_string(), None), Ok(false));
+            assert_eq!(contrato.verificar_dni_privado(accounts.django, "12345678".to_string(), Some(sal_bob)), Err(Error::UsuarioInexistente));
+
+            // Nadie se acepta sin que el padrón confirme su DNI
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Err(Error::DniNoConfirmado));
+            assert_eq!(contrato.procesar_usuarios_pendientes_privado(vec![accounts.charlie], true), Ok(vec![(accounts.charlie, Err(Error::DniNoConfirmado))]));
+            assert!(contrato.usuarios_pendientes.contains(accounts.bob));
+
+            // La huella usa la sal del padrón, así que detecta el mismo DNI en otra cuenta
+            let huella = SistemaElecciones::calcular_compromiso_dni("12345678", sal_padron);
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_dni_privado(accounts.bob, huella), Err(Error::NoEsOperadorPadron));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.confirmar_dni_privado(accounts.django, huella), Err(Error::UsuarioNoPendiente));
+            assert_eq!(contrato.confirmar_dni_privado(accounts.bob, huella), Ok(()));
+            assert_eq!(contrato.confirmar_dni_privado(accounts.bob, huella), Ok(()));
+            assert_eq!(contrato.confirmar_dni_privado(accounts.charlie, huella), Err(Error::DniYaRegistrado));
+            assert_eq!(contrato.obtener_cuenta_por_huella_dni(huella), Ok(accounts.bob));
+            assert_eq!(contrato.obtener_cuenta_por_dni_privado("12345678".to_string()), Err(Error::UsuarioInexistente));
+
+            // Las consultas muestran un identificador derivado del compromiso en lugar del DNI
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Ok(()));
+            let (_, _, identificador) = contrato.obtener_informacion_usuario_privado(accounts.bob).unwrap();
+            assert!(identificador.starts_with('#'));
+            assert_eq!(identificador.len(), 9);
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, "12345678".to_string(), Some(sal_bob)), Ok(true));
+
+            // Al rechazar una solicitud su huella queda libre
+            let otra_huella = SistemaElecciones::calcular_compromiso_dni("87654321", sal_padron);
+            assert_eq!(contrato.confirmar_dni_privado(accounts.charlie, otra_huella), Ok(()));
+            assert_eq!(contrato.procesar_usuarios_pendientes_privado(vec![accounts.charlie], false), Ok(vec![(accounts.charlie, Ok(()))]));
+            assert_eq!(contrato.obtener_cuenta_por_huella_dni(otra_huella), Err(Error::UsuarioInexistente));
         }
 
         #[ink::test]
-        fn test_obtener_resultados_privado_fallo_no_finalizo() 
+        fn test_proteccion_dni_requiere_padron_vacio()
         {
-            // Configuración inicial del contrato y del contexto de prueba
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]); // Ejemplo de ID de administrador
-
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+            contrato.activar_registro_privado().unwrap();
+            set_caller(accounts.bob);
+            contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()).unwrap();
+
+            set_caller(accounts.alice);
+            assert_eq!(contrato.activar_proteccion_dni_privado(), Err(Error::ProteccionDniNoDisponible));
+            assert!(!contrato.proteccion_dni_activada());
+            assert_eq!(contrato.confirmar_dni_privado(accounts.bob, Hash::from([0x01; 32])), Err(Error::ProteccionDniNoActivada));
+            // Sin protección, verificar_dni compara contra el DNI guardado y el usuario se acepta sin confirmación
+            assert_eq!(contrato.verificar_dni_privado(accounts.bob, " 12345678".to_string(), None), Ok(true));
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Ok(()));
+        }
 
-            // Simular una elección válida sin usuarios pendientes
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 50, // Ajustar según la lógica de tu contrato
-                fecha_final: 100, // Ajustar según la lógica de tu contrato
-                resultados: None,
-            });
-            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(75);
+        #[ink::test]
+        fn test_participantes_por_eleccion() 
+        {
+            let mut contrato = SistemaElecciones::new();
+            let candidato1 = AccountId::from([0x01; 32]);
+            let candidato2 = AccountId::from([0x02; 32]);
+            let votante1 = AccountId::from([0x03; 32]);
+            let votante2 = AccountId::from([0x04; 32]);
+
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 0, 0));
+            insertar_eleccion(&mut contrato, Eleccion::nueva(2, 0, 0));
+            agregar_participante(&mut contrato, 1, candidato1, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_participante(&mut contrato, 1, votante1, TIPO_DE_USUARIO::VOTANTE);
+            agregar_participante(&mut contrato, 2, candidato2, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_participante(&mut contrato, 2, votante2, TIPO_DE_USUARIO::VOTANTE);
+
+            // Cada elección guarda sus propios candidatos y votantes, con candidato_id empezando en 1
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(contrato.obtener_candidatos(&eleccion), vec![CandidatoConteo { id: candidato1, candidato_id: 1, votos_totales: 0, retirado: false }]);
+            assert_eq!(contrato.obtener_votantes(&eleccion), vec![Votante { id: votante1, voto_emitido: false, compromiso: None, voto_revelado: false, delegado: None, peso: 1, indice: 0 }]);
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_resultados_privado(1);
+            let eleccion = contrato.obtener_eleccion_por_id(2).unwrap();
+            assert_eq!(contrato.obtener_candidatos(&eleccion), vec![CandidatoConteo { id: candidato2, candidato_id: 1, votos_totales: 0, retirado: false }]);
+            assert_eq!(contrato.obtener_votantes(&eleccion), vec![Votante { id: votante2, voto_emitido: false, compromiso: None, voto_revelado: false, delegado: None, peso: 1, indice: 0 }]);
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_err(), "Se esperaba un error al obtener el siguiente usuario pendiente en la elección (la eleccion no finalizo)");
+            assert!(contrato.obtener_eleccion_por_id(3).is_none());
         }
 
         #[ink::test]
-        fn test_obtener_informacion_usuario_privado()
+        fn test_validar_estado_eleccion() 
         {
-            let generador_reportes = AccountId::from([0; 32]);
-            let administrador = AccountId::from([1; 32]);
-            let user_id = AccountId::from([2; 32]);
-            let random_user = AccountId::from([12; 32]);
-            set_caller(administrador);
             let mut contrato = SistemaElecciones::new();
-            assert!(contrato.asignar_generador_reportes(generador_reportes).is_ok());
-
-            set_caller(generador_reportes);
-            //Usuario no existente
-            let resultado = contrato.obtener_informacion_usuario_privado(user_id);
-            assert!(resultado.is_none());
-            
-            let user = Usuario { id: user_id, nombre: "Joaquin".to_string(), apellido: "Fontana".to_string(), dni: "22222".to_string() };
-            let nombre = user.nombre.clone();
-            let apellido = user.apellido.clone();
-            let dni = user.dni.clone();
-            contrato.usuarios.push(user);
-            
-            //Intentar llamar sin ser el generador reporte o admin
-            set_caller(random_user);
-            assert!(contrato.obtener_informacion_usuario_privado(user_id).is_none());
-            
-            set_caller(administrador);
-            assert!(contrato.obtener_informacion_usuario_privado(user_id).is_some_and(|tupla| tupla.0 == nombre && tupla.1 == apellido && tupla.2 == dni));
+            let usuario_id = AccountId::from([0x01; 32]);
             
-            set_caller(generador_reportes);
-            assert!(contrato.obtener_informacion_usuario_privado(user_id).is_some_and(|tupla| tupla.0 == nombre && tupla.1 == apellido && tupla.2 == dni));
-        }
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 100, 200));
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, usuario_id, TIPO_DE_USUARIO::VOTANTE);
 
-        #[test]
-        fn test_obtener_votantes_eleccion_por_id_privado() 
-        {
-            let administrador: AccountId = AccountId::from([0x1; 32]);
-            set_caller(administrador);
+            insertar_eleccion(&mut contrato, Eleccion {
+                estado: EstadoEleccion::Votacion,
+                ..Eleccion::nueva(2, 100, 200)
+            });
 
-            let mut contrato = SistemaElecciones::new();
-            set_block_timestamp::<ink::env::DefaultEnvironment>(1675666400000);
+            insertar_eleccion(&mut contrato, Eleccion::nueva(3, 100, 50));
 
-            set_contract(administrador);
-            
-            let _ = contrato.activar_registro();
-            let _ = contrato.crear_eleccion_privado(
-                "01-01-2023 12:00".to_string(),
-                "31-01-2025 12:00".to_string()
-            );
-            let _ = contrato.crear_eleccion_privado(
-                "01-01-2023 12:00".to_string(),
-                "31-01-2023 12:00".to_string()
-            );
+            insertar_eleccion(&mut contrato, Eleccion::nueva(4, 150, 200));
 
-            assert!(contrato.obtener_votantes_eleccion_por_id_privado(1).is_err()); // No se obtienen resultados porque no es el generador de reportes
-            let _ = contrato.asignar_generador_reportes_privado(administrador);
-            assert!(contrato.obtener_votantes_eleccion_por_id_privado(1).is_err()); // No se obtienen resultados porque la elección no finalizó
-            
-            let err = contrato.obtener_votantes_eleccion_por_id_privado(2); // Se obtienen resultados porque es el generador de reportes y finalizó la elección
-            assert!(err.is_ok());
+            // Caso 1: Usuario ya registrado
+            let resultado = contrato.validar_estado_eleccion(1, 50, usuario_id);
+            assert_eq!(resultado, Err(Error::YaInscriptoEnEleccion));
 
-            let err = contrato.obtener_votantes_eleccion_por_id_privado(3); // No se obtienen resultados porque no existe la elección
-            assert!(err.is_err());
+            // Caso 2: Votación ya comenzó
+            let resultado = contrato.validar_estado_eleccion(2, 50, usuario_id);
+            assert_eq!(resultado, Err(Error::InscripcionCerrada));
+
+            // Caso 3: Elección ya finalizó
+            let resultado = contrato.validar_estado_eleccion(3, 52, usuario_id);
+            assert_eq!(resultado, Err(Error::EleccionFinalizada));
+
+            // Caso 4: Elección válida y no iniciada
+            let resultado = contrato.validar_estado_eleccion(4, 50, usuario_id);
+            assert!(resultado.is_ok());
+            let eleccion = resultado.unwrap();
+            assert_eq!(eleccion.id, 4);
         }
 
-        #[test]
-        fn test_contiene_usuario_pendiente() 
+        #[ink::test]
+        fn test_crear_eleccion() 
         {
-            let mut eleccion = setup_eleccion();
-            let accounts: DefaultAccounts<DefaultEnvironment> = get_default_test_accounts(); // Cuentas predeterminadas utilizadas para tests
-            
-            eleccion.usuarios_pendientes.push((accounts.alice, TIPO_DE_USUARIO::VOTANTE));
-            
-            assert!(eleccion.contiene_usuario_pendiente(accounts.alice));
-            assert!(!eleccion.contiene_usuario_pendiente(accounts.bob));
-        }
+            // Instanciar el contrato
+            let mut contrato = SistemaElecciones::new();
     
-        #[test]
-        fn test_existe_candidato() 
-        {
-            let mut eleccion = setup_eleccion();
-            let accounts = get_default_test_accounts();
-            
-            eleccion.candidatos.push(CandidatoConteo {
-                id: accounts.alice,
-                candidato_id: 1,
-                votos_totales: 0,
-            });
+            // Configurar el administrador
+            let administrador = AccountId::from([0x1; 32]);
+            cambiar_administrador(&mut contrato, administrador);
     
-            assert!(eleccion.existe_candidato(1));
-            assert!(!eleccion.existe_candidato(2));
-        }
+            // Crear una elección válida
+            let resultado = contrato.crear_eleccion_privado(
+                "01-01-2025 12:00".to_string(),
+                "31-01-2025 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
     
-        #[test]
-        fn test_votar_candidato() 
-        {
-            let mut eleccion = setup_eleccion();
-            let accounts = get_default_test_accounts();
+            // Verificar que la elección se creó correctamente
+            assert_eq!(resultado, Ok(1));
     
-            eleccion.candidatos.push(CandidatoConteo {
-                id: accounts.alice,
-                candidato_id: 1,
-                votos_totales: 0,
-            });
+            // Verificar que la elección se añadió a la lista
+            assert_eq!(contrato.cantidad_elecciones, 1);
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(eleccion.id, 1);
+            assert_eq!(eleccion.fecha_inicio, 1735732800000); 
+            assert_eq!(eleccion.fecha_final, 1738324800000);
     
-            eleccion.votantes.push(Votante {
-                id: accounts.bob,
-                voto_emitido: false,
-            });
+            // Crear una elección con fecha inicial inválida
+            let resultado = contrato.crear_eleccion_privado(
+                "01-01-2025 12:00".to_string(),
+                "invalid-date".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            assert_eq!(resultado, Err(Error::FechaFinalInvalida));
     
-            let result = eleccion.votar_candidato(accounts.bob, 1);
-            assert_eq!(result, Ok(String::from("Voto emitido exitosamente.")));
-            assert!(eleccion.votantes[0].voto_emitido);
-            assert_eq!(eleccion.candidatos[0].votos_totales, 1);
-        }
+            // Crear una elección con fecha final inválida
+            let resultado = contrato.crear_eleccion_privado(
+                "invalid-date".to_string(),
+                "31-01-2025 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            assert_eq!(resultado, Err(Error::FechaInicialInvalida));
     
-        #[test]
-        fn test_procesar_siguiente_usuario_pendiente() 
+            // Crear una elección sin ser administrador
+            cambiar_administrador(&mut contrato, AccountId::from([0x2; 32]));
+            let resultado = contrato.crear_eleccion_privado(
+                "01-01-2025 12:00".to_string(),
+                "31-01-2025 12:00".to_string(),
+                ConfiguracionEleccion::default()
+            );
+            assert_eq!(resultado, Err(Error::NoEsAdministrador));
+        }
+
+        #[ink::test]
+        fn test_procesar_usuarios_en_una_eleccion() 
         {
-            let mut eleccion = setup_eleccion();
-            let accounts = get_default_test_accounts();
+            let mut contrato = SistemaElecciones::new();
+            let accounts = default_accounts::<DefaultEnvironment>();
+            
+            contrato.crear_eleccion_privado(
+                String::from("01-07-2024 12:00"),
+                String::from("02-07-2024 12:00"), ConfiguracionEleccion::default()
+            ).unwrap();
+
+            
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.alice, TIPO_DE_USUARIO::VOTANTE);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
+
     
-            eleccion.usuarios_pendientes.push((accounts.alice, TIPO_DE_USUARIO::VOTANTE));
-            eleccion.usuarios_pendientes.push((accounts.bob, TIPO_DE_USUARIO::CANDIDATO));
+            // Caso 1: Procesar siguiente usuario aceptando
+            let result = contrato.procesar_usuarios_en_una_eleccion(1, true);
+            assert_eq!(result, Ok(()));
     
-            let result = eleccion.procesar_siguiente_usuario_pendiente(true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-            assert_eq!(eleccion.votantes.len(), 1);
-            assert_eq!(eleccion.candidatos.len(), 0);
+            // Caso 2: Procesar siguiente usuario aceptando como candidato
+            let result = contrato.procesar_usuarios_en_una_eleccion(1, true);
+            assert_eq!(result, Ok(()));
     
-            let result = eleccion.procesar_siguiente_usuario_pendiente(true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-            assert_eq!(eleccion.votantes.len(), 1);
-            assert_eq!(eleccion.candidatos.len(), 1);
+            // Caso 3: Procesar siguiente usuario rechazando
+            let result = contrato.procesar_usuarios_en_una_eleccion(1, false);
+            assert_eq!(result, Err(Error::NoHayUsuariosPendientes));
     
-            let result = eleccion.procesar_siguiente_usuario_pendiente(false);
-            assert_eq!(result, Err(String::from("No hay usuarios pendientes.")));
+            // Caso 4: Intentar procesar usuario en una elección no existente
+            let result = contrato.procesar_usuarios_en_una_eleccion(2, true);
+            assert_eq!(result, Err(Error::EleccionInexistente));
+        } 
+
+        #[ink::test]
+        fn test_ingresar_a_eleccion() 
+        {
+            let accounts = get_default_test_accounts();
+            let alice = accounts.alice;
+            let charlie = accounts.charlie;
+            let eleccion_id: u64 = 1;
+            let tipo_usuario: TIPO_DE_USUARIO = TIPO_DE_USUARIO::VOTANTE;
+            set_caller(alice);
+        
+            let mut contract = SistemaElecciones::new();
+        
+            // Establecemos el administrador como el llamante y activamos el registro
+            contract.activar_registro().unwrap();
+            contract.crear_eleccion("01-01-2024 10:00".into(), "02-01-2024 10:00".into(), ConfiguracionEleccion::default()).unwrap();
+        
+            // Usuario no registrado intenta ingresar a la elección
+            set_caller(charlie);
+            let result = contract.ingresar_a_eleccion_privado(eleccion_id, tipo_usuario.clone());
+            assert_eq!(result, Err(Error::UsuarioNoRegistrado), "Error: Usuario no registrado");
+        
+            // Registramos al usuario
+            let result = contract.registrarse("Juan".into(), "Perez".into(), "12345678".into());
+            assert_eq!(result, Ok(()));
+        
+            // Aceptamos al usuario pendiente
+            set_caller(alice);
+            let result = contract.procesar_siguiente_usuario_pendiente(true);
+            assert_eq!(result, Ok(()));
+        
+            // Usuario registrado intenta ingresar a la elección
+            set_caller(charlie);
+            let result = contract.ingresar_a_eleccion_privado(eleccion_id, tipo_usuario.clone());
+            assert_eq!(result, Ok(()));
+
+            // Usuario que ya está pendiente intenta volver a ingresar a la elección
+            let result = contract.ingresar_a_eleccion_privado(eleccion_id, TIPO_DE_USUARIO::CANDIDATO);
+            assert_eq!(result, Err(Error::YaInscriptoEnEleccion));
+
+            // Una vez aceptado tampoco puede volver a inscribirse
+            set_caller(alice);
+            contract.procesar_usuarios_en_una_eleccion(eleccion_id, true).unwrap();
+            set_caller(charlie);
+            let result = contract.ingresar_a_eleccion_privado(eleccion_id, TIPO_DE_USUARIO::CANDIDATO);
+            assert_eq!(result, Err(Error::YaInscriptoEnEleccion));
+        }
+
+        #[ink::test]
+        fn test_iniciar_votacion_privado() 
+        {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+
+            // Crear el contrato con el administrador
+            let mut contrato = SistemaElecciones::new();
+
+            // Añadir una elección de prueba
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 50, 150));
+
+            // Caso 1: No es administrador
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contrato.iniciar_votacion_privado(1),
+                Err(Error::NoEsAdministrador)
+            );
+
+            // Caso 2: Elección no encontrada
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice); // Restaurar administrador
+            assert_eq!(
+                contrato.iniciar_votacion_privado(2),
+                Err(Error::EleccionInexistente)
+            );
+
+            // Caso 3: Votación ya finalizó
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(200);
+            assert_eq!(
+                contrato.iniciar_votacion_privado(1),
+                Err(Error::VotacionFinalizada)
+            );
+
+            // Caso 4: Votación ya inició
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100); // Restaurar el timestamp del bloque
+            cambiar_estado(&mut contrato, 1, EstadoEleccion::Votacion);
+            assert_eq!(
+                contrato.iniciar_votacion_privado(1),
+                Err(Error::VotacionYaIniciada)
+            );
+
+            // Caso 5: Todavía no es la fecha para la votación
+            cambiar_estado(&mut contrato, 1, EstadoEleccion::Inscripcion);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30); // Cambiar el timestamp del bloque
+            assert_eq!(
+                contrato.iniciar_votacion_privado(1),
+                Err(Error::VotacionNoIniciada)
+            );
+
+            // Caso 6: Se inició la votación exitosamente
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100); // Restaurar el timestamp del bloque
+            assert_eq!(
+                contrato.iniciar_votacion_privado(1),
+                Ok(())
+            );
+            assert_eq!(contrato.obtener_eleccion_por_id(1).unwrap().estado, EstadoEleccion::Votacion);
         }
-    
-        #[test]
-        fn test_obtener_informacion_candidato() 
+
+        #[ink::test]
+        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_exito_votante() 
         {
-            let administrador: AccountId = AccountId::from([0x1; 32]);
-            let bob: AccountId = AccountId::from([0x2; 32]);
-            set_caller(administrador);
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]); 
+            let id_usuario = AccountId::from([2; 32]);
+
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
+
+            // Simular una elección válida con usuarios pendientes
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 0, 100));
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, id_usuario, TIPO_DE_USUARIO::VOTANTE);
 
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
+
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+
+            // Verificar el resultado esperado
+            assert_eq!(result_obtener, Ok(SolicitudPendiente { usuario: id_usuario, tipo: TIPO_DE_USUARIO::VOTANTE }));
+        }
+
+        #[ink::test]
+        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_exito_candidato() 
+        {
             let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]); 
+            let id_usuario = AccountId::from([2; 32]);
 
-            let _ = contrato.activar_registro();
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
 
-            let _ = contrato.crear_eleccion(
-                String::from("01-07-2024 12:00"),
-                String::from("31-12-2024 12:00"),
-            );
+            // Simular una elección válida con usuarios pendientes
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 0, 100));
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, id_usuario, TIPO_DE_USUARIO::CANDIDATO);
 
-            set_caller(bob);
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
 
-            let _ = contrato.registrarse(String::from("Bob"), String::from("Bob"), String::from("Bob"));
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
 
-            set_caller(administrador);
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
 
-            let _ = contrato.procesar_siguiente_usuario_pendiente(true);
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
+        }
 
-            set_caller(bob);
 
-            let _ = contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO);
+        #[ink::test]
+        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_fallo_eleccion_inexistente()
+        {
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]);
+            let id_usuario = AccountId::from([2; 32]);
 
-            set_caller(administrador);
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
 
-            let _ = contrato.procesar_usuarios_en_una_eleccion_privado(1, true);
+            // Simular una elección válida con usuarios pendientes
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 0, 100));
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, id_usuario, TIPO_DE_USUARIO::VOTANTE);
 
-            set_caller(bob);
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(10);
 
-            // El código de arriba simúla la activación del registro, creación de una elección, registro de una persona, 
-            // aceptación del registro de una persona, registro de una persona a una elección y aceptación de ese registro en la elección
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_err(), "Se esperaba un error al obtener el siguiente usuario pendiente en la elección (no se encontró elección)");
+        }
 
-            let res = contrato.obtener_informacion_candidato_eleccion(1, 1);
-            assert!(res.is_ok()); // Se obtiene la información del candidato porque existe la elección y existe el candidato
+        #[ink::test]
+        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_fallo_sin_usuarios_pendientes() 
+        {
+            // Configuración inicial del contrato y del contexto de prueba
+            let mut contrato = SistemaElecciones::new();
+            let id_administrador = AccountId::from([1; 32]); // Ejemplo de ID de administrador
 
-            let res = contrato.obtener_informacion_candidato_eleccion(2, 1);
-            assert!(res.is_err()); // No se obtiene la información porque la elección no existe
+            // Simular un administrador registrado
+            cambiar_administrador(&mut contrato, id_administrador);
 
-            let res = contrato.obtener_informacion_candidato_eleccion(1, 2);
-            assert!(res.is_err()); // No se obtiene la información porque el candidato no existe
+            // Simular una elección válida sin usuarios pendientes
+            insertar_eleccion(&mut contrato, Eleccion::nueva(1, 0, 100));
+
+            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
+            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+
+            // Verificar el resultado esperado
+            assert!(result_obtener.is_err(), "Se esperaba un error al obtener el siguiente usuario pendiente en la elección (ningún usuario pendiente)");
         }
+        
 
-        #[test]
-        fn test_procesar_siguiente_usuario_pendiente_eleccion() 
+        // ====================== INICIO TESTS VOTO SECRETO ======================
+        // ====================== INICIO TESTS VOTO SECRETO ======================
+        // ====================== INICIO TESTS VOTO SECRETO ======================
+
+        fn configuracion_voto_secreto(fin_revelacion: &str) -> ConfiguracionEleccion
         {
-            let mut eleccion = setup_eleccion();
-            let accounts = default_accounts::<DefaultEnvironment>();
-    
-            eleccion.usuarios_pendientes.push((accounts.alice, TIPO_DE_USUARIO::VOTANTE));
-            eleccion.usuarios_pendientes.push((accounts.bob, TIPO_DE_USUARIO::CANDIDATO));
-    
-            let result = eleccion.procesar_siguiente_usuario_pendiente(true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-            assert_eq!(eleccion.votantes.len(), 1);
-            assert_eq!(eleccion.candidatos.len(), 0);
-    
-            let result = eleccion.procesar_siguiente_usuario_pendiente(true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-            assert_eq!(eleccion.votantes.len(), 1);
-            assert_eq!(eleccion.candidatos.len(), 1);
-    
-            let result = eleccion.procesar_siguiente_usuario_pendiente(false);
-            assert_eq!(result, Err(String::from("No hay usuarios pendientes.")));
+            ConfiguracionEleccion { fin_revelacion: Some(fin_revelacion.to_string()), ..Default::default() }
         }
 
-        #[test]
-        fn test_votar_a_candidato_privado() 
+        // Crea una elección de voto secreto del 01-07-2024 al 31-12-2024 con revelación hasta el 07-01-2025,
+        // con Bob como candidato y Charlie, Django y Eve como votantes
+        fn setup_contrato_voto_secreto() -> SistemaElecciones
         {
             let accounts = get_default_test_accounts();
-            let alice = accounts.alice;
-            let charlie = accounts.charlie;
-            let bob = accounts.bob;
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.crear_eleccion_privado(
+                "01-07-2024 12:00".to_string(),
+                "31-12-2024 12:00".to_string(),
+                configuracion_voto_secreto("07-01-2025 12:00")
+            ).unwrap();
+            for (cuenta, tipo) in [(accounts.bob, TIPO_DE_USUARIO::CANDIDATO), (accounts.charlie, TIPO_DE_USUARIO::VOTANTE), (accounts.django, TIPO_DE_USUARIO::VOTANTE), (accounts.eve, TIPO_DE_USUARIO::VOTANTE)] {
+                contrato.usuarios.insert(cuenta, &crear_usuario(cuenta, "Nombre", "Apellido", "12345678"));
+                agregar_participante(&mut contrato, 1, cuenta, tipo);
+            }
+            contrato
+        }
 
-            set_caller(alice);
+        #[ink::test]
+        fn test_crear_eleccion_voto_secreto()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let nueva_eleccion = contrato.crear_eleccion_privado(
-                String::from("01-07-2024 12:00"),
-                String::from("31-12-2024 12:00"),
-            );
-            assert_eq!(nueva_eleccion, Ok(format!("Eleccion creada exitosamente. Id de la elección: 1")) );
-            contrato.activar_registro_privado();
 
-            // Usuarios se registran
-            set_caller(bob);
-            let result = contrato.registrarse_privado("Bob".to_string(), "Asd".to_string(), "12345678".to_string() );
-            assert_eq!(result, Ok("Registro exitoso. Se te añadió en la cola de usuarios pendientes.".to_string()) );
-            set_caller(charlie);
-            contrato.registrarse_privado("Charlie".to_string(), "Dsa".to_string(), "87654321".to_string() );
-            assert_eq!(result, Ok("Registro exitoso. Se te añadió en la cola de usuarios pendientes.".to_string()) );
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_voto_secreto("invalid-date"));
+            assert_eq!(resultado, Err(Error::FechaRevelacionInvalida));
 
-            // Alice aprueba registro en sistema
-            set_caller(alice);
-            contrato.procesar_siguiente_usuario_pendiente_privado(true);
-            contrato.procesar_siguiente_usuario_pendiente_privado(true);
-        
-            // Usuarios se registran en eleccion
-            set_caller(bob);
-            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO);
-            set_caller(charlie);
-            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE);
+            // El fin de la revelación debe ser posterior a la fecha final
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_voto_secreto("31-12-2024 12:00"));
+            assert_eq!(resultado, Err(Error::FechaRevelacionInvalida));
 
-            set_caller(alice);
-            let result = contrato.procesar_usuarios_en_una_eleccion_privado(1, true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-            let result = contrato.procesar_usuarios_en_una_eleccion_privado(1, true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_voto_secreto("07-01-2025 12:00"));
+            assert_eq!(resultado, Ok(1));
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert!(eleccion.es_voto_secreto());
+            assert_eq!(eleccion.fecha_fin_revelacion, Some(1736251200000));
 
-            // Vota en eleccion usuario no registrado
-            set_caller(alice);
-            let result = contrato.votar_a_candidato_privado(1, 1);
-            assert_eq!(result, Err(String::from("No estás registrado en el sistema. Espera a que te acepten en el mismo o realiza la solicitud.")) );
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default());
+            assert_eq!(resultado, Ok(2));
+            assert!(!contrato.obtener_eleccion_por_id(2).unwrap().es_voto_secreto());
+        }
 
-            set_caller(charlie);
-            // Vota en eleccion que todavia no inicio
-            let result = contrato.votar_a_candidato_privado(1, 1);
-            assert_eq!(result, Err(String::from("Todavía no es la fecha para la votación.")) );
+        #[ink::test]
+        fn test_comprometer_voto()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+            let compromiso = SistemaElecciones::calcular_compromiso(1, accounts.charlie, &Voto::Candidato(1), [7; 32]);
+
+            set_caller(accounts.charlie);
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            assert_eq!(contrato.comprometer_voto_privado(1, compromiso), Err(Error::VotacionNoIniciada));
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            // En una elección de voto secreto no se puede votar en claro
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Err(Error::EleccionConVotoSecreto));
+            assert_eq!(contrato.comprometer_voto_privado(1, compromiso), Ok(()));
+            assert_eq!(contrato.comprometer_voto_privado(1, compromiso), Err(Error::VotoYaEmitido));
+
+            // El compromiso no se suma a ningún candidato hasta que se revele
+            let votante = contrato.votantes.get((1, accounts.charlie)).unwrap();
+            assert!(votante.voto_emitido);
+            assert_eq!(votante.compromiso, Some(compromiso));
+            assert_eq!(contrato.candidatos.get((1, 1)).unwrap().votos_totales, 0);
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.comprometer_voto_privado(1, compromiso), Err(Error::NoRegistradoEnEleccion));
+
+            // En una elección de voto público no se pueden enviar compromisos
+            set_caller(accounts.alice);
+            crear_eleccion_anterior(&mut contrato, "01-07-2024 12:00", "31-12-2024 12:00", ConfiguracionEleccion::default());
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.comprometer_voto_privado(2, compromiso), Err(Error::EleccionSinVotoSecreto));
+            assert_eq!(contrato.revelar_voto_privado(2, Voto::Candidato(1), [7; 32]), Err(Error::EleccionSinVotoSecreto));
+        }
 
-            contrato.elecciones[0].votacion_iniciada = true;
-            // Voto a candidato inexistente
-            let result = contrato.votar_a_candidato_privado(1, 2);
-            assert_eq!(result, Err(String::from("No existe un candidato con este id.")) );
+        #[ink::test]
+        fn test_revelar_voto_y_resultados()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(accounts.charlie);
+            let compromiso_charlie = SistemaElecciones::calcular_compromiso(1, accounts.charlie, &Voto::Candidato(1), [7; 32]);
+            contrato.comprometer_voto_privado(1, compromiso_charlie).unwrap();
+            // Django copia el compromiso de Charlie
+            set_caller(accounts.django);
+            contrato.comprometer_voto_privado(1, compromiso_charlie).unwrap();
+
+            // Todavía no terminó la votación
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Err(Error::RevelacionNoIniciada));
+
+            // Período de revelación
+            set_block_timestamp::<DefaultEnvironment>(1736000000000);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [8; 32]), Err(Error::CompromisoInvalido));
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Ok(()));
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Err(Error::VotoYaRevelado));
+            // Aunque Charlie ya publicó su voto y su sal, Django no puede revelar el compromiso copiado
+            set_caller(accounts.django);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Err(Error::CompromisoInvalido));
+            set_caller(accounts.eve);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Err(Error::VotoNoEmitido));
+
+            // Los resultados no están disponibles hasta que termine la revelación
+            assert_eq!(contrato.obtener_resultados_privado(1), Err(Error::EleccionNoFinalizada));
+
+            // Django no llegó a revelar su voto
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            set_caller(accounts.django);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [9; 32]), Err(Error::RevelacionFinalizada));
+
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados, Resultados {
+                votos_totales: 3,
+                votos_realizados: 2,
+                votos_delegados: 0,
+                votos_sin_revelar: 1,
+                votos_en_blanco: 0,
+                votos_nulos: 0,
+                votos_candidatos: vec![(accounts.bob, 1)],
+                rondas: vec![],
+                ganadores: vec![accounts.bob],
+                empatados_en_corte: vec![],
+            });
 
-            // Voto exitoso
-            let result = contrato.votar_a_candidato_privado(1, 1);
-            assert_eq!(result, Ok(String::from("Voto emitido exitosamente.")));
+            let eventos = eventos_emitidos();
+            assert!(matches!(eventos.last(), Some(Event::VotoRevelado(e)) if e.votante == accounts.charlie && e.eleccion_id == 1));
+        }
 
-            // Volver a votar en una eleccion que ya voto
-            let result = contrato.votar_a_candidato_privado(1, 1);
-            assert_eq!(result, Err("No se realizó el voto porque ya votaste anteriormente.".to_string() ));
+        // ====================== INICIO TESTS PREFERENCIA INSTANTANEA ======================
+        // ====================== INICIO TESTS PREFERENCIA INSTANTANEA ======================
+        // ====================== INICIO TESTS PREFERENCIA INSTANTANEA ======================
 
-            // Vota eleccion inexistente
-            let result = contrato.votar_a_candidato_privado(7, 1);
-            assert_eq!(result, Err(String::from("No existe una elección con ese id.")) );
-            
-            // Usuario no registrado como votante trata de votar
-            set_caller(bob);
-            let result = contrato.votar_a_candidato_privado(1, 1);
-            assert_eq!(result, Err(String::from("No estás registrado en la elección.")) );
+        // Configuración de una elección con el sistema de votación y las bancas indicados
+        fn configuracion_votacion(sistema_votacion: SistemaVotacion, bancas: u32) -> ConfiguracionEleccion
+        {
+            ConfiguracionEleccion { sistema_votacion, bancas, ..Default::default() }
         }
 
-        #[test]
-        fn test_obtener_informacion_siguiente_usuario_pendiente_privado() {
-            let accounts = get_default_test_accounts();
-            let alice = accounts.alice;
-            let charlie = accounts.charlie;
-            let bob = accounts.bob;
-
-            set_caller(alice);
+        // Crea una elección del 01-07-2024 al 31-12-2024 con la configuración, los candidatos y la cantidad de votantes indicados.
+        // Los votantes son las cuentas [100; 32], [101; 32], ...
+        fn setup_contrato_eleccion(configuracion: ConfiguracionEleccion, candidatos: &[AccountId], cantidad_votantes: u8) -> SistemaElecciones
+        {
+            set_caller(get_default_test_accounts().alice);
             let mut contrato = SistemaElecciones::new();
-            contrato.activar_registro_privado();
-            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
-            assert_eq!(result, Err(String::from("No hay usuarios pendientes.")) );
-            
-            set_caller(bob);
-            let result = contrato.registrarse_privado("Bob".to_string(), "Asd".to_string(), "12345678".to_string() );
-            assert_eq!(contrato.usuarios_pendientes.len(), 1);
-            
-            set_caller(alice);
-            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
-            let string_bob = "Nombre: Bob\nApellido: Asd\nDNI: 12345678".to_string();
-            assert_eq!(result, Ok(string_bob) );
+            contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion).unwrap();
+            for candidato in candidatos {
+                agregar_participante(&mut contrato, 1, *candidato, TIPO_DE_USUARIO::CANDIDATO);
+            }
+            for i in 0..cantidad_votantes {
+                let votante = AccountId::from([100 + i; 32]);
+                contrato.usuarios.insert(votante, &crear_usuario(votante, "Nombre", "Apellido", "12345678"));
+                agregar_participante(&mut contrato, 1, votante, TIPO_DE_USUARIO::VOTANTE);
+            }
+            contrato
+        }
 
-            set_caller(charlie);
-            let result = contrato.registrarse_privado("Charlie".to_string(), "Asd".to_string(), "12345678".to_string() );
-            assert_eq!(contrato.usuarios_pendientes.len(), 2);
+        // Cada votante emite, en orden, una de las boletas indicadas con el mensaje de votación `votar`,
+        // por ejemplo SistemaElecciones::votar_con_preferencias_privado o SistemaElecciones::votar_aprobando_privado
+        fn votar_boletas(contrato: &mut SistemaElecciones, boletas: &[(u8, Vec<u32>)], mut votar: impl FnMut(&mut SistemaElecciones, u64, Vec<u32>) -> Result<()>)
+        {
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            let mut votante = 100;
+            for (cantidad, boleta) in boletas {
+                for _ in 0..*cantidad {
+                    set_caller(AccountId::from([votante; 32]));
+                    votar(contrato, 1, boleta.clone()).unwrap();
+                    votante += 1;
+                }
+            }
+        }
 
-            set_caller(alice);
-            // Aprobamos a Bob para ver la informacion de Charlie
-            contrato.procesar_siguiente_usuario_pendiente_privado(true);
+        #[ink::test]
+        fn test_votar_con_preferencias()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::PreferenciaInstantanea, 1), &[accounts.bob, accounts.charlie, accounts.django], 1);
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(AccountId::from([100; 32]));
+
+            assert_eq!(contrato.votar_con_preferencias_privado(1, vec![]), Err(Error::PreferenciasInvalidas));
+            assert_eq!(contrato.votar_con_preferencias_privado(1, vec![1, 2, 1]), Err(Error::PreferenciasInvalidas));
+            assert_eq!(contrato.votar_con_preferencias_privado(1, vec![2, 4]), Err(Error::PreferenciasInvalidas));
+            // En una elección por preferencia instantánea no se vota a un único candidato
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Err(Error::TipoDeVotoInvalido));
+
+            // No hace falta ordenar a todos los candidatos
+            assert_eq!(contrato.votar_con_preferencias_privado(1, vec![3, 1]), Ok(()));
+            assert_eq!(contrato.votar_con_preferencias_privado(1, vec![1]), Err(Error::VotoYaEmitido));
+            assert_eq!(contrato.boletas.get((1, 0)), Some(Boleta { preferencias: vec![3, 1], peso: 1 }));
+
+            // En una elección por mayoría no se vota con preferencias
+            set_caller(accounts.alice);
+            crear_eleccion_anterior(&mut contrato, "01-07-2024 12:00", "31-12-2024 12:00", ConfiguracionEleccion::default());
+            agregar_participante(&mut contrato, 2, AccountId::from([100; 32]), TIPO_DE_USUARIO::VOTANTE);
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_con_preferencias_privado(2, vec![1]), Err(Error::TipoDeVotoInvalido));
+        }
 
-            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
-            let string_charlie = "Nombre: Charlie\nApellido: Asd\nDNI: 12345678".to_string();
-            assert_eq!(result, Ok(string_charlie) );
+        #[ink::test]
+        fn test_resultados_preferencia_instantanea()
+        {
+            let accounts = get_default_test_accounts();
+            let (bob, charlie, django) = (accounts.bob, accounts.charlie, accounts.django);
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::PreferenciaInstantanea, 1), &[bob, charlie, django], 12);
+            votar_boletas(&mut contrato, &[(5, vec![1]), (4, vec![2]), (2, vec![3, 2]), (1, vec![3])], SistemaElecciones::votar_con_preferencias_privado);
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            // Django queda último y sus boletas pasan a Charlie, salvo la que no tiene otra preferencia
+            assert_eq!(resultados.rondas, vec![
+                Ronda { votos_candidatos: vec![(bob, 5), (charlie, 4), (django, 3)], eliminados: vec![django], votos_agotados: 0 },
+                Ronda { votos_candidatos: vec![(bob, 5), (charlie, 6)], eliminados: vec![], votos_agotados: 1 },
+            ]);
+            assert_eq!(resultados.votos_candidatos, vec![(bob, 5), (charlie, 6), (django, 3)]);
+            assert_eq!(resultados.votos_realizados, 12);
+
+            // Los reportes de candidatos usan los votos de la última ronda de cada uno
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_candidatos_eleccion_por_id_privado(1), Ok(vec![(bob, 5), (charlie, 6), (django, 3)]));
+        }
 
-            // Aprobamos a todos los usuarios pendientes y volvemos a consultar
-            contrato.procesar_siguiente_usuario_pendiente_privado(true);
-            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
-            assert_eq!(result, Err(String::from("No hay usuarios pendientes.")) );
+        #[ink::test]
+        fn test_resultados_preferencia_instantanea_empate()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::PreferenciaInstantanea, 1), &[accounts.bob, accounts.charlie], 2);
+            votar_boletas(&mut contrato, &[(1, vec![1, 2]), (1, vec![2, 1])], SistemaElecciones::votar_con_preferencias_privado);
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            // Si todos los candidatos en competencia empatan no se elimina a ninguno
+            assert_eq!(resultados.rondas, vec![
+                Ronda { votos_candidatos: vec![(accounts.bob, 1), (accounts.charlie, 1)], eliminados: vec![], votos_agotados: 0 },
+            ]);
         }
 
-        // ====================== FIN TESTS ELECCION ======================
-        // ====================== FIN TESTS ELECCION ======================
-        // ====================== FIN TESTS ELECCION ======================
-    
-        // ====================== INICIO TESTS SISTEMA ELECCIONES ======================
-        // ====================== INICIO TESTS SISTEMA ELECCIONES ======================
-        // ====================== INICIO TESTS SISTEMA ELECCIONES ======================
-        
-        #[test]
-        fn test_constructor() 
+        #[ink::test]
+        fn test_voto_secreto_con_preferencias()
         {
             let accounts = get_default_test_accounts();
-            let alice = accounts.alice;
-            let charlie = accounts.charlie;
-            set_caller(alice);
-    
-            let sistema_elecciones = SistemaElecciones::new();
-            assert_eq!(sistema_elecciones.registro_activado, false);
-            assert_eq!(sistema_elecciones.administrador, alice);
-            assert_ne!(sistema_elecciones.administrador, charlie);
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.crear_eleccion_privado(
+                "01-07-2024 12:00".to_string(),
+                "31-12-2024 12:00".to_string(),
+                ConfiguracionEleccion {
+                    fin_revelacion: Some("07-01-2025 12:00".to_string()),
+                    sistema_votacion: SistemaVotacion::PreferenciaInstantanea,
+                    ..Default::default()
+                }
+            ).unwrap();
+            agregar_participante(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_participante(&mut contrato, 1, accounts.charlie, TIPO_DE_USUARIO::CANDIDATO);
+            contrato.usuarios.insert(accounts.django, &crear_usuario(accounts.django, "Django", "Apellido", "12345678"));
+            agregar_participante(&mut contrato, 1, accounts.django, TIPO_DE_USUARIO::VOTANTE);
+
+            set_caller(accounts.django);
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            let voto = Voto::Preferencias(vec![2, 1]);
+            contrato.comprometer_voto_privado(1, SistemaElecciones::calcular_compromiso(1, accounts.django, &voto, [3; 32])).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(1736000000000);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Preferencias(vec![1, 2]), [3; 32]), Err(Error::CompromisoInvalido));
+            assert_eq!(contrato.revelar_voto_privado(1, voto, [3; 32]), Ok(()));
+
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 0), (accounts.charlie, 1)]);
         }
 
+        // ====================== INICIO TESTS APROBACION ======================
+        // ====================== INICIO TESTS APROBACION ======================
+        // ====================== INICIO TESTS APROBACION ======================
+
         #[ink::test]
-        fn test_obtener_informacion_candidato_eleccion() 
+        fn test_crear_eleccion_bancas_invalidas()
         {
-            let mut eleccion = setup_eleccion();
-            let accounts = default_accounts::<DefaultEnvironment>();
-            eleccion.candidatos.push(CandidatoConteo {
-                id: accounts.alice,
-                candidato_id: 1,
-                votos_totales: 0,
-            });
-            eleccion.candidatos.push(CandidatoConteo {
-                id: accounts.bob,
-                candidato_id: 2,
-                votos_totales: 0,
-            });
+            set_caller(get_default_test_accounts().alice);
+            let mut contrato = SistemaElecciones::new();
+            let crear = |contrato: &mut SistemaElecciones, sistema_votacion: SistemaVotacion, bancas: u32| {
+                contrato.crear_eleccion_privado(
+                    "01-07-2024 12:00".to_string(),
+                    "31-12-2024 12:00".to_string(),
+                    ConfiguracionEleccion { sistema_votacion, bancas, ..Default::default() }
+                )
+            };
 
-            let candidato_info = eleccion.obtener_informacion_candidato(1);
-            assert!(candidato_info.is_some());
-            assert_eq!(candidato_info.unwrap().id, accounts.alice);
+            assert_eq!(crear(&mut contrato, SistemaVotacion::Mayoria, 0), Err(Error::BancasInvalidas));
+            assert_eq!(crear(&mut contrato, SistemaVotacion::Limitado(0), 3), Err(Error::BancasInvalidas));
+            // La preferencia instantánea elige una sola banca
+            assert_eq!(crear(&mut contrato, SistemaVotacion::PreferenciaInstantanea, 2), Err(Error::BancasInvalidas));
 
-            let candidato_info = eleccion.obtener_informacion_candidato(2);
-            assert!(candidato_info.is_some());
-            assert_eq!(candidato_info.unwrap().id, accounts.bob);
-    
-            let candidato_info = eleccion.obtener_informacion_candidato(3);
-            assert!(candidato_info.is_none());
+            assert_eq!(crear(&mut contrato, SistemaVotacion::Aprobacion, 5), Ok(1));
+            assert_eq!(contrato.obtener_eleccion_por_id(1).unwrap().bancas, 5);
+            assert_eq!(crear(&mut contrato, SistemaVotacion::Limitado(2), 3), Ok(2));
         }
 
-        #[test]
-        fn test_obtener_informacion_siguiente_usuario_pendiente() 
+        #[ink::test]
+        fn test_votar_aprobando()
         {
-            let administrador: AccountId = AccountId::from([0x1; 32]);
-            let otro_usuario: AccountId = AccountId::from([0x2; 32]);
-            set_caller(administrador);
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Limitado(2), 2), &[accounts.bob, accounts.charlie, accounts.django], 1);
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(AccountId::from([100; 32]));
+
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![]), Err(Error::AprobacionInvalida));
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![1, 1]), Err(Error::AprobacionInvalida));
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![4]), Err(Error::AprobacionInvalida));
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![1, 2, 3]), Err(Error::DemasiadosCandidatosAprobados));
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Err(Error::TipoDeVotoInvalido));
+
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![3, 1]), Ok(()));
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![2]), Err(Error::VotoYaEmitido));
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(contrato.obtener_informacion_candidato(&eleccion, 1).unwrap().votos_totales, 1);
+            assert_eq!(contrato.obtener_informacion_candidato(&eleccion, 2).unwrap().votos_totales, 0);
+            assert_eq!(contrato.obtener_informacion_candidato(&eleccion, 3).unwrap().votos_totales, 1);
+
+            // En una elección por mayoría no se vota aprobando candidatos
+            set_caller(accounts.alice);
+            crear_eleccion_anterior(&mut contrato, "01-07-2024 12:00", "31-12-2024 12:00", ConfiguracionEleccion::default());
+            agregar_participante(&mut contrato, 2, AccountId::from([100; 32]), TIPO_DE_USUARIO::VOTANTE);
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_aprobando_privado(2, vec![1]), Err(Error::TipoDeVotoInvalido));
+        }
 
-            let mut contrato = SistemaElecciones::new();
-            
-            let usuario = Usuario { id: (otro_usuario), nombre: ("Joaquin".to_string()), apellido: ("Fontana".to_string()), dni: ("22222222".to_string()) };
-            let mut str = String::from("Nombre: ") + usuario.nombre.as_str();
-            str.push_str((String::from("\nApellido: ") + usuario.apellido.as_str()).as_str());
-            str.push_str((String::from("\nDNI: ") + usuario.dni.as_str()).as_str());
-            //Intentar obtener informacion sin usuarios pendientes
-            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
-            assert!(result.is_err());
-            
-            contrato.usuarios_pendientes.push(usuario);
+        #[ink::test]
+        fn test_resultados_aprobacion_varias_bancas()
+        {
+            let accounts = get_default_test_accounts();
+            let (bob, charlie, django, eve) = (accounts.bob, accounts.charlie, accounts.django, accounts.eve);
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Aprobacion, 2), &[bob, charlie, django, eve], 6);
+            votar_boletas(&mut contrato, &[(3, vec![1, 2, 3]), (2, vec![3, 4]), (1, vec![4])], SistemaElecciones::votar_aprobando_privado);
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_candidatos, vec![(bob, 3), (charlie, 3), (django, 5), (eve, 3)]);
+            // Django ocupa la primera banca, pero Bob, Charlie y Eve empatan en la segunda
+            assert_eq!(resultados.ganadores, vec![django]);
+            assert_eq!(resultados.empatados_en_corte, vec![bob, charlie, eve]);
+        }
 
-            let result = contrato.obtener_informacion_siguiente_usuario_pendiente();
-            assert!(result.is_ok_and(|info| info == str));
+        #[ink::test]
+        fn test_resultados_voto_limitado_sin_empate()
+        {
+            let accounts = get_default_test_accounts();
+            let (bob, charlie, django, eve) = (accounts.bob, accounts.charlie, accounts.django, accounts.eve);
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Limitado(2), 3), &[bob, charlie, django, eve], 5);
+            votar_boletas(&mut contrato, &[(2, vec![1, 2]), (2, vec![2, 3]), (1, vec![3, 4])], SistemaElecciones::votar_aprobando_privado);
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            // Charlie (4) y Django (3) superan el corte, Bob (2) ocupa la tercera banca sin empatar con Eve (1)
+            assert_eq!(resultados.ganadores, vec![charlie, django, bob]);
+            assert!(resultados.empatados_en_corte.is_empty());
         }
 
         #[test]
-        fn test_transferir_administrador() 
+        fn test_calcular_ganadores()
+        {
+            let a = AccountId::from([0x01; 32]);
+            let b = AccountId::from([0x02; 32]);
+            let c = AccountId::from([0x03; 32]);
+
+            // Menos candidatos que bancas: todos son electos
+            assert_eq!(SistemaElecciones::calcular_ganadores(&[(a, 0), (b, 2)], 3), (vec![b, a], vec![]));
+            // El empate en el corte entra justo en las bancas
+            assert_eq!(SistemaElecciones::calcular_ganadores(&[(a, 1), (b, 4), (c, 4)], 2), (vec![b, c], vec![]));
+            // El empate en el corte no entra en las bancas restantes
+            assert_eq!(SistemaElecciones::calcular_ganadores(&[(a, 4), (b, 4), (c, 4)], 2), (vec![], vec![a, b, c]));
+        }
+
+        // ====================== INICIO TESTS VOTO EN BLANCO Y NULO ======================
+        // ====================== INICIO TESTS VOTO EN BLANCO Y NULO ======================
+        // ====================== INICIO TESTS VOTO EN BLANCO Y NULO ======================
+
+        #[ink::test]
+        fn test_votar_en_blanco_y_nulo()
         {
             let accounts = get_default_test_accounts();
-            let alice = accounts.alice;
-            let charlie = accounts.charlie;
-            let bob = accounts.bob;
-            set_caller(alice);
-    
-            let mut sistema_elecciones = SistemaElecciones::new();
-    
-            let result = sistema_elecciones.transferir_administrador_privado(charlie);
-            assert!(result.is_ok());
-            assert_ne!(sistema_elecciones.administrador, alice);
-            assert_eq!(sistema_elecciones.administrador, charlie);
-    
-            set_caller(bob);
-            let result = sistema_elecciones.transferir_administrador_privado(charlie);
-            assert!(result.is_err());
-            assert_ne!(sistema_elecciones.administrador, alice);
-            assert_eq!(sistema_elecciones.administrador, charlie);
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Mayoria, 1), &[accounts.bob, accounts.charlie], 4);
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_a_candidato_privado(1, VOTO_EN_BLANCO), Ok(()));
+            set_caller(AccountId::from([101; 32]));
+            assert_eq!(contrato.votar_a_candidato_privado(1, VOTO_NULO), Ok(()));
+            set_caller(AccountId::from([102; 32]));
+            assert_eq!(contrato.votar_a_candidato_privado(1, 3), Err(Error::CandidatoInexistente));
+            assert_eq!(contrato.votar_a_candidato_privado(1, 2), Ok(()));
+            // Un voto en blanco también cuenta como voto emitido
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Err(Error::VotoYaEmitido));
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_totales, 4);
+            assert_eq!(resultados.votos_realizados, 3);
+            assert_eq!(resultados.votos_en_blanco, 1);
+            assert_eq!(resultados.votos_nulos, 1);
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 0), (accounts.charlie, 1)]);
+            assert_eq!(resultados.ganadores, vec![accounts.charlie]);
         }
 
         #[ink::test]
-        fn test_obtener_candidatos_eleccion_por_id()
+        fn test_voto_en_blanco_en_preferencia_instantanea()
         {
-            let administrador: AccountId = AccountId::from([0x1; 32]);
-            let generador_reportes: AccountId = AccountId::from([0x2; 32]);
-            set_caller(administrador);
-            let mut contrato = SistemaElecciones::new();
-            assert!(contrato.asignar_generador_reportes(generador_reportes).is_ok());
-            assert!(contrato.generador_reportes.is_some_and(|id| id == generador_reportes));
-            let mut eleccion = setup_eleccion();
-            let eleccion_id = eleccion.id;
-            eleccion.fecha_final = contrato.env().block_timestamp();
-            contrato.elecciones.push(eleccion);
-            advance_block::<ink::env::DefaultEnvironment>();
-            set_caller(generador_reportes);
-            let resultado = contrato.obtener_candidatos_eleccion_por_id_privado(eleccion_id);
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::PreferenciaInstantanea, 1), &[accounts.bob, accounts.charlie], 2);
+            votar_boletas(&mut contrato, &[(1, vec![1, 2])], SistemaElecciones::votar_con_preferencias_privado);
+            set_caller(AccountId::from([101; 32]));
+            assert_eq!(contrato.votar_a_candidato_privado(1, VOTO_EN_BLANCO), Ok(()));
+
+            // El voto en blanco no se guarda como boleta, por lo que no se cuenta como agotado en las rondas
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_en_blanco, 1);
+            assert_eq!(resultados.rondas.len(), 1);
+            assert_eq!(resultados.rondas[0].votos_agotados, 0);
+            assert_eq!(resultados.ganadores, vec![accounts.bob]);
+        }
 
-            // Verificar el resultado y manejar el error
-            assert!(
-                resultado.is_ok(),
-                "Error al obtener candidatos para la elección: {:?}",
-                resultado.unwrap_err().to_string()
-            );
+        #[ink::test]
+        fn test_revelar_voto_nulo()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(accounts.charlie);
+            contrato.comprometer_voto_privado(1, SistemaElecciones::calcular_compromiso(1, accounts.charlie, &Voto::Nulo, [5; 32])).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(1735700000000);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::EnBlanco, [5; 32]), Err(Error::CompromisoInvalido));
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Nulo, [5; 32]), Ok(()));
+
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_nulos, 1);
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 0)]);
         }
-    
+
+        // ====================== INICIO TESTS DELEGACION ======================
+        // ====================== INICIO TESTS DELEGACION ======================
+        // ====================== INICIO TESTS DELEGACION ======================
+
+        // Peso y delegado del votante [indice; 32] en la elección 1
+        fn peso_y_delegado(contrato: &SistemaElecciones, indice: u8) -> (u32, Option<AccountId>)
+        {
+            let votante = contrato.votantes.get((1, AccountId::from([indice; 32]))).unwrap();
+            (votante.peso, votante.delegado)
+        }
+
         #[ink::test]
-        fn test_obtener_usuario() 
+        fn test_delegar_voto()
         {
             let accounts = get_default_test_accounts();
-            let id: AccountId = accounts.alice;
-            let mut trabajo_final = SistemaElecciones::new();
-            let usuario = crear_usuario(id, "Juan", "Perez", "12345678");
-            trabajo_final.usuarios.push(usuario);
-    
-            let result = trabajo_final.obtener_usuario(id);
-            assert!(result.is_some());
-            let user = result.unwrap();
-            assert_eq!(user.nombre, "Juan");
-            assert_eq!(user.apellido, "Perez");
-            assert_eq!(user.dni, "12345678");
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Mayoria, 1), &[accounts.bob], 4);
+            let (a, b, c) = (AccountId::from([100; 32]), AccountId::from([101; 32]), AccountId::from([102; 32]));
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+
+            set_caller(a);
+            assert_eq!(contrato.delegar_voto_privado(1, a), Err(Error::DelegacionCircular));
+            assert_eq!(contrato.delegar_voto_privado(1, accounts.bob), Err(Error::DelegadoNoEsVotante));
+            assert_eq!(contrato.delegar_voto_privado(2, b), Err(Error::EleccionInexistente));
+            assert_eq!(contrato.delegar_voto_privado(1, b), Ok(()));
+            assert_eq!(contrato.delegar_voto_privado(1, c), Err(Error::VotoYaDelegado));
+
+            // B no puede delegar en A porque A ya le delegó su voto
+            set_caller(b);
+            assert_eq!(contrato.delegar_voto_privado(1, a), Err(Error::DelegacionCircular));
+
+            // La delegación es transitiva: el voto de C pasa por A y llega a B
+            set_caller(c);
+            assert_eq!(contrato.delegar_voto_privado(1, a), Ok(()));
+            assert_eq!(peso_y_delegado(&contrato, 100), (2, Some(b)));
+            assert_eq!(peso_y_delegado(&contrato, 101), (3, None));
+            assert_eq!(peso_y_delegado(&contrato, 102), (1, Some(a)));
+
+            let eventos = eventos_emitidos();
+            assert!(matches!(eventos.last(), Some(Event::VotoDelegado(e)) if e.votante == c && e.delegado == a && e.eleccion_id == 1));
+
+            // Una vez iniciada la votación no se puede delegar, y quien delegó no puede votar
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(AccountId::from([103; 32]));
+            assert_eq!(contrato.delegar_voto_privado(1, b), Err(Error::VotacionYaIniciada));
+            set_caller(a);
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Err(Error::VotoYaDelegado));
         }
-    
+
         #[ink::test]
-        fn test_es_usuario_registrado() 
+        fn test_resultados_con_votos_delegados()
         {
             let accounts = get_default_test_accounts();
-            let id: AccountId = accounts.alice;
-            let mut trabajo_final = SistemaElecciones::new();
-            let usuario = crear_usuario(id, "Juan", "Perez", "12345678");
-            trabajo_final.usuarios.push(usuario);
-    
-            assert!(trabajo_final.es_usuario_registrado());
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Mayoria, 1), &[accounts.bob, accounts.charlie], 4);
+            let (a, b, c, d) = (AccountId::from([100; 32]), AccountId::from([101; 32]), AccountId::from([102; 32]), AccountId::from([103; 32]));
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(a);
+            contrato.delegar_voto_privado(1, b).unwrap();
+            set_caller(c);
+            contrato.delegar_voto_privado(1, a).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(b);
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Ok(()));
+            set_caller(d);
+            assert_eq!(contrato.votar_a_candidato_privado(1, 2), Ok(()));
+
+            // Una vez que el delegado votó, la delegación no se puede revocar
+            set_caller(c);
+            assert_eq!(contrato.revocar_delegacion_privado(1), Err(Error::DelegadoYaVoto));
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000); // Un minuto después de la fecha final
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_realizados, 2);
+            assert_eq!(resultados.votos_delegados, 2);
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 3), (accounts.charlie, 1)]);
+            assert_eq!(resultados.ganadores, vec![accounts.bob]);
         }
-    
+
         #[ink::test]
-        fn test_es_usuario_pendiente() 
+        fn test_revocar_delegacion()
         {
             let accounts = get_default_test_accounts();
-            let id: AccountId = accounts.alice;
-            let mut trabajo_final = SistemaElecciones::new();
-            let usuario = crear_usuario(id, "Juan", "Perez", "12345678");
-            trabajo_final.usuarios_pendientes.push(usuario);
-    
-            assert!(trabajo_final.es_usuario_pendiente());
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Mayoria, 1), &[accounts.bob], 4);
+            let (a, b, c) = (AccountId::from([100; 32]), AccountId::from([101; 32]), AccountId::from([102; 32]));
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(a);
+            contrato.delegar_voto_privado(1, b).unwrap();
+            set_caller(c);
+            contrato.delegar_voto_privado(1, a).unwrap();
+
+            set_caller(b);
+            assert_eq!(contrato.revocar_delegacion_privado(1), Err(Error::VotoNoDelegado));
+
+            // A revoca durante la votación: B deja de llevar los votos de A y C, que ahora lleva A
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(a);
+            assert_eq!(contrato.revocar_delegacion_privado(1), Ok(()));
+            assert_eq!(peso_y_delegado(&contrato, 100), (2, None));
+            assert_eq!(peso_y_delegado(&contrato, 101), (1, None));
+            let eventos = eventos_emitidos();
+            assert!(matches!(eventos.last(), Some(Event::DelegacionRevocada(e)) if e.votante == a && e.eleccion_id == 1));
+
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Ok(()));
+            set_caller(c);
+            assert_eq!(contrato.revocar_delegacion_privado(1), Err(Error::DelegadoYaVoto));
+
+            set_block_timestamp::<DefaultEnvironment>(1735700000000);
+            assert_eq!(contrato.revocar_delegacion_privado(1), Err(Error::VotacionFinalizada));
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 2)]);
         }
-    
-        #[test]
-        fn test_existe_eleccion() 
+
+        #[ink::test]
+        fn test_voto_delegado_en_voto_secreto()
         {
-            let id: AccountId = [0; 32].into();
-            let mut trabajo_final = crear_trabajo_final(id);
-            let eleccion = setup_eleccion();
-            trabajo_final.elecciones.push(eleccion);
-    
-            assert!(trabajo_final.existe_eleccion(1));
-            assert!(!trabajo_final.existe_eleccion(2));
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(accounts.django);
+            contrato.delegar_voto_privado(1, accounts.charlie).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(accounts.charlie);
+            contrato.comprometer_voto_privado(1, SistemaElecciones::calcular_compromiso(1, accounts.charlie, &Voto::Candidato(1), [7; 32])).unwrap();
+            set_caller(accounts.django);
+            assert_eq!(contrato.comprometer_voto_privado(1, SistemaElecciones::calcular_compromiso(1, accounts.django, &Voto::Candidato(1), [8; 32])), Err(Error::VotoYaDelegado));
+
+            // Mientras Charlie no revele, sus dos votos figuran sin revelar
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            let mut eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(contrato.obtener_resultados_votacion(&mut eleccion, 1736251200000).unwrap().votos_sin_revelar, 2);
+
+            set_block_timestamp::<DefaultEnvironment>(1735700000000);
+            set_caller(accounts.charlie);
+            contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]).unwrap();
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_sin_revelar, 0);
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 2)]);
         }
-    
-        #[test]
-        fn test_obtener_eleccion_por_id() 
+
+        // ====================== INICIO TESTS ROLES ======================
+        // ====================== INICIO TESTS ROLES ======================
+        // ====================== INICIO TESTS ROLES ======================
+
+        #[ink::test]
+        fn test_otorgar_y_revocar_roles()
         {
-            let id: AccountId = [0; 32].into();
-            let mut trabajo_final = crear_trabajo_final(id);
-            let eleccion = setup_eleccion();
-            trabajo_final.elecciones.push(eleccion);
-    
-            let result = trabajo_final.obtener_eleccion_por_id(1);
-            assert!(result.is_some());
-            let eleccion_obtenida = result.unwrap();
-            assert_eq!(eleccion_obtenida.id, 1);
-    
-            assert!(trabajo_final.obtener_eleccion_por_id(2).is_none());
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+
+            assert_eq!(contrato.otorgar_rol_privado(accounts.bob, Rol::Administrador, None), Ok(()));
+            assert_eq!(contrato.otorgar_rol_privado(accounts.bob, Rol::Administrador, None), Err(Error::RolYaOtorgado));
+            assert_eq!(contrato.otorgar_rol_privado(accounts.charlie, Rol::Auditor, Some(1)), Err(Error::RolSinAlcancePorEleccion));
+            assert_eq!(contrato.otorgar_rol_privado(accounts.charlie, Rol::OperadorPadron, Some(1)), Err(Error::EleccionInexistente));
+            assert!(contrato.consultar_rol(accounts.alice, Rol::Administrador, None));
+            assert!(contrato.consultar_rol(accounts.bob, Rol::Administrador, None));
+
+            // Sin rol de administrador no se otorgan ni revocan roles
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.otorgar_rol_privado(accounts.charlie, Rol::Auditor, None), Err(Error::NoEsAdministrador));
+            assert_eq!(contrato.revocar_rol_privado(accounts.alice, Rol::Administrador, None), Err(Error::NoEsAdministrador));
+            assert_eq!(contrato.renunciar_rol_privado(Rol::Auditor, None), Err(Error::RolNoOtorgado));
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.revocar_rol_privado(accounts.alice, Rol::Administrador, None), Ok(()));
+            assert!(!contrato.consultar_rol(accounts.alice, Rol::Administrador, None));
+            assert_eq!(contrato.revocar_rol_privado(accounts.alice, Rol::Administrador, None), Err(Error::RolNoOtorgado));
+            // Siempre debe quedar al menos un administrador
+            assert_eq!(contrato.renunciar_rol_privado(Rol::Administrador, None), Err(Error::UltimoAdministrador));
+
+            set_caller(accounts.alice);
+            assert_eq!(contrato.crear_eleccion_privado("01-01-2025 12:00".to_string(), "31-01-2025 12:00".to_string(), ConfiguracionEleccion::default()), Err(Error::NoEsAdministrador));
         }
 
+        #[ink::test]
+        fn test_operador_padron_por_eleccion()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            for _ in 0..2 {
+                contrato.crear_eleccion_privado("01-01-2025 12:00".to_string(), "31-01-2025 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            }
+            contrato.otorgar_rol_privado(accounts.charlie, Rol::OperadorPadron, Some(1)).unwrap();
+            assert!(contrato.consultar_rol(accounts.charlie, Rol::OperadorPadron, Some(1)));
+            assert!(!contrato.consultar_rol(accounts.charlie, Rol::OperadorPadron, None));
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.eve, "Eve", "Apellido", "12345678"));
+            for eleccion_id in [1, 2] {
+                agregar_usuario_pendiente_en_eleccion(&mut contrato, eleccion_id, accounts.django, TIPO_DE_USUARIO::VOTANTE);
+            }
+
+            // Charlie solo procesa la cola de la elección 1
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.procesar_usuarios_en_una_eleccion_privado(1, true), Ok(()));
+            assert!(contrato.votantes.contains((1, accounts.django)));
+            assert_eq!(contrato.procesar_usuarios_en_una_eleccion_privado(2, true), Err(Error::NoEsOperadorPadron));
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Err(Error::NoEsOperadorPadron));
+            assert_eq!(contrato.crear_eleccion_privado("01-01-2025 12:00".to_string(), "31-01-2025 12:00".to_string(), ConfiguracionEleccion::default()), Err(Error::NoEsAdministrador));
+
+            assert_eq!(contrato.renunciar_rol_privado(Rol::OperadorPadron, Some(1)), Ok(()));
+            assert_eq!(contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1), Err(Error::NoEsOperadorPadron));
+            let eventos = eventos_emitidos();
+            assert!(matches!(eventos.last(), Some(Event::RolRevocado(e)) if e.cuenta == accounts.charlie && e.rol == Rol::OperadorPadron && e.eleccion_id == Some(1) && e.renuncia));
+
+            // Un operador de todo el sistema procesa el registro y las colas de todas las elecciones
+            set_caller(accounts.alice);
+            contrato.otorgar_rol_privado(accounts.bob, Rol::OperadorPadron, None).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Ok(()));
+            assert_eq!(contrato.procesar_usuarios_en_una_eleccion_privado(2, false), Ok(()));
+        }
 
         #[ink::test]
-        fn test_activar_registro() 
+        fn test_auditor_consulta_reportes()
         {
-            let mut contract = SistemaElecciones::new();
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.usuarios.insert(accounts.eve, &crear_usuario(accounts.eve, "Eve", "Apellido", "12345678"));
 
-            let res = contract.activar_registro_privado();
-            assert_eq!(res, Ok(String::from("Se activó el registro para los usuarios.")));
-            assert_eq!(contract.registro_activado, true);
+            set_caller(accounts.frank);
+            assert_eq!(contrato.obtener_informacion_usuario_privado(accounts.eve), Err(Error::NoEsGeneradorReportes));
 
-            let res = contract.activar_registro_privado();
-            assert_eq!(res, Err(String::from("El registro ya está activado.")));
+            set_caller(accounts.alice);
+            contrato.otorgar_rol_privado(accounts.frank, Rol::Auditor, None).unwrap();
+            let eventos = eventos_emitidos();
+            assert!(matches!(eventos.last(), Some(Event::RolOtorgado(e)) if e.cuenta == accounts.frank && e.rol == Rol::Auditor && e.eleccion_id.is_none()));
+
+            set_caller(accounts.frank);
+            assert!(contrato.obtener_informacion_usuario_privado(accounts.eve).is_ok());
+            assert_eq!(contrato.activar_registro_privado(), Err(Error::NoEsAdministrador));
         }
 
         #[ink::test]
-        fn test_registro_usuario() 
+        fn test_cancelar_transferencia_administrador()
         {
-            let administrador: AccountId = AccountId::from([0x1; 32]);
-            let otro_usuario: AccountId = AccountId::from([0x2; 32]);
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            assert_eq!(contrato.cancelar_transferencia_administrador_privado(), Err(Error::SinTransferenciaPendiente));
+            assert_eq!(contrato.transferir_administrador_privado(accounts.alice), Err(Error::RolYaOtorgado));
+
+            // Una nueva propuesta reemplaza a la anterior
+            contrato.transferir_administrador_privado(accounts.bob).unwrap();
+            contrato.transferir_administrador_privado(accounts.charlie).unwrap();
+            assert_eq!(contrato.obtener_transferencia_administrador(), Some(PropuestaAdministrador { proponente: accounts.alice, nuevo: accounts.charlie, vence_en_bloque: None }));
+            set_caller(accounts.bob);
+            assert_eq!(contrato.aceptar_administrador_privado(), Err(Error::NoEsAdministradorPropuesto));
+            assert_eq!(contrato.cancelar_transferencia_administrador_privado(), Err(Error::NoEsAdministrador));
+
+            // La cuenta propuesta puede rechazar la transferencia cancelándola
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.cancelar_transferencia_administrador_privado(), Ok(()));
+            assert_eq!(contrato.aceptar_administrador_privado(), Err(Error::SinTransferenciaPendiente));
+            assert!(contrato.tiene_rol(accounts.alice, Rol::Administrador));
+            let eventos = eventos_emitidos();
+            assert!(matches!(eventos.last(), Some(Event::TransferenciaAdministradorCancelada(e)) if e.proponente == accounts.alice && e.nuevo == accounts.charlie));
+        }
 
-            set_caller(administrador);
+        #[ink::test]
+        fn test_vencimiento_transferencia_administrador()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
+            contrato.configurar_vencimiento_transferencia_privado(Some(2)).unwrap();
+            contrato.transferir_administrador_privado(accounts.bob).unwrap();
+            let vence_en_bloque = contrato.obtener_transferencia_administrador().unwrap().vence_en_bloque.unwrap();
+            assert_eq!(vence_en_bloque, contrato.env().block_number() + 2);
 
-            contrato.activar_registro_privado().unwrap();
+            for _ in 0..3 {
+                advance_block::<DefaultEnvironment>();
+            }
+            set_caller(accounts.bob);
+            assert_eq!(contrato.aceptar_administrador_privado(), Err(Error::TransferenciaVencida));
+            assert!(!contrato.tiene_rol(accounts.bob, Rol::Administrador));
+
+            // Con una nueva propuesta se acepta antes de que venza
+            set_caller(accounts.alice);
+            contrato.transferir_administrador_privado(accounts.bob).unwrap();
+            advance_block::<DefaultEnvironment>();
+            set_caller(accounts.bob);
+            assert_eq!(contrato.aceptar_administrador_privado(), Ok(()));
+            assert!(contrato.tiene_rol(accounts.bob, Rol::Administrador));
+            assert!(!contrato.tiene_rol(accounts.alice, Rol::Administrador));
+
+            set_caller(accounts.alice);
+            assert_eq!(contrato.configurar_vencimiento_transferencia_privado(None), Err(Error::NoEsAdministrador));
+        }
 
-            set_caller(otro_usuario);
+        // ====================== INICIO TESTS MULTIFIRMA ======================
+        // ====================== INICIO TESTS MULTIFIRMA ======================
+        // ====================== INICIO TESTS MULTIFIRMA ======================
 
-            let resultado = contrato.registrarse_privado("John".to_string(), "Doe".to_string(), "12345678".to_string());
-            assert_eq!(resultado, Ok("Registro exitoso. Se te añadió en la cola de usuarios pendientes.".to_string()));
+        //Crea un contrato con alice, bob y charlie como administradores y umbral 2
+        fn setup_contrato_multifirma() -> SistemaElecciones
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.otorgar_rol_privado(accounts.bob, Rol::Administrador, None).unwrap();
+            contrato.otorgar_rol_privado(accounts.charlie, Rol::Administrador, None).unwrap();
+            contrato.configurar_multifirma_privado(2, 10).unwrap();
+            contrato
         }
 
         #[ink::test]
-        fn test_obtener_ref_eleccion_por_id() 
+        fn test_configurar_multifirma()
         {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
+            assert_eq!(contrato.obtener_configuracion_multifirma(), (1, BLOQUES_VENCIMIENTO_PROPUESTAS));
+            // El umbral no puede superar la cantidad de administradores
+            assert_eq!(contrato.configurar_multifirma_privado(2, 10), Err(Error::UmbralInvalido));
+            assert_eq!(contrato.configurar_multifirma_privado(0, 10), Err(Error::UmbralInvalido));
+            contrato.otorgar_rol_privado(accounts.bob, Rol::Administrador, None).unwrap();
+            assert_eq!(contrato.configurar_multifirma_privado(2, 0), Err(Error::UmbralInvalido));
+            assert_eq!(contrato.configurar_multifirma_privado(2, 10), Ok(()));
+            assert_eq!(contrato.obtener_configuracion_multifirma(), (2, 10));
+
+            // Con la multifirma activada ya no se puede cambiar directamente, ni quedar con menos administradores que el umbral
+            assert_eq!(contrato.configurar_multifirma_privado(1, 10), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.renunciar_rol_privado(Rol::Administrador, None), Err(Error::UmbralInvalido));
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.configurar_multifirma_privado(1, 10), Err(Error::NoEsAdministrador));
+        }
 
-            let candidato1 = CandidatoConteo {
-                id: AccountId::from([0x01; 32]),
-                candidato_id: 1,
-                votos_totales: 0,
-            };
-            let candidato2 = CandidatoConteo {
-                id: AccountId::from([0x02; 32]),
-                candidato_id: 2,
-                votos_totales: 0,
-            };
+        #[ink::test]
+        fn test_acciones_criticas_requieren_multifirma()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            assert_eq!(contrato.crear_eleccion_privado("01-01-2025 12:00".to_string(), "31-01-2025 12:00".to_string(), ConfiguracionEleccion::default()), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.iniciar_votacion_privado(1), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.transferir_administrador_privado(accounts.django), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.asignar_generador_reportes_privado(accounts.django), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.otorgar_rol_privado(accounts.django, Rol::Administrador, None), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.revocar_rol_privado(accounts.bob, Rol::Administrador, None), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.configurar_vencimiento_transferencia_privado(Some(10)), Err(Error::RequiereMultifirma));
+        }
 
-            let votante1 = Votante {
-                id: AccountId::from([0x03; 32]),
-                voto_emitido: false,
-            };
-            let votante2 = Votante {
-                id: AccountId::from([0x04; 32]),
-                voto_emitido: false,
+        #[ink::test]
+        fn test_otorgar_rol_no_evita_multifirma()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            // Ningún rol se otorga ni se revoca con un solo administrador, ni siquiera los que no son de administrador
+            assert_eq!(contrato.otorgar_rol_privado(accounts.django, Rol::GeneradorReportes, None), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.otorgar_rol_privado(accounts.django, Rol::Auditor, None), Err(Error::RequiereMultifirma));
+            assert_eq!(contrato.otorgar_rol_privado(accounts.django, Rol::OperadorPadron, None), Err(Error::RequiereMultifirma));
+            assert!(!contrato.tiene_rol(accounts.django, Rol::GeneradorReportes));
+
+            contrato.proponer_accion_privado(AccionAdministrativa::OtorgarRol { cuenta: accounts.django, rol: Rol::GeneradorReportes, eleccion_id: None }).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_accion_privado(1), Ok(true));
+            assert!(contrato.tiene_rol(accounts.django, Rol::GeneradorReportes));
+
+            assert_eq!(contrato.revocar_rol_privado(accounts.django, Rol::GeneradorReportes, None), Err(Error::RequiereMultifirma));
+            contrato.proponer_accion_privado(AccionAdministrativa::RevocarRol { cuenta: accounts.django, rol: Rol::GeneradorReportes, eleccion_id: None }).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.confirmar_accion_privado(2), Ok(true));
+            assert!(!contrato.tiene_rol(accounts.django, Rol::GeneradorReportes));
+
+            contrato.proponer_accion_privado(AccionAdministrativa::ConfigurarVencimientoTransferencia { bloques: Some(5) }).unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(contrato.confirmar_accion_privado(3), Ok(true));
+            contrato.proponer_accion_privado(AccionAdministrativa::TransferirAdministrador { id: accounts.django }).unwrap();
+            set_caller(accounts.bob);
+            contrato.confirmar_accion_privado(4).unwrap();
+            assert!(contrato.obtener_transferencia_administrador().unwrap().vence_en_bloque.is_some());
+        }
+
+        #[ink::test]
+        fn test_confirmaciones_de_administrador_revocado_no_cuentan()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            // Alice propone otorgar un rol y después se le revoca el rol de administrador
+            contrato.proponer_accion_privado(AccionAdministrativa::OtorgarRol { cuenta: accounts.django, rol: Rol::Auditor, eleccion_id: None }).unwrap();
+            set_caller(accounts.bob);
+            contrato.proponer_accion_privado(AccionAdministrativa::RevocarRol { cuenta: accounts.alice, rol: Rol::Administrador, eleccion_id: None }).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.confirmar_accion_privado(2), Ok(true));
+            assert_eq!(contrato.obtener_propuestas_pendientes()[0].confirmaciones, 0);
+
+            // La confirmación de Bob sola no alcanza el umbral
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_accion_privado(1), Ok(false));
+            assert!(!contrato.tiene_rol(accounts.django, Rol::Auditor));
+            assert!(contrato.consultar_confirmacion(1, accounts.alice));
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.confirmar_accion_privado(1), Ok(true));
+            assert!(contrato.tiene_rol(accounts.django, Rol::Auditor));
+        }
+
+        #[ink::test]
+        fn test_proponer_y_confirmar_accion()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            let accion = AccionAdministrativa::CrearEleccion {
+                fecha_inicial: "01-01-2025 12:00".to_string(),
+                fecha_final: "31-01-2025 12:00".to_string(),
+                configuracion: ConfiguracionEleccion::default(),
             };
+            assert_eq!(contrato.proponer_accion_privado(accion), Ok(1));
+            assert!(!contrato.existe_eleccion(1));
+            assert!(contrato.consultar_confirmacion(1, accounts.alice));
+            assert_eq!(contrato.confirmar_accion_privado(1), Err(Error::PropuestaYaConfirmada));
+            assert_eq!(contrato.obtener_propuestas_pendientes().len(), 1);
+
+            set_caller(accounts.django);
+            assert_eq!(contrato.confirmar_accion_privado(1), Err(Error::NoEsAdministrador));
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_accion_privado(2), Err(Error::PropuestaInexistente));
+            assert_eq!(contrato.confirmar_accion_privado(1), Ok(true));
+            assert!(contrato.existe_eleccion(1));
+            assert!(contrato.obtener_propuestas_pendientes().is_empty());
+
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.confirmar_accion_privado(1), Err(Error::PropuestaYaEjecutada));
+
+            let eventos = eventos_emitidos();
+            assert!(eventos.iter().any(|evento| matches!(evento, Event::AccionPropuesta(e) if e.propuesta_id == 1 && e.proponente == accounts.alice)));
+            assert!(eventos.iter().any(|evento| matches!(evento, Event::AccionConfirmada(e) if e.confirmante == accounts.bob && e.confirmaciones == 2)));
+            assert!(eventos.iter().any(|evento| matches!(evento, Event::AccionEjecutada(e) if e.propuesta_id == 1)));
+            assert!(eventos.iter().any(|evento| matches!(evento, Event::EleccionCreada(e) if e.eleccion_id == 1)));
+        }
 
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![candidato1],
-                votantes: vec![votante1],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 0,
-                fecha_final: 0,
-                resultados:None
-            });
+        #[ink::test]
+        fn test_propuesta_vencida()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            contrato.proponer_accion_privado(AccionAdministrativa::AsignarGeneradorReportes { id: accounts.django }).unwrap();
+            for _ in 0..11 {
+                advance_block::<DefaultEnvironment>();
+            }
+            assert!(contrato.obtener_propuestas_pendientes().is_empty());
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_accion_privado(1), Err(Error::PropuestaVencida));
+            assert!(!contrato.tiene_rol(accounts.django, Rol::GeneradorReportes));
+        }
 
-            contrato.elecciones.push(Eleccion {
-                id: 2,
-                candidatos: vec![candidato2],
-                votantes: vec![votante2],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 0,
-                fecha_final: 0,
-                resultados:None
-            });
+        #[ink::test]
+        fn test_transferencia_y_configuracion_por_multifirma()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            contrato.proponer_accion_privado(AccionAdministrativa::TransferirAdministrador { id: accounts.django }).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.confirmar_accion_privado(1), Ok(true));
+            // La transferencia queda a nombre de quien propuso la acción y la acepta la cuenta propuesta
+            assert_eq!(contrato.obtener_transferencia_administrador().unwrap().proponente, accounts.alice);
+            set_caller(accounts.django);
+            assert_eq!(contrato.aceptar_administrador_privado(), Ok(()));
+            assert!(contrato.tiene_rol(accounts.django, Rol::Administrador));
+            assert!(!contrato.tiene_rol(accounts.alice, Rol::Administrador));
+
+            // Volver a un solo firmante también requiere confirmaciones
+            contrato.proponer_accion_privado(AccionAdministrativa::ConfigurarMultifirma { umbral: 1, bloques_vencimiento: 10 }).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_accion_privado(2), Ok(true));
+            assert_eq!(contrato.obtener_configuracion_multifirma(), (1, 10));
+            assert_eq!(contrato.asignar_generador_reportes_privado(accounts.eve), Ok(()));
+        }
 
-            let resultado = contrato.obtener_ref_eleccion_por_id(1);
-            assert!(resultado.is_some());
-            assert_eq!(resultado.unwrap(), &contrato.elecciones[0]);
+        #[ink::test]
+        fn test_accion_fallida_no_se_confirma()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            contrato.proponer_accion_privado(AccionAdministrativa::IniciarVotacion { eleccion_id: 1 }).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contrato.confirmar_accion_privado(1), Err(Error::EleccionInexistente));
+            assert!(!contrato.consultar_confirmacion(1, accounts.bob));
+            assert_eq!(contrato.obtener_propuestas_pendientes()[0].confirmaciones, 1);
+        }
 
-            let resultado = contrato.obtener_ref_eleccion_por_id(2);
-            assert!(resultado.is_some());
-            assert_eq!(resultado.unwrap(), &contrato.elecciones[1]);
+        // ====================== INICIO TESTS LOTES ======================
+        // ====================== INICIO TESTS LOTES ======================
+        // ====================== INICIO TESTS LOTES ======================
 
-            let resultado = contrato.obtener_ref_eleccion_por_id(3);
-            assert!(resultado.is_none());
+        #[ink::test]
+        fn test_procesar_usuarios_pendientes_en_lote()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = crear_trabajo_final(accounts.alice);
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.bob, "Bob", "Apellido", "11111111"));
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.charlie, "Charlie", "Apellido", "22222222"));
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.django, "Django", "Apellido", "33333333"));
+
+            // Se procesan usuarios en cualquier posición de la cola, con un resultado por cuenta
+            let resultados = contrato.procesar_usuarios_pendientes_privado(vec![accounts.charlie, accounts.eve, accounts.charlie], true).unwrap();
+            assert_eq!(resultados, vec![
+                (accounts.charlie, Ok(())),
+                (accounts.eve, Err(Error::UsuarioNoPendiente)),
+                (accounts.charlie, Err(Error::UsuarioNoPendiente)),
+            ]);
+            assert!(contrato.usuarios.contains(accounts.charlie));
+            assert_eq!(contrato.procesar_usuarios_pendientes_privado(vec![accounts.django], false), Ok(vec![(accounts.django, Ok(()))]));
+            assert!(contrato.usuarios_rechazados.contains(accounts.django));
+
+            // La cola sigue procesando en orden a los que quedaron pendientes
+            assert_eq!(contrato.siguiente_usuario_pendiente().unwrap().id, accounts.bob);
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Ok(()));
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Err(Error::NoHayUsuariosPendientes));
+            assert!(contrato.siguiente_usuario_pendiente().is_none());
+
+            let lote = vec![accounts.bob; MAXIMO_USUARIOS_POR_LOTE + 1];
+            assert_eq!(contrato.procesar_usuarios_pendientes_privado(lote, true), Err(Error::LoteDemasiadoGrande));
+            set_caller(accounts.bob);
+            assert_eq!(contrato.procesar_usuarios_pendientes_privado(vec![accounts.bob], true), Err(Error::NoEsOperadorPadron));
         }
 
         #[ink::test]
-        fn test_validar_estado_eleccion() 
+        fn test_procesar_usuarios_pendientes_en_eleccion_en_lote()
         {
-            let mut contrato = SistemaElecciones::new();
-            let usuario_id = AccountId::from([0x01; 32]);
-            
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![(usuario_id, TIPO_DE_USUARIO::VOTANTE)],
-                votacion_iniciada: false,
-                fecha_inicio: 100,
-                fecha_final: 200,
-                resultados:None
-            });
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = crear_trabajo_final(accounts.alice);
+            contrato.crear_eleccion_privado("01-01-2025 12:00".to_string(), "31-01-2025 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.charlie, TIPO_DE_USUARIO::VOTANTE);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.django, TIPO_DE_USUARIO::VOTANTE);
+
+            let resultados = contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.django, accounts.bob, accounts.eve], true).unwrap();
+            assert_eq!(resultados, vec![
+                (accounts.django, Ok(())),
+                (accounts.bob, Ok(())),
+                (accounts.eve, Err(Error::UsuarioNoPendiente)),
+            ]);
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(eleccion.cantidad_votantes, 1);
+            assert_eq!(eleccion.cantidad_candidatos, 1);
+            assert!(contrato.votantes.contains((1, accounts.django)));
+            assert_eq!(contrato.candidatos_por_cuenta.get((1, accounts.bob)), Some(1));
+
+            // Charlie quedó primero en la cola al descartar a los ya procesados
+            assert_eq!(contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1).unwrap().usuario, accounts.charlie);
+            assert_eq!(contrato.procesar_usuarios_en_una_eleccion_privado(1, false), Ok(()));
+            assert!(contrato.rechazados_eleccion.contains((1, accounts.charlie)));
+            assert_eq!(contrato.procesar_usuarios_en_una_eleccion_privado(1, false), Err(Error::NoHayUsuariosPendientes));
+
+            assert_eq!(contrato.procesar_usuarios_pendientes_en_eleccion_privado(2, vec![accounts.bob], true), Err(Error::EleccionInexistente));
+            let eventos = eventos_emitidos();
+            assert!(eventos.iter().any(|evento| matches!(evento, Event::UsuarioAceptado(e) if e.usuario == accounts.bob && e.tipo == Some(TIPO_DE_USUARIO::CANDIDATO))));
+        }
 
-            contrato.elecciones.push(Eleccion {
-                id: 2,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: true,
-                fecha_inicio: 100,
-                fecha_final: 200,
-                resultados:None
-            });
+        // ====================== INICIO TESTS CONSULTAS PAGINADAS ======================
+        // ====================== INICIO TESTS CONSULTAS PAGINADAS ======================
+        // ====================== INICIO TESTS CONSULTAS PAGINADAS ======================
 
-            contrato.elecciones.push(Eleccion {
-                id: 3,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 100,
-                fecha_final: 50,
-                resultados:None
-            });
+        #[test]
+        fn test_paginar()
+        {
+            let pagina = SistemaElecciones::paginar(0, 2, 5, Some);
+            assert_eq!(pagina, Pagina { elementos: vec![0, 1], siguiente: Some(2) });
+            let pagina = SistemaElecciones::paginar(4, 2, 5, Some);
+            assert_eq!(pagina, Pagina { elementos: vec![4], siguiente: None });
+            let pagina = SistemaElecciones::paginar(0, u32::MAX, 500, Some);
+            assert_eq!(pagina.elementos.len(), MAXIMO_ELEMENTOS_POR_PAGINA as usize);
+            assert_eq!(pagina.siguiente, Some(MAXIMO_ELEMENTOS_POR_PAGINA));
+            let pagina = SistemaElecciones::paginar(10, 2, 5, Some);
+            assert_eq!(pagina, Pagina { elementos: vec![], siguiente: None });
+        }
 
-            contrato.elecciones.push(Eleccion {
-                id: 4,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 150,
-                fecha_final: 200,
-                resultados:None
-            });
+        #[ink::test]
+        fn test_listar_usuarios()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = crear_trabajo_final(accounts.alice);
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.bob, "Bob", "Apellido", "11111111"));
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.charlie, "Charlie", "Apellido", "22222222"));
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.django, "Django", "Apellido", "33333333"));
+            agregar_usuario_pendiente(&mut contrato, crear_usuario(accounts.eve, "Eve", "Apellido", "44444444"));
+            contrato.procesar_usuarios_pendientes_privado(vec![accounts.charlie, accounts.bob], true).unwrap();
+            contrato.procesar_usuarios_pendientes_privado(vec![accounts.eve], false).unwrap();
+
+            let usuarios = contrato.listar_usuarios_privado(0, 1).unwrap();
+            assert_eq!(usuarios.elementos[0].nombre, "Charlie");
+            assert_eq!(usuarios.siguiente, Some(1));
+            let usuarios = contrato.listar_usuarios_privado(1, 10).unwrap();
+            assert_eq!(usuarios.elementos[0].id, accounts.bob);
+            assert_eq!(usuarios.siguiente, None);
+
+            // Las posiciones de la cola ya procesadas se omiten
+            let pendientes = contrato.listar_usuarios_pendientes_privado(0, 10).unwrap();
+            assert_eq!(pendientes.elementos.iter().map(|usuario| usuario.id).collect::<Vec<_>>(), vec![accounts.django]);
+            assert_eq!(contrato.listar_usuarios_rechazados_privado(0, 10).unwrap().elementos, vec![accounts.eve]);
+
+            // Un auditor puede listar, un usuario sin roles no
+            contrato.otorgar_rol_privado(accounts.frank, Rol::Auditor, None).unwrap();
+            set_caller(accounts.frank);
+            assert!(contrato.listar_usuarios_privado(0, 10).is_ok());
+            set_caller(accounts.bob);
+            assert_eq!(contrato.listar_usuarios_privado(0, 10), Err(Error::NoEsOperadorPadron));
+            assert_eq!(contrato.listar_usuarios_pendientes_privado(0, 10), Err(Error::NoEsOperadorPadron));
+            assert_eq!(contrato.listar_usuarios_rechazados_privado(0, 10), Err(Error::NoEsOperadorPadron));
+        }
 
-            // Caso 1: Usuario ya registrado
-            let resultado = contrato.validar_estado_eleccion(1, 50, usuario_id);
-            assert_eq!(resultado, Err(String::from("Ya está registrado en la elección.")));
+        #[ink::test]
+        fn test_listar_elecciones_y_participantes()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = crear_trabajo_final(accounts.alice);
+            contrato.crear_eleccion_privado("01-01-2025 12:00".to_string(), "31-01-2025 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            contrato.crear_eleccion_privado("01-01-2024 12:00".to_string(), "31-01-2024 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1720000000000);
+
+            let elecciones = contrato.listar_elecciones(0, 10);
+            assert_eq!(elecciones.elementos.len(), 2);
+            assert_eq!(elecciones.elementos[0].fecha_inicio, SistemaElecciones::parsear_fecha("01-01-2025 12:00").unwrap());
+            assert_eq!(elecciones.elementos[0].estado, EstadoEleccion::Inscripcion);
+            assert_eq!(elecciones.elementos[1].estado, EstadoEleccion::Finalizada);
+            assert_eq!(contrato.listar_elecciones(1, 10).elementos[0].id, 2);
+
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.bob, TIPO_DE_USUARIO::CANDIDATO);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.charlie, TIPO_DE_USUARIO::VOTANTE);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.django, TIPO_DE_USUARIO::VOTANTE);
+            agregar_usuario_pendiente_en_eleccion(&mut contrato, 1, accounts.eve, TIPO_DE_USUARIO::VOTANTE);
+            contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.bob, accounts.django], true).unwrap();
+            contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.eve], false).unwrap();
+
+            assert_eq!(contrato.listar_pendientes_eleccion_privado(1, 0, 10).unwrap().elementos, vec![
+                SolicitudPendiente { usuario: accounts.charlie, tipo: TIPO_DE_USUARIO::VOTANTE },
+            ]);
+            assert_eq!(contrato.listar_votantes_eleccion_privado(1, 0, 10).unwrap().elementos, vec![accounts.django]);
+            assert_eq!(contrato.listar_candidatos_eleccion(1, 0, 10).unwrap().elementos, vec![accounts.bob]);
+            assert_eq!(contrato.listar_rechazados_eleccion_privado(1, 0, 10).unwrap().elementos, vec![accounts.eve]);
+            assert_eq!(contrato.listar_votantes_eleccion_privado(3, 0, 10), Err(Error::EleccionInexistente));
+
+            // Un operador de padrón de otra elección no puede listar esta
+            contrato.otorgar_rol_privado(accounts.frank, Rol::OperadorPadron, Some(2)).unwrap();
+            set_caller(accounts.frank);
+            assert!(contrato.listar_votantes_eleccion_privado(2, 0, 10).is_ok());
+            assert_eq!(contrato.listar_votantes_eleccion_privado(1, 0, 10), Err(Error::NoEsOperadorPadron));
+            assert_eq!(contrato.listar_pendientes_eleccion_privado(1, 0, 10), Err(Error::NoEsOperadorPadron));
+            assert_eq!(contrato.listar_rechazados_eleccion_privado(1, 0, 10), Err(Error::NoEsOperadorPadron));
+        }
 
-            // Caso 2: Votación ya comenzó
-            let resultado = contrato.validar_estado_eleccion(2, 50, usuario_id);
-            assert_eq!(resultado, Err(String::from("La votación en la elección ya comenzó, no te puedes registrar.")));
+        // ====================== INICIO TESTS ESTADOS ======================
+        // ====================== INICIO TESTS ESTADOS ======================
+        // ====================== INICIO TESTS ESTADOS ======================
 
-            // Caso 3: Elección ya finalizó
-            let resultado = contrato.validar_estado_eleccion(3, 52, usuario_id);
-            assert_eq!(resultado, Err(String::from("La elección ya finalizó, no te puedes registrar.")));
+        #[test]
+        fn test_actualizar_estado()
+        {
+            let mut eleccion = Eleccion { fecha_fin_revelacion: Some(300), ..Eleccion::nueva(1, 100, 200) };
+            assert_eq!(eleccion.estado, EstadoEleccion::Borrador);
+            assert_eq!(eleccion.actualizar_estado(50), EstadoEleccion::Inscripcion);
+            assert_eq!(eleccion.actualizar_estado(100), EstadoEleccion::Votacion);
+            assert_eq!(eleccion.actualizar_estado(200), EstadoEleccion::Votacion);
+            assert_eq!(eleccion.actualizar_estado(201), EstadoEleccion::Escrutinio);
+            assert_eq!(eleccion.actualizar_estado(300), EstadoEleccion::Finalizada);
+            // La fase nunca retrocede
+            assert_eq!(eleccion.actualizar_estado(50), EstadoEleccion::Finalizada);
+
+            // Sin voto secreto no hay escrutinio
+            let mut eleccion = Eleccion::nueva(2, 100, 200);
+            assert_eq!(eleccion.actualizar_estado(201), EstadoEleccion::Finalizada);
+
+            // Una elección cancelada queda cancelada
+            let mut eleccion = Eleccion { estado: EstadoEleccion::Cancelada, ..Eleccion::nueva(3, 100, 200) };
+            assert_eq!(eleccion.actualizar_estado(150), EstadoEleccion::Cancelada);
+            assert_eq!(eleccion.actualizar_estado(300), EstadoEleccion::Cancelada);
+        }
 
-            // Caso 4: Elección válida y no iniciada
-            let resultado = contrato.validar_estado_eleccion(4, 50, usuario_id);
-            assert!(resultado.is_ok());
-            let eleccion = resultado.unwrap();
-            assert_eq!(eleccion.id, 4);
+        #[ink::test]
+        fn test_obtener_estado_eleccion()
+        {
+            let contrato = setup_contrato_voto_secreto();
+            assert_eq!(contrato.obtener_estado_eleccion(2), Err(Error::EleccionInexistente));
+
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Inscripcion));
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Votacion));
+            set_block_timestamp::<DefaultEnvironment>(1736000000000);
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Escrutinio));
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Finalizada));
+            // La consulta no guarda la fase
+            assert_eq!(contrato.obtener_eleccion_por_id(1).unwrap().estado, EstadoEleccion::Borrador);
         }
 
         #[ink::test]
-        fn test_crear_eleccion() 
+        fn test_mensajes_validados_contra_estado()
         {
-            // Instanciar el contrato
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+            let compromiso = SistemaElecciones::calcular_compromiso(1, accounts.charlie, &Voto::Candidato(1), [7; 32]);
+
+            // En escrutinio ya no se puede votar ni procesar pendientes, y los resultados no están disponibles
+            set_block_timestamp::<DefaultEnvironment>(1736000000000);
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.comprometer_voto_privado(1, compromiso), Err(Error::VotacionFinalizada));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.iniciar_votacion_privado(1), Err(Error::VotacionFinalizada));
+            assert_eq!(contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.frank], true), Err(Error::EleccionFinalizada));
+            assert_eq!(contrato.obtener_resultados_privado(1), Err(Error::EleccionNoFinalizada));
+
+            // Una elección cancelada rechaza todo
+            cambiar_estado(&mut contrato, 1, EstadoEleccion::Cancelada);
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            assert_eq!(contrato.iniciar_votacion_privado(1), Err(Error::EleccionCancelada));
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.comprometer_voto_privado(1, compromiso), Err(Error::EleccionCancelada));
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_resultados_privado(1), Err(Error::EleccionCancelada));
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Cancelada));
+        }
+
+        #[ink::test]
+        fn test_ventanas_inscripcion()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-    
-            // Configurar el administrador
-            let administrador = AccountId::from([0x1; 32]);
-            contrato.administrador = administrador;
-    
-            // Crear una elección válida
-            let resultado = contrato.crear_eleccion_privado(
-                "01-01-2025 12:00".to_string(),
-                "31-01-2025 12:00".to_string()
-            );
-    
-            // Verificar que la elección se creó correctamente
-            assert_eq!(resultado, Ok("Eleccion creada exitosamente. Id de la elección: 1".to_string()));
-    
-            // Verificar que la elección se añadió a la lista
-            assert_eq!(contrato.elecciones.len(), 1);
-            let eleccion = &contrato.elecciones[0];
-            assert_eq!(eleccion.id, 1);
-            assert_eq!(eleccion.fecha_inicio, 1735732800000); 
-            assert_eq!(eleccion.fecha_final, 1738324800000);
-    
-            // Crear una elección con fecha inicial inválida
-            let resultado = contrato.crear_eleccion_privado(
-                "01-01-2025 12:00".to_string(),
-                "invalid-date".to_string()
-            );
-            assert_eq!(resultado, Err("Error en el formato de la fecha final. Formato: dd-mm-YYYY hh:mm".to_string()));
-    
-            // Crear una elección con fecha final inválida
-            let resultado = contrato.crear_eleccion_privado(
-                "invalid-date".to_string(),
-                "31-01-2025 12:00".to_string()
-            );
-            assert_eq!(resultado, Err("Error en el formato de la fecha inicial. Formato: dd-mm-YYYY hh:mm".to_string()));
-    
-            // Crear una elección sin ser administrador
-            contrato.administrador = AccountId::from([0x2; 32]);
-            let resultado = contrato.crear_eleccion_privado(
-                "01-01-2025 12:00".to_string(),
-                "31-01-2025 12:00".to_string()
-            );
-            assert_eq!(resultado, Err(ERRORES::NO_ES_ADMINISTRADOR.to_string()));
+            let configuracion = |inicio: &str, cierre_candidatos: &str| ConfiguracionEleccion {
+                inicio_inscripcion: Some(inicio.to_string()),
+                cierre_inscripcion_candidatos: Some(cierre_candidatos.to_string()),
+                ..Default::default()
+            };
+
+            // La apertura debe ser anterior a los cierres, y los cierres no pueden ser posteriores a la fecha de inicio
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion("invalid-date", "01-06-2024 12:00"));
+            assert_eq!(resultado, Err(Error::VentanaInscripcionInvalida));
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion("01-06-2024 12:00", "01-06-2024 12:00"));
+            assert_eq!(resultado, Err(Error::VentanaInscripcionInvalida));
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion("01-05-2024 12:00", "02-07-2024 12:00"));
+            assert_eq!(resultado, Err(Error::VentanaInscripcionInvalida));
+
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion("01-05-2024 12:00", "01-06-2024 12:00"));
+            assert_eq!(resultado, Ok(1));
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(eleccion.cierre_inscripcion_candidatos, 1717243200000);
+            assert_eq!(eleccion.cierre_inscripcion_votantes, eleccion.fecha_inicio);
+            for cuenta in [accounts.bob, accounts.charlie, accounts.django] {
+                contrato.usuarios.insert(cuenta, &crear_usuario(cuenta, "Nombre", "Apellido", "12345678"));
+            }
+
+            // Antes de la apertura la elección está en borrador
+            set_block_timestamp::<DefaultEnvironment>(1714000000000);
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Borrador));
+            set_caller(accounts.bob);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO), Err(Error::InscripcionCerrada));
+
+            set_block_timestamp::<DefaultEnvironment>(1715000000000);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO), Ok(()));
+
+            // Cerrada la inscripción de candidatos, los votantes todavía pueden inscribirse
+            set_block_timestamp::<DefaultEnvironment>(1717243200000);
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO), Err(Error::InscripcionCerrada));
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE), Ok(()));
+
+            set_block_timestamp::<DefaultEnvironment>(1719835200000);
+            set_caller(accounts.django);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE), Err(Error::InscripcionCerrada));
         }
 
         #[ink::test]
-        fn test_procesar_usuarios_en_una_eleccion() 
+        fn test_perfil_candidato()
         {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let accounts = default_accounts::<DefaultEnvironment>();
-            
-            contrato.crear_eleccion_privado(
-                String::from("01-07-2024 12:00"),
-                String::from("02-07-2024 12:00"),
-            ).unwrap();
+            assert_eq!(contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()), Ok(1));
+            for cuenta in [accounts.bob, accounts.charlie, accounts.django] {
+                contrato.usuarios.insert(cuenta, &crear_usuario(cuenta, "Nombre", "Apellido", "12345678"));
+            }
+            let perfil = PerfilCandidato {
+                partido: "Frente Vecinal".to_string(),
+                numero_lista: 501,
+                nombre_boleta: "Bob Pérez".to_string(),
+                plataforma: "Más espacios verdes.".to_string(),
+                hash_contenido: Some(Hash::from([0x07; 32])),
+            };
 
-            
-            contrato.elecciones[0].usuarios_pendientes.push((accounts.alice, TIPO_DE_USUARIO::VOTANTE));
-            contrato.elecciones[0].usuarios_pendientes.push((accounts.bob, TIPO_DE_USUARIO::CANDIDATO));
+            set_caller(accounts.bob);
+            let invalido = PerfilCandidato { nombre_boleta: "x".repeat(LARGO_MAXIMO_NOMBRE_BOLETA + 1), ..perfil.clone() };
+            assert_eq!(contrato.ingresar_como_candidato_privado(1, invalido), Err(Error::PerfilCandidatoInvalido));
+            assert_eq!(contrato.ingresar_como_candidato_privado(1, perfil.clone()), Ok(()));
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.ingresar_como_candidato_privado(1, perfil.clone()), Ok(()));
+
+            // Mientras la candidatura está pendiente solo la ve quien puede aprobarla
+            assert_eq!(contrato.obtener_perfil_candidato_privado(1, accounts.bob), Err(Error::CandidatoInexistente));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_perfil_candidato_privado(1, accounts.bob), Ok(perfil.clone()));
+
+            // Al aceptar se publica; al rechazar se descarta
+            assert!(contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.bob], true).is_ok());
+            assert!(contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.charlie], false).is_ok());
+            set_caller(accounts.django);
+            assert_eq!(contrato.obtener_perfil_candidato_privado(1, accounts.bob), Ok(perfil.clone()));
+            assert_eq!(contrato.obtener_informacion_candidato_eleccion_privado(1, 1).unwrap().perfil, perfil);
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_perfil_candidato_privado(1, accounts.charlie), Err(Error::CandidatoInexistente));
+
+            // Quien ingresa sin perfil queda con uno vacío, y los votantes no tienen perfil
+            set_caller(accounts.django);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE), Ok(()));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_perfil_candidato_privado(1, accounts.django), Err(Error::CandidatoInexistente));
+        }
 
-    
-            // Caso 1: Procesar siguiente usuario aceptando
-            let result = contrato.procesar_usuarios_en_una_eleccion(1, true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-    
-            // Caso 2: Procesar siguiente usuario aceptando como candidato
-            let result = contrato.procesar_usuarios_en_una_eleccion(1, true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-    
-            // Caso 3: Procesar siguiente usuario rechazando
-            let result = contrato.procesar_usuarios_en_una_eleccion(1, false);
-            assert_eq!(result, Err(String::from("No hay usuarios pendientes.")));
-    
-            // Caso 4: Intentar procesar usuario en una elección no existente
-            let result = contrato.procesar_usuarios_en_una_eleccion(2, true);
-            assert_eq!(result, Err(String::from("Eleccion no encontrada")));
-        } 
+        // ====================== INICIO TESTS EDICION DE ELECCIONES ======================
+        // ====================== INICIO TESTS EDICION DE ELECCIONES ======================
+        // ====================== INICIO TESTS EDICION DE ELECCIONES ======================
 
         #[ink::test]
-        fn test_ingresar_a_eleccion() 
+        fn test_metadatos_eleccion()
         {
             let accounts = get_default_test_accounts();
-            let alice = accounts.alice;
-            let charlie = accounts.charlie;
-            let eleccion_id: u64 = 1;
-            let tipo_usuario: TIPO_DE_USUARIO = TIPO_DE_USUARIO::VOTANTE;
-            set_caller(alice);
-        
-            let mut contract = SistemaElecciones::new();
-        
-            // Establecemos el administrador como el llamante y activamos el registro
-            contract.activar_registro().unwrap();
-            contract.crear_eleccion("01-01-2024 10:00".into(), "02-01-2024 10:00".into()).unwrap();
-        
-            // Usuario no registrado intenta ingresar a la elección
-            set_caller(charlie);
-            let result = contract.ingresar_a_eleccion_privado(eleccion_id, tipo_usuario.clone());
-            assert_eq!(result, Err(ERRORES::USUARIO_NO_REGISTRADO.to_string()), "Error: Usuario no registrado");
-        
-            // Registramos al usuario
-            let result = contract.registrarse("Juan".into(), "Perez".into(), "12345678".into()).unwrap();
-            assert_eq!(result, String::from("Registro exitoso. Se te añadió en la cola de usuarios pendientes."));
-        
-            // Aceptamos al usuario pendiente
-            set_caller(alice);
-            let result = contract.procesar_siguiente_usuario_pendiente(true);
-            assert_eq!(result, Ok(String::from("Usuario agregado exitosamente.")));
-        
-            // Usuario registrado intenta ingresar a la elección
-            set_caller(charlie);
-            let result = contract.ingresar_a_eleccion_privado(eleccion_id, tipo_usuario.clone());
-            assert_eq!(result, Ok(String::from("Ingresó a la elección correctamente Pendiente de aprobacion del Administrador")));
-        
-            
-            // Limpiamos el estado de usuarios rechazados para continuar con el test
-            contract.elecciones[0].usuarios_rechazados.clear();
-            contract.elecciones[0].usuarios_pendientes.clear();
-        
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            let metadatos = MetadatosEleccion {
+                titulo: "Elección de intendente".to_string(),
+                descripcion: "Elección general de intendente municipal.".to_string(),
+                cargo: "Intendente".to_string(),
+                jurisdiccion: "AR-B-001".to_string(),
+                hash_documento: Some(Hash::from([7; 32])),
+            };
 
+            let demasiado_largo = MetadatosEleccion { jurisdiccion: "X".repeat(LARGO_MAXIMO_JURISDICCION + 1), ..metadatos.clone() };
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion { metadatos: demasiado_largo, ..Default::default() });
+            assert_eq!(resultado, Err(Error::MetadatosInvalidos));
+
+            let resultado = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion { metadatos: metadatos.clone(), ..Default::default() });
+            assert_eq!(resultado, Ok(1));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::EleccionCreada(e)) if e.titulo == "Elección de intendente"));
+
+            // Cualquier usuario puede consultar a qué elección ingresa
+            set_caller(accounts.bob);
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            let informacion = contrato.obtener_informacion_eleccion(1).unwrap();
+            assert_eq!(informacion.metadatos, metadatos);
+            assert_eq!(informacion.estado, EstadoEleccion::Inscripcion);
+            assert_eq!(informacion, contrato.listar_elecciones(0, 1).elementos[0]);
+            assert_eq!(contrato.obtener_informacion_eleccion(2), Err(Error::EleccionInexistente));
         }
 
         #[ink::test]
-        fn test_iniciar_votacion_privado() 
+        fn test_editar_eleccion()
         {
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-
-            // Crear el contrato con el administrador
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
+            let configuracion = ConfiguracionEleccion { inicio_inscripcion: Some("01-06-2024 12:00".to_string()), ..Default::default() };
+            contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion.clone()).unwrap();
 
-            // Añadir una elección de prueba
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_pendientes: vec![],
-                usuarios_rechazados: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 50,
-                fecha_final: 150,
-                resultados: None,
-            });
+            set_block_timestamp::<DefaultEnvironment>(1717000000000);
+            // Una fecha mal tipeada se corrige sin crear otra elección
+            let configuracion_editada = ConfiguracionEleccion { bancas: 2, ..configuracion };
+            assert_eq!(contrato.editar_eleccion_privado(1, "01-08-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_editada.clone()), Ok(()));
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(eleccion.fecha_inicio, SistemaElecciones::parsear_fecha("01-08-2024 12:00").unwrap());
+            assert_eq!(eleccion.cierre_inscripcion_votantes, eleccion.fecha_inicio);
+            assert_eq!(eleccion.bancas, 2);
+            assert!(matches!(eventos_emitidos().last(), Some(Event::EleccionEditada(e)) if e.eleccion_id == 1 && e.fecha_inicio == eleccion.fecha_inicio));
+            // Se valida igual que al crearla
+            assert_eq!(contrato.editar_eleccion_privado(1, "invalid-date".to_string(), "31-12-2024 12:00".to_string(), configuracion_editada.clone()), Err(Error::FechaInicialInvalida));
+            assert_eq!(contrato.editar_eleccion_privado(2, "01-08-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_editada.clone()), Err(Error::EleccionInexistente));
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.editar_eleccion_privado(1, "01-08-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_editada.clone()), Err(Error::NoEsAdministrador));
+
+            // Una vez abierta la inscripción ya no se puede editar
+            set_caller(accounts.alice);
+            set_block_timestamp::<DefaultEnvironment>(1717243200000);
+            assert_eq!(contrato.editar_eleccion_privado(1, "01-08-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), configuracion_editada), Err(Error::EleccionNoEditable));
+        }
 
-            // Caso 1: No es administrador
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            assert_eq!(
-                contrato.iniciar_votacion_privado(1),
-                Err(String::from("No eres el administrador."))
-            );
+        #[ink::test]
+        fn test_postergar_eleccion()
+        {
+            let mut contrato = setup_contrato_voto_secreto();
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
 
-            // Caso 2: Elección no encontrada
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice); // Restaurar administrador
-            assert_eq!(
-                contrato.iniciar_votacion_privado(2),
-                Err(String::from("No existe una elección con ese id."))
-            );
+            // Las nuevas fechas no pueden adelantar la elección
+            assert_eq!(contrato.postergar_eleccion_privado(1, "01-06-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), "Feriado".to_string()), Err(Error::PostergacionInvalida));
+            assert_eq!(contrato.postergar_eleccion_privado(1, "01-07-2024 12:00".to_string(), "30-12-2024 12:00".to_string(), "Feriado".to_string()), Err(Error::PostergacionInvalida));
 
-            // Caso 3: Votación ya finalizó
-            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(200);
-            assert_eq!(
-                contrato.iniciar_votacion_privado(1),
-                Err(String::from("La votación ya finalizó."))
-            );
+            assert_eq!(contrato.postergar_eleccion_privado(1, "08-07-2024 12:00".to_string(), "07-01-2025 12:00".to_string(), "Feriado".to_string()), Ok(()));
+            let eleccion = contrato.obtener_eleccion_por_id(1).unwrap();
+            assert_eq!(eleccion.fecha_inicio, 1720440000000);
+            assert_eq!(eleccion.fecha_final, 1736251200000);
+            // Los cierres de inscripción y el fin de la revelación se corren junto con las fechas
+            assert_eq!(eleccion.cierre_inscripcion_candidatos, 1720440000000);
+            assert_eq!(eleccion.fecha_fin_revelacion, Some(1736856000000));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::EleccionPostergada(e)) if e.eleccion_id == 1 && e.motivo == "Feriado"));
+
+            // Con la votación iniciada ya no se puede postergar
+            set_block_timestamp::<DefaultEnvironment>(1720440000000);
+            assert_eq!(contrato.postergar_eleccion_privado(1, "15-07-2024 12:00".to_string(), "14-01-2025 12:00".to_string(), "Feriado".to_string()), Err(Error::VotacionYaIniciada));
+        }
 
-            // Caso 4: Votación ya inició
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100); // Restaurar el timestamp del bloque
-            contrato.elecciones[0].votacion_iniciada = true;
-            assert_eq!(
-                contrato.iniciar_votacion_privado(1),
-                Err(String::from("La votación ya inició."))
-            );
+        #[ink::test]
+        fn test_cancelar_eleccion()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Mayoria, 1), &[accounts.bob], 2);
+            contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            contrato.usuarios.insert(accounts.charlie, &crear_usuario(accounts.charlie, "Nombre", "Apellido", "12345678"));
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            assert_eq!(contrato.cancelar_eleccion_privado(1, "Irregularidades".to_string()), Ok(()));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::EleccionCancelada(e)) if e.eleccion_id == 1 && e.motivo == "Irregularidades"));
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Cancelada));
+            assert_eq!(contrato.cancelar_eleccion_privado(1, "Irregularidades".to_string()), Err(Error::EleccionCancelada));
+
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_a_candidato_privado(1, 1), Err(Error::EleccionCancelada));
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(accounts.alice);
+            contrato.cancelar_eleccion_privado(2, "Error de carga".to_string()).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(2, TIPO_DE_USUARIO::VOTANTE), Err(Error::EleccionCancelada));
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000);
+            assert_eq!(contrato.obtener_resultados_privado(1), Err(Error::EleccionCancelada));
+        }
 
-            // Caso 5: Todavía no es la fecha para la votación
-            contrato.elecciones[0].votacion_iniciada = false;
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30); // Cambiar el timestamp del bloque
-            assert_eq!(
-                contrato.iniciar_votacion_privado(1),
-                Err(String::from("Todavía no es la fecha para la votación."))
-            );
+        #[ink::test]
+        fn test_cancelar_eleccion_con_multifirma()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_multifirma();
+            let accion = AccionAdministrativa::CrearEleccion {
+                fecha_inicial: "01-07-2024 12:00".to_string(),
+                fecha_final: "31-12-2024 12:00".to_string(),
+                configuracion: ConfiguracionEleccion::default(),
+            };
+            contrato.proponer_accion_privado(accion).unwrap();
+            set_caller(accounts.bob);
+            contrato.confirmar_accion_privado(1).unwrap();
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000);
+            assert_eq!(contrato.cancelar_eleccion_privado(1, "Fraude".to_string()), Err(Error::RequiereMultifirma));
+            let propuesta_id = contrato.proponer_accion_privado(AccionAdministrativa::CancelarEleccion { eleccion_id: 1, motivo: "Fraude".to_string() }).unwrap();
+            // Finalizada la elección ya no se puede cancelar
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.confirmar_accion_privado(propuesta_id), Err(Error::EleccionFinalizada));
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            assert_eq!(contrato.confirmar_accion_privado(propuesta_id), Ok(true));
+            assert_eq!(contrato.obtener_estado_eleccion(1), Ok(EstadoEleccion::Cancelada));
+        }
 
-            // Caso 6: Se inició la votación exitosamente
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100); // Restaurar el timestamp del bloque
-            assert_eq!(
-                contrato.iniciar_votacion_privado(1),
-                Ok(String::from("Se inició la votación exitosamente."))
-            );
-            assert!(contrato.elecciones[0].votacion_iniciada);
+        // ====================== INICIO TESTS APELACIONES ======================
+        // ====================== INICIO TESTS APELACIONES ======================
+        // ====================== INICIO TESTS APELACIONES ======================
+
+        fn motivo(codigo: CodigoRechazo, detalle: &str) -> MotivoRechazo
+        {
+            MotivoRechazo { codigo, detalle: detalle.to_string() }
         }
 
         #[ink::test]
-        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_exito_VOTANTE() 
+        fn test_rechazar_usuario_con_motivo()
         {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]); 
-            let id_usuario = AccountId::from([2; 32]);
+            contrato.activar_registro_privado().unwrap();
+            assert_eq!(contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()), Ok(1));
+            set_caller(accounts.bob);
+            contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()).unwrap();
+            contrato.usuarios.insert(accounts.charlie, &crear_usuario(accounts.charlie, "Charlie", "Doe", "87654321"));
+            set_caller(accounts.charlie);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO).unwrap();
+
+            assert_eq!(contrato.rechazar_usuario_privado(accounts.bob, None, MotivoRechazo::default()), Err(Error::NoEsOperadorPadron));
+            set_caller(accounts.alice);
+            let largo = motivo(CodigoRechazo::Otro, &"x".repeat(LARGO_MAXIMO_MOTIVO + 1));
+            assert_eq!(contrato.rechazar_usuario_privado(accounts.bob, None, largo), Err(Error::MotivoInvalido));
+            assert_eq!(contrato.rechazar_usuario_privado(accounts.django, None, MotivoRechazo::default()), Err(Error::UsuarioNoPendiente));
+
+            let rechazo = motivo(CodigoRechazo::DatosIncorrectos, "El apellido no coincide con el DNI.");
+            assert_eq!(contrato.rechazar_usuario_privado(accounts.bob, None, rechazo.clone()), Ok(()));
+            assert!(contrato.usuarios_rechazados.contains(accounts.bob));
+            assert_eq!(contrato.rechazar_usuario_privado(accounts.charlie, Some(1), motivo(CodigoRechazo::NoHabilitado, "")), Ok(()));
+            assert!(contrato.rechazados_eleccion.contains((1, accounts.charlie)));
+            assert!(!contrato.pendientes_eleccion.contains((1, accounts.charlie)));
+            assert_eq!(contrato.rechazar_usuario_privado(accounts.charlie, Some(2), MotivoRechazo::default()), Err(Error::EleccionInexistente));
+
+            // El motivo queda en el historial, que puede ver el propio usuario
+            set_caller(accounts.bob);
+            let historial = contrato.obtener_historial_usuario_privado(accounts.bob, 0, 10).unwrap().elementos;
+            assert_eq!(historial.len(), 2);
+            assert_eq!((historial[0].accion.clone(), historial[0].autor), (AccionHistorial::Solicitud, accounts.bob));
+            assert_eq!((historial[1].accion.clone(), historial[1].autor), (AccionHistorial::Rechazo(rechazo), accounts.alice));
+            assert_eq!(contrato.obtener_historial_usuario_privado(accounts.charlie, 0, 10), Err(Error::NoEsOperadorPadron));
+            set_caller(accounts.alice);
+            let historial = contrato.obtener_historial_usuario_privado(accounts.charlie, 0, 10).unwrap().elementos;
+            assert_eq!(historial[1].eleccion_id, Some(1));
+            assert_eq!(historial[1].accion, AccionHistorial::Rechazo(motivo(CodigoRechazo::NoHabilitado, "")));
+        }
 
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+        #[ink::test]
+        fn test_apelar_rechazo_en_sistema()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(contrato.apelar_rechazo_privado(None, String::new()), Err(Error::UsuarioNoRechazado));
+            contrato.registrarse_privado("Bob".to_string(), "Dou".to_string(), "12345678".to_string()).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_siguiente_usuario_pendiente_privado(false).unwrap();
+
+            // Sin apelar no puede volver a registrarse
+            set_caller(accounts.bob);
+            assert_eq!(contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()), Err(Error::RegistroRechazado));
+            assert_eq!(contrato.apelar_rechazo_privado(None, "x".repeat(LARGO_MAXIMO_MOTIVO + 1)), Err(Error::MotivoInvalido));
+            assert_eq!(contrato.apelar_rechazo_privado(None, "Me equivoqué en el apellido.".to_string()), Ok(()));
+            assert_eq!(contrato.apelar_rechazo_privado(None, String::new()), Err(Error::ApelacionYaPresentada));
+
+            // Con la apelación vuelve a la cola con los datos corregidos
+            assert_eq!(contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()), Ok(()));
+            assert!(!contrato.usuarios_rechazados.contains(accounts.bob));
+            assert_eq!(contrato.usuarios_pendientes.get(accounts.bob).unwrap().apellido, "Doe");
+            set_caller(accounts.alice);
+            assert_eq!(contrato.listar_usuarios_rechazados_privado(0, 10).unwrap().elementos, Vec::<AccountId>::new());
+
+            // Si se rechaza otra vez, no puede volver a apelar
+            contrato.procesar_siguiente_usuario_pendiente_privado(false).unwrap();
+            assert_eq!(contrato.listar_usuarios_rechazados_privado(0, 10).unwrap().elementos, vec![accounts.bob]);
+            set_caller(accounts.bob);
+            assert_eq!(contrato.apelar_rechazo_privado(None, String::new()), Err(Error::ApelacionYaPresentada));
+            assert_eq!(contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()), Err(Error::RegistroRechazado));
+
+            let acciones: Vec<AccionHistorial> = contrato.obtener_historial_usuario_privado(accounts.bob, 0, 10).unwrap()
+                .elementos.into_iter().map(|registro| registro.accion).collect();
+            assert_eq!(acciones, vec![
+                AccionHistorial::Solicitud,
+                AccionHistorial::Rechazo(MotivoRechazo::default()),
+                AccionHistorial::Apelacion("Me equivoqué en el apellido.".to_string()),
+                AccionHistorial::Solicitud,
+                AccionHistorial::Rechazo(MotivoRechazo::default()),
+            ]);
+        }
 
-            // Simular una elección válida con usuarios pendientes
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![(id_usuario, TIPO_DE_USUARIO::VOTANTE)],
-                votacion_iniciada: false,
-                fecha_inicio: 0, 
-                fecha_final: 100, 
-                resultados: None,
-            });
+        #[ink::test]
+        fn test_rehabilitar_y_bloquear_usuario()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
+            for (cuenta, dni) in [(accounts.bob, "12345678"), (accounts.charlie, "87654321")] {
+                set_caller(cuenta);
+                contrato.registrarse_privado("Nombre".to_string(), "Apellido".to_string(), dni.to_string()).unwrap();
+            }
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_pendientes_privado(vec![accounts.bob, accounts.charlie], false).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.rehabilitar_usuario_privado(accounts.bob, None), Err(Error::NoEsAdministrador));
+            assert_eq!(contrato.bloquear_usuario_privado(accounts.charlie, None, MotivoRechazo::default()), Err(Error::NoEsAdministrador));
+
+            // Al rehabilitarlo puede volver a registrarse sin apelar
+            set_caller(accounts.alice);
+            assert_eq!(contrato.rehabilitar_usuario_privado(accounts.django, None), Err(Error::UsuarioNoRechazado));
+            assert_eq!(contrato.rehabilitar_usuario_privado(accounts.bob, None), Ok(()));
+            assert_eq!(contrato.rehabilitar_usuario_privado(accounts.bob, None), Err(Error::UsuarioNoRechazado));
+            set_caller(accounts.bob);
+            assert_eq!(contrato.registrarse_privado("Nombre".to_string(), "Apellido".to_string(), "12345678".to_string()), Ok(()));
+
+            // Una cuenta bloqueada no puede apelar ni ser rehabilitada
+            set_caller(accounts.alice);
+            let bloqueo = motivo(CodigoRechazo::DniNoVerificado, "Documentación adulterada.");
+            assert_eq!(contrato.bloquear_usuario_privado(accounts.charlie, None, bloqueo.clone()), Ok(()));
+            assert_eq!(contrato.bloquear_usuario_privado(accounts.charlie, None, bloqueo.clone()), Err(Error::UsuarioBloqueado));
+            assert_eq!(contrato.rehabilitar_usuario_privado(accounts.charlie, None), Err(Error::UsuarioBloqueado));
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.apelar_rechazo_privado(None, String::new()), Err(Error::UsuarioBloqueado));
+            assert_eq!(contrato.registrarse_privado("Nombre".to_string(), "Apellido".to_string(), "87654321".to_string()), Err(Error::RegistroRechazado));
+
+            let historial = contrato.obtener_historial_usuario_privado(accounts.charlie, 0, 10).unwrap().elementos;
+            assert_eq!(historial.last().map(|registro| registro.accion.clone()), Some(AccionHistorial::Bloqueo(bloqueo)));
+            set_caller(accounts.alice);
+            let historial = contrato.obtener_historial_usuario_privado(accounts.bob, 0, 10).unwrap().elementos;
+            assert_eq!(historial[2].accion, AccionHistorial::Rehabilitacion);
+            assert_eq!(historial.len(), 4);
+        }
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+        #[ink::test]
+        fn test_volver_a_registrarse_no_duplica_pendientes()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
+            set_caller(accounts.bob);
+            contrato.registrarse_privado("Bob".to_string(), "Dou".to_string(), "12345678".to_string()).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_pendientes_privado(vec![accounts.bob], false).unwrap();
+
+            set_caller(accounts.bob);
+            contrato.apelar_rechazo_privado(None, "Me equivoqué en el apellido.".to_string()).unwrap();
+            contrato.registrarse_privado("Bob".to_string(), "Doe".to_string(), "12345678".to_string()).unwrap();
+
+            // La posición de la solicitud rechazada se liberó y Bob aparece una sola vez en la cola
+            set_caller(accounts.alice);
+            let pendientes = contrato.listar_usuarios_pendientes_privado(0, 10).unwrap().elementos;
+            assert_eq!(pendientes.iter().map(|usuario| usuario.id).collect::<Vec<_>>(), vec![accounts.bob]);
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Ok(()));
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Err(Error::NoHayUsuariosPendientes));
+        }
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
+        #[ink::test]
+        fn test_apelar_rechazo_en_eleccion()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            assert_eq!(contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()), Ok(1));
+            contrato.usuarios.insert(accounts.bob, &crear_usuario(accounts.bob, "Bob", "Doe", "12345678"));
+            set_caller(accounts.bob);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO).unwrap();
+            set_caller(accounts.alice);
+            contrato.rechazar_usuario_privado(accounts.bob, Some(1), motivo(CodigoRechazo::NoHabilitado, "Debe inscribirse como votante.")).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE), Err(Error::RechazadoEnEleccion));
+            assert_eq!(contrato.apelar_rechazo_privado(Some(2), String::new()), Err(Error::EleccionInexistente));
+            // El rechazo en la elección no es un rechazo en el sistema
+            assert_eq!(contrato.apelar_rechazo_privado(None, String::new()), Err(Error::UsuarioNoRechazado));
+            assert_eq!(contrato.apelar_rechazo_privado(Some(1), "Me inscribo como votante.".to_string()), Ok(()));
+            assert_eq!(contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE), Ok(()));
+            assert_eq!(contrato.pendientes_eleccion.get((1, accounts.bob)), Some(TIPO_DE_USUARIO::VOTANTE));
+
+            set_caller(accounts.alice);
+            assert_eq!(contrato.listar_rechazados_eleccion_privado(1, 0, 10).unwrap().elementos, Vec::<AccountId>::new());
+            contrato.procesar_usuarios_en_una_eleccion_privado(1, true).unwrap();
+            assert!(contrato.votantes.contains((1, accounts.bob)));
+        }
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+        // ====================== INICIO TESTS CAMBIO DE DATOS Y BAJA ======================
+        // ====================== INICIO TESTS CAMBIO DE DATOS Y BAJA ======================
+        // ====================== INICIO TESTS CAMBIO DE DATOS Y BAJA ======================
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
+        #[ink::test]
+        fn test_cambio_de_datos()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.usuarios.insert(accounts.bob, &crear_usuario(accounts.bob, "Bob", "Doe", "12345678"));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.solicitar_cambio_de_datos_privado("Charlie".to_string(), "Doe".to_string()), Err(Error::UsuarioNoRegistrado));
+            set_caller(accounts.bob);
+            assert_eq!(contrato.solicitar_cambio_de_datos_privado(" ".to_string(), "Doe".to_string()), Err(Error::NombreInvalido));
+            assert_eq!(contrato.obtener_cambio_de_datos_privado(accounts.bob), Err(Error::CambioDeDatosInexistente));
+            assert_eq!(contrato.solicitar_cambio_de_datos_privado("Roberta".to_string(), "Doe".to_string()), Ok(()));
+            // Una nueva solicitud reemplaza a la pendiente
+            assert_eq!(contrato.solicitar_cambio_de_datos_privado(" Roberta ".to_string(), "Gómez".to_string()), Ok(()));
+            let cambio = CambioDeDatos { nombre: "Roberta".to_string(), apellido: "Gómez".to_string() };
+            assert_eq!(contrato.obtener_cambio_de_datos_privado(accounts.bob), Ok(cambio.clone()));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::CambioDeDatosSolicitado(e)) if e.usuario == accounts.bob));
+            assert_eq!(contrato.procesar_cambio_de_datos_privado(accounts.bob, true), Err(Error::NoEsOperadorPadron));
+
+            set_caller(accounts.charlie);
+            assert_eq!(contrato.obtener_cambio_de_datos_privado(accounts.bob), Err(Error::NoEsOperadorPadron));
+
+            // Hasta que se acepta, el usuario conserva sus datos
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_informacion_usuario_privado(accounts.bob), Ok(("Bob".to_string(), "Doe".to_string(), "12345678".to_string())));
+            assert_eq!(contrato.procesar_cambio_de_datos_privado(accounts.bob, false), Ok(()));
+            assert_eq!(contrato.obtener_cambio_de_datos_privado(accounts.bob), Err(Error::CambioDeDatosInexistente));
+            assert_eq!(contrato.procesar_cambio_de_datos_privado(accounts.bob, true), Err(Error::CambioDeDatosInexistente));
+            assert_eq!(contrato.obtener_informacion_usuario_privado(accounts.bob), Ok(("Bob".to_string(), "Doe".to_string(), "12345678".to_string())));
+
+            set_caller(accounts.bob);
+            contrato.solicitar_cambio_de_datos_privado("Roberta".to_string(), "Gómez".to_string()).unwrap();
+            set_caller(accounts.alice);
+            assert_eq!(contrato.procesar_cambio_de_datos_privado(accounts.bob, true), Ok(()));
+            assert_eq!(contrato.obtener_informacion_usuario_privado(accounts.bob), Ok(("Roberta".to_string(), "Gómez".to_string(), "12345678".to_string())));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::CambioDeDatosProcesado(e)) if e.usuario == accounts.bob && e.aceptado));
+
+            let acciones:Vec<AccionHistorial> = contrato.obtener_historial_usuario_privado(accounts.bob, 0, 10).unwrap()
+                .elementos.into_iter().map(|registro| registro.accion).collect();
+            assert_eq!(acciones, vec![
+                AccionHistorial::SolicitudCambioDeDatos(CambioDeDatos { nombre: "Roberta".to_string(), apellido: "Doe".to_string() }),
+                AccionHistorial::SolicitudCambioDeDatos(cambio.clone()),
+                AccionHistorial::CambioDeDatosRechazado,
+                AccionHistorial::SolicitudCambioDeDatos(cambio),
+                AccionHistorial::CambioDeDatosAceptado,
+            ]);
         }
 
         #[ink::test]
-        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_exito_CANDIDATO() 
+        fn test_darse_de_baja()
         {
+            let accounts = get_default_test_accounts();
+            let (a, b) = (AccountId::from([100; 32]), AccountId::from([101; 32]));
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]); 
-            let id_usuario = AccountId::from([2; 32]);
+            contrato.activar_registro_privado().unwrap();
+            for _ in 0..2 {
+                contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            }
+            set_caller(a);
+            contrato.registrarse_privado("Ana".to_string(), "Paz".to_string(), "12345678".to_string()).unwrap();
+            set_caller(b);
+            contrato.registrarse_privado("Beto".to_string(), "Paz".to_string(), "87654321".to_string()).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_pendientes_privado(vec![a, b], true).unwrap();
+            for eleccion_id in [1, 2] {
+                agregar_participante(&mut contrato, eleccion_id, accounts.charlie, TIPO_DE_USUARIO::CANDIDATO);
+                agregar_participante(&mut contrato, eleccion_id, a, TIPO_DE_USUARIO::VOTANTE);
+            }
+            agregar_participante(&mut contrato, 1, b, TIPO_DE_USUARIO::VOTANTE);
+
+            // En la elección 1 B delega su voto en A, y en la elección 2 A ya votó
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(b);
+            contrato.delegar_voto_privado(1, a).unwrap();
+            cambiar_estado(&mut contrato, 2, EstadoEleccion::Votacion);
+            set_caller(a);
+            contrato.votar_a_candidato_privado(2, 1).unwrap();
+            contrato.solicitar_cambio_de_datos_privado("Ana María".to_string(), "Paz".to_string()).unwrap();
+
+            assert_eq!(contrato.darse_de_baja_privado(), Ok(()));
+            assert_eq!(contrato.darse_de_baja_privado(), Err(Error::UsuarioNoRegistrado));
+            assert!(contrato.obtener_usuario(a).is_none());
+            assert_eq!(contrato.obtener_cambio_de_datos_privado(a), Err(Error::CambioDeDatosInexistente));
+            assert_eq!(contrato.votar_a_candidato_privado(2, 1), Err(Error::UsuarioNoRegistrado));
+
+            // Sale de la elección que no empezó y B recupera su voto
+            assert!(!contrato.votantes.contains((1, a)));
+            assert_eq!(peso_y_delegado(&contrato, 101), (1, None));
+            assert_eq!(contrato.obtener_informacion_eleccion(1).unwrap().cantidad_votantes, 1);
+            // La elección en votación conserva su voto y sigue entre sus elecciones
+            assert!(contrato.votantes.get((2, a)).unwrap().voto_emitido);
+            assert_eq!(contrato.elecciones_de_usuario.get(a), Some(vec![2]));
+            assert_eq!(contrato.obtener_informacion_eleccion(2).unwrap().cantidad_votantes, 1);
+
+            let eventos = eventos_emitidos();
+            assert!(eventos.iter().any(|evento| matches!(evento, Event::RetiradoDeEleccion(e) if e.usuario == a && e.eleccion_id == 1 && e.tipo == TIPO_DE_USUARIO::VOTANTE)));
+            assert!(!eventos.iter().any(|evento| matches!(evento, Event::RetiradoDeEleccion(e) if e.eleccion_id == 2)));
+            assert!(matches!(eventos.last(), Some(Event::UsuarioDadoDeBaja(e)) if e.usuario == a));
+
+            set_caller(accounts.alice);
+            assert_eq!(contrato.listar_usuarios_privado(0, 10).unwrap().elementos.iter().map(|usuario| usuario.id).collect::<Vec<_>>(), vec![b]);
+            assert_eq!(contrato.listar_votantes_eleccion_privado(1, 0, 10).unwrap().elementos, vec![b]);
+            let historial = contrato.obtener_historial_usuario_privado(a, 0, 10).unwrap().elementos;
+            assert_eq!(historial.last().map(|registro| (registro.eleccion_id, registro.accion.clone())), Some((None, AccionHistorial::Baja)));
+            assert!(historial.iter().any(|registro| registro.eleccion_id == Some(1) && registro.accion == AccionHistorial::Retiro));
+
+            // Su DNI queda libre
+            set_caller(accounts.django);
+            assert_eq!(contrato.registrarse_privado("Django".to_string(), "Paz".to_string(), "12345678".to_string()), Ok(()));
+        }
 
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+        #[ink::test]
+        fn test_volver_a_registrarse_despues_de_la_baja()
+        {
+            let accounts = get_default_test_accounts();
+            let (a, b) = (AccountId::from([100; 32]), AccountId::from([101; 32]));
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
+            set_caller(a);
+            contrato.registrarse_privado("Ana".to_string(), "Paz".to_string(), "12345678".to_string()).unwrap();
+            set_caller(b);
+            contrato.registrarse_privado("Beto".to_string(), "Paz".to_string(), "87654321".to_string()).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_pendientes_privado(vec![a, b], true).unwrap();
+
+            set_caller(a);
+            assert_eq!(contrato.darse_de_baja_privado(), Ok(()));
+            assert_eq!(contrato.registrarse_privado("Ana".to_string(), "Paz".to_string(), "12345678".to_string()), Ok(()));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.procesar_siguiente_usuario_pendiente_privado(true), Ok(()));
+
+            // Su posición anterior se omite y aparece una sola vez, en el orden de su nueva aceptación
+            let usuarios = contrato.listar_usuarios_privado(0, 10).unwrap().elementos;
+            assert_eq!(usuarios.iter().map(|usuario| usuario.id).collect::<Vec<_>>(), vec![b, a]);
+        }
 
-            // Simular una elección válida con usuarios pendientes
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![(id_usuario, TIPO_DE_USUARIO::CANDIDATO)],
-                votacion_iniciada: false,
-                fecha_inicio: 0, 
-                fecha_final: 100, 
-                resultados: None,
-            });
+        #[ink::test]
+        fn test_revelar_voto_despues_de_la_baja()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+
+            // Eve delega en Charlie, que compromete su voto y se da de baja durante la votación
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(accounts.eve);
+            contrato.delegar_voto_privado(1, accounts.charlie).unwrap();
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(accounts.charlie);
+            contrato.comprometer_voto_privado(1, SistemaElecciones::calcular_compromiso(1, accounts.charlie, &Voto::Candidato(1), [7; 32])).unwrap();
+            assert_eq!(contrato.darse_de_baja_privado(), Ok(()));
+
+            // Sigue siendo votante de la elección y puede revelar su voto con el peso delegado
+            set_block_timestamp::<DefaultEnvironment>(1736000000000);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Ok(()));
+            set_caller(accounts.frank);
+            assert_eq!(contrato.revelar_voto_privado(1, Voto::Candidato(1), [7; 32]), Err(Error::NoRegistradoEnEleccion));
+
+            set_block_timestamp::<DefaultEnvironment>(1736251200000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!((resultados.votos_sin_revelar, resultados.votos_candidatos), (0, vec![(accounts.bob, 2)]));
+        }
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+        #[ink::test]
+        fn test_baja_conserva_identidad_en_elecciones_iniciadas()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_voto_secreto();
+            contrato.usuarios.insert(accounts.frank, &crear_usuario(accounts.frank, "Frank", "Doe", "87654321"));
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            for cuenta in [accounts.bob, accounts.frank] {
+                set_caller(cuenta);
+                assert_eq!(contrato.darse_de_baja_privado(), Ok(()));
+            }
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+            // El candidato sigue identificado en la elección, con el DNI enmascarado
+            let info = contrato.obtener_informacion_candidato_eleccion_privado(1, 1).unwrap();
+            assert_eq!((info.id, info.nombre.as_str(), info.dni.as_str()), (accounts.bob, "Nombre", "*****678"));
+            set_caller(accounts.alice);
+            assert_eq!(contrato.obtener_informacion_usuario_privado(accounts.bob), Ok(("Nombre".to_string(), "Apellido".to_string(), "*****678".to_string())));
+            // De quien no participaba de ninguna elección no se conserva nada
+            assert_eq!(contrato.obtener_informacion_usuario_privado(accounts.frank), Err(Error::UsuarioInexistente));
+        }
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_ok(), "Error al obtener el siguiente usuario pendiente en la elección");
+        #[ink::test]
+        fn test_baja_de_candidato_conserva_ids()
+        {
+            let accounts = get_default_test_accounts();
+            let (bob, charlie, django) = (accounts.bob, accounts.charlie, accounts.django);
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::PreferenciaInstantanea, 1), &[bob, charlie, django], 6);
+            for candidato in [charlie, django] {
+                contrato.usuarios.insert(candidato, &crear_usuario(candidato, "Nombre", "Apellido", "87654321"));
+            }
+
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+            set_caller(charlie);
+            assert_eq!(contrato.darse_de_baja_privado(), Ok(()));
+            assert_eq!(contrato.obtener_perfil_candidato_privado(1, charlie), Err(Error::CandidatoInexistente));
+
+            // Django conserva su candidato_id y el de Charlie no existe más
+            assert_eq!(contrato.obtener_informacion_candidato_eleccion_privado(1, 2), Err(Error::CandidatoInexistente));
+            assert_eq!(contrato.obtener_informacion_candidato_eleccion_privado(1, 3).unwrap().id, django);
+            assert_eq!(contrato.listar_candidatos_eleccion(1, 0, 10).unwrap().elementos, vec![bob, django]);
+            assert_eq!(contrato.obtener_informacion_eleccion(1).unwrap().cantidad_candidatos, 2);
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_con_preferencias_privado(1, vec![2, 1]), Err(Error::PreferenciasInvalidas));
+            votar_boletas(&mut contrato, &[(4, vec![1]), (2, vec![3, 1])], SistemaElecciones::votar_con_preferencias_privado);
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.rondas, vec![Ronda { votos_candidatos: vec![(bob, 4), (django, 2)], eliminados: vec![], votos_agotados: 0 }]);
+            assert_eq!(resultados.votos_candidatos, vec![(bob, 4), (django, 2)]);
+            assert_eq!(resultados.ganadores, vec![bob]);
         }
 
+        // ====================== INICIO TESTS RETIRO DE ELECCIONES ======================
+        // ====================== INICIO TESTS RETIRO DE ELECCIONES ======================
+        // ====================== INICIO TESTS RETIRO DE ELECCIONES ======================
 
         #[ink::test]
-        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_fallo_eleccion_inexistente()
+        fn test_retirar_candidatura()
         {
-            let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]);
-            let id_usuario = AccountId::from([2; 32]);
+            let accounts = get_default_test_accounts();
+            let (bob, charlie, django) = (accounts.bob, accounts.charlie, accounts.django);
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Aprobacion, 1), &[bob, charlie, django], 3);
+            contrato.usuarios.insert(accounts.eve, &crear_usuario(accounts.eve, "Eve", "Doe", "11223344"));
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+
+            set_caller(accounts.eve);
+            assert_eq!(contrato.retirar_candidatura_privado(2), Err(Error::EleccionInexistente));
+            assert_eq!(contrato.retirar_candidatura_privado(1), Err(Error::NoEsCandidato));
+            // Una candidatura pendiente también se puede retirar
+            contrato.ingresar_como_candidato_privado(1, PerfilCandidato { partido: "Frente".to_string(), ..Default::default() }).unwrap();
+            assert_eq!(contrato.retirarse_como_votante_privado(1), Err(Error::NoRegistradoEnEleccion));
+            assert_eq!(contrato.retirar_candidatura_privado(1), Ok(()));
+            assert!(!contrato.pendientes_eleccion.contains((1, accounts.eve)));
+            assert!(!contrato.perfiles_candidatos.contains((1, accounts.eve)));
 
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+            set_caller(charlie);
+            assert_eq!(contrato.retirar_candidatura_privado(1), Ok(()));
+            assert_eq!(contrato.retirar_candidatura_privado(1), Err(Error::NoEsCandidato));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::RetiradoDeEleccion(e))
+                if e.usuario == charlie && e.eleccion_id == 1 && e.tipo == TIPO_DE_USUARIO::CANDIDATO));
+
+            // El candidato retirado queda marcado y los demás conservan su candidato_id
+            assert!(contrato.candidatos.get((1, 2)).is_some_and(|candidato| candidato.retirado));
+            assert_eq!(contrato.obtener_informacion_candidato_eleccion_privado(1, 2), Err(Error::CandidatoInexistente));
+            assert_eq!(contrato.listar_candidatos_eleccion(1, 0, 10).unwrap().elementos, vec![bob, django]);
+            assert_eq!(contrato.obtener_informacion_eleccion(1).unwrap().cantidad_candidatos, 2);
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(bob);
+            assert_eq!(contrato.retirar_candidatura_privado(1), Err(Error::VotacionYaIniciada));
+            set_caller(AccountId::from([100; 32]));
+            assert_eq!(contrato.votar_aprobando_privado(1, vec![1, 2]), Err(Error::AprobacionInvalida));
+            votar_boletas(&mut contrato, &[(2, vec![3]), (1, vec![1, 3])], SistemaElecciones::votar_aprobando_privado);
+
+            set_block_timestamp::<DefaultEnvironment>(1735646460000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_candidatos, vec![(bob, 1), (django, 3)]);
+            assert_eq!(resultados.ganadores, vec![django]);
+            set_caller(bob);
+            assert_eq!(contrato.retirar_candidatura_privado(1), Err(Error::EleccionFinalizada));
+        }
 
-            // Simular una elección válida con usuarios pendientes
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![(id_usuario, TIPO_DE_USUARIO::VOTANTE)],
-                votacion_iniciada: false,
-                fecha_inicio: 0, 
-                fecha_final: 100, 
-                resultados: None,
-            });
+        #[ink::test]
+        fn test_retirarse_como_votante()
+        {
+            let accounts = get_default_test_accounts();
+            let mut contrato = setup_contrato_eleccion(configuracion_votacion(SistemaVotacion::Mayoria, 1), &[accounts.bob], 4);
+            let (a, b, c, d) = (AccountId::from([100; 32]), AccountId::from([101; 32]), AccountId::from([102; 32]), AccountId::from([103; 32]));
+            set_block_timestamp::<DefaultEnvironment>(1719000000000);
+
+            // C delega en A y A delega en B
+            set_caller(c);
+            contrato.delegar_voto_privado(1, a).unwrap();
+            set_caller(a);
+            contrato.delegar_voto_privado(1, b).unwrap();
+            assert_eq!(peso_y_delegado(&contrato, 101), (3, None));
+            assert_eq!((contrato.delegantes.get((1, a)), contrato.delegantes.get((1, b))), (Some(vec![c]), Some(vec![a])));
+
+            set_caller(accounts.bob);
+            assert_eq!(contrato.retirarse_como_votante_privado(1), Err(Error::NoRegistradoEnEleccion));
+            set_caller(a);
+            assert_eq!(contrato.retirarse_como_votante_privado(1), Ok(()));
+            assert_eq!(contrato.retirarse_como_votante_privado(1), Err(Error::NoRegistradoEnEleccion));
+            assert!(matches!(eventos_emitidos().last(), Some(Event::RetiradoDeEleccion(e))
+                if e.usuario == a && e.eleccion_id == 1 && e.tipo == TIPO_DE_USUARIO::VOTANTE));
+
+            // C recupera su voto y B deja de llevar los votos de A y C
+            assert_eq!(peso_y_delegado(&contrato, 101), (1, None));
+            assert_eq!(peso_y_delegado(&contrato, 102), (1, None));
+            assert!(!contrato.delegantes.contains((1, a)) && !contrato.delegantes.contains((1, b)));
+            assert_eq!(contrato.obtener_informacion_eleccion(1).unwrap().cantidad_votantes, 3);
+            set_caller(accounts.alice);
+            assert_eq!(contrato.listar_votantes_eleccion_privado(1, 0, 10).unwrap().elementos, vec![b, c, d]);
+
+            // Puede volver a inscribirse sin aparecer dos veces en el padrón
+            set_caller(a);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_en_una_eleccion_privado(1, true).unwrap();
+            assert_eq!(contrato.listar_votantes_eleccion_privado(1, 0, 10).unwrap().elementos, vec![b, c, d, a]);
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(d);
+            assert_eq!(contrato.retirarse_como_votante_privado(1), Err(Error::VotacionYaIniciada));
+            for votante in [a, b, c] {
+                set_caller(votante);
+                contrato.votar_a_candidato_privado(1, 1).unwrap();
+            }
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(10);
+            set_block_timestamp::<DefaultEnvironment>(1735646460000);
+            let resultados = contrato.obtener_resultados_privado(1).unwrap();
+            assert_eq!(resultados.votos_totales, 4);
+            assert_eq!(resultados.votos_realizados, 3);
+            assert_eq!(resultados.votos_candidatos, vec![(accounts.bob, 3)]);
+        }
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_err(), "Se esperaba un error al obtener el siguiente usuario pendiente en la elección (no se encontró elección)");
+        #[ink::test]
+        fn test_volver_a_solicitar_ingreso_no_duplica_pendientes()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+            contrato.usuarios.insert(accounts.bob, &crear_usuario(accounts.bob, "Bob", "Doe", "12345678"));
+            contrato.usuarios.insert(accounts.charlie, &crear_usuario(accounts.charlie, "Charlie", "Doe", "87654321"));
+
+            // Bob retira su solicitud y la vuelve a enviar
+            set_caller(accounts.bob);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE).unwrap();
+            assert_eq!(contrato.retirarse_como_votante_privado(1), Ok(()));
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE).unwrap();
+
+            // Charlie es rechazado en un lote, apela y vuelve a enviar su solicitud
+            set_caller(accounts.charlie);
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::CANDIDATO).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_pendientes_en_eleccion_privado(1, vec![accounts.charlie], false).unwrap();
+            set_caller(accounts.charlie);
+            contrato.apelar_rechazo_privado(Some(1), "Me inscribo como votante.".to_string()).unwrap();
+            contrato.ingresar_a_eleccion_privado(1, TIPO_DE_USUARIO::VOTANTE).unwrap();
+
+            // Cada uno aparece una sola vez en la cola
+            set_caller(accounts.alice);
+            let pendientes = contrato.listar_pendientes_eleccion_privado(1, 0, 10).unwrap().elementos;
+            assert_eq!(pendientes.iter().map(|solicitud| solicitud.usuario).collect::<Vec<_>>(), vec![accounts.bob, accounts.charlie]);
+            assert_eq!(contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1).unwrap().usuario, accounts.bob);
+            for _ in 0..2 {
+                contrato.procesar_usuarios_en_una_eleccion_privado(1, true).unwrap();
+            }
+            assert_eq!(contrato.procesar_usuarios_en_una_eleccion_privado(1, true), Err(Error::NoHayUsuariosPendientes));
+            assert_eq!(contrato.listar_votantes_eleccion_privado(1, 0, 10).unwrap().elementos, vec![accounts.bob, accounts.charlie]);
+            assert_eq!(contrato.listar_pendientes_eleccion_privado(1, 0, 10).unwrap().elementos, Vec::<SolicitudPendiente>::new());
         }
 
+        // ====================== INICIO TESTS EVENTOS ======================
+        // ====================== INICIO TESTS EVENTOS ======================
+        // ====================== INICIO TESTS EVENTOS ======================
+
         #[ink::test]
-        fn test_obtener_siguiente_usuario_pendiente_en_una_eleccion_privado_fallo_sin_usuarios_pendientes() 
+        fn test_eventos_registro_y_aceptacion_de_usuarios()
         {
-            // Configuración inicial del contrato y del contexto de prueba
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
             let mut contrato = SistemaElecciones::new();
-            let id_administrador = AccountId::from([1; 32]); // Ejemplo de ID de administrador
+            contrato.activar_registro_privado().unwrap();
 
-            // Simular un administrador registrado
-            contrato.administrador = id_administrador;
+            set_caller(accounts.bob);
+            contrato.registrarse_privado("Bob".to_string(), "Asd".to_string(), "12345678".to_string()).unwrap();
+            set_caller(accounts.charlie);
+            contrato.registrarse_privado("Charlie".to_string(), "Dsa".to_string(), "87654321".to_string()).unwrap();
+
+            set_caller(accounts.alice);
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+            contrato.procesar_siguiente_usuario_pendiente_privado(false).unwrap();
+
+            let eventos = eventos_emitidos();
+            assert_eq!(eventos.len(), 4);
+            assert!(matches!(&eventos[0], Event::UsuarioRegistrado(e) if e.usuario == accounts.bob));
+            assert!(matches!(&eventos[1], Event::UsuarioRegistrado(e) if e.usuario == accounts.charlie));
+            assert!(matches!(&eventos[2], Event::UsuarioAceptado(e) if e.usuario == accounts.bob && e.eleccion_id.is_none()));
+            assert!(matches!(&eventos[3], Event::UsuarioRechazado(e) if e.usuario == accounts.charlie && e.eleccion_id.is_none()));
+        }
 
-            // Simular una elección válida sin usuarios pendientes
-            contrato.elecciones.push(Eleccion {
-                id: 1,
-                candidatos: vec![],
-                votantes: vec![],
-                usuarios_rechazados: vec![],
-                usuarios_pendientes: vec![],
-                votacion_iniciada: false,
-                fecha_inicio: 0, // Ajustar según la lógica de tu contrato
-                fecha_final: 100, // Ajustar según la lógica de tu contrato
-                resultados: None,
-            });
+        #[ink::test]
+        fn test_eventos_eleccion_y_voto()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
+            contrato.activar_registro_privado().unwrap();
+            let eleccion_id = contrato.crear_eleccion_privado("01-07-2024 12:00".to_string(), "31-12-2024 12:00".to_string(), ConfiguracionEleccion::default()).unwrap();
+
+            set_caller(accounts.bob);
+            contrato.registrarse_privado("Bob".to_string(), "Asd".to_string(), "12345678".to_string()).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_siguiente_usuario_pendiente_privado(true).unwrap();
+
+            set_caller(accounts.bob);
+            contrato.ingresar_a_eleccion_privado(eleccion_id, TIPO_DE_USUARIO::VOTANTE).unwrap();
+            set_caller(accounts.alice);
+            contrato.procesar_usuarios_en_una_eleccion_privado(eleccion_id, true).unwrap();
+            agregar_participante(&mut contrato, eleccion_id, accounts.charlie, TIPO_DE_USUARIO::CANDIDATO);
+
+            set_block_timestamp::<DefaultEnvironment>(1720000000000);
+            set_caller(accounts.bob);
+            contrato.votar_a_candidato_privado(eleccion_id, 1).unwrap();
+
+            let eventos = eventos_emitidos();
+            assert!(matches!(&eventos[0], Event::EleccionCreada(e) if e.eleccion_id == eleccion_id && e.fecha_inicio == 1719835200000));
+            assert!(matches!(&eventos[3], Event::IngresoAEleccionSolicitado(e) if e.usuario == accounts.bob && e.eleccion_id == eleccion_id && e.tipo == TIPO_DE_USUARIO::VOTANTE));
+            assert!(matches!(&eventos[4], Event::UsuarioAceptado(e) if e.usuario == accounts.bob && e.eleccion_id == Some(eleccion_id) && e.tipo == Some(TIPO_DE_USUARIO::VOTANTE)));
+            // El primer voto dentro del período inicia la votación
+            assert!(matches!(&eventos[5], Event::VotacionIniciada(e) if e.eleccion_id == eleccion_id));
+            assert!(matches!(&eventos[6], Event::VotoEmitido(e) if e.votante == accounts.bob && e.eleccion_id == eleccion_id));
+            assert_eq!(eventos.len(), 7);
+        }
 
-            // Ejecutar el método para obtener el siguiente usuario pendiente en la elección
-            let result_obtener = contrato.obtener_siguiente_usuario_pendiente_en_una_eleccion_privado(1);
+        #[ink::test]
+        fn test_eventos_roles_administrativos()
+        {
+            let accounts = get_default_test_accounts();
+            set_caller(accounts.alice);
+            let mut contrato = SistemaElecciones::new();
 
-            // Verificar el resultado esperado
-            assert!(result_obtener.is_err(), "Se esperaba un error al obtener el siguiente usuario pendiente en la elección (ningún usuario pendiente)");
+            contrato.asignar_generador_reportes_privado(accounts.django).unwrap();
+            contrato.transferir_administrador_privado(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            contrato.aceptar_administrador_privado().unwrap();
+
+            // Los intentos fallidos no emiten eventos
+            set_caller(accounts.alice);
+            assert!(contrato.transferir_administrador_privado(accounts.charlie).is_err());
+
+            let eventos = eventos_emitidos();
+            assert_eq!(eventos.len(), 6);
+            assert!(matches!(&eventos[0], Event::RolOtorgado(e) if e.cuenta == accounts.django && e.rol == Rol::GeneradorReportes && e.eleccion_id.is_none()));
+            assert!(matches!(&eventos[1], Event::GeneradorReportesAsignado(e) if e.generador_reportes == accounts.django));
+            assert!(matches!(&eventos[2], Event::TransferenciaAdministradorPropuesta(e) if e.proponente == accounts.alice && e.nuevo == accounts.bob && e.vence_en_bloque.is_none()));
+            assert!(matches!(&eventos[3], Event::RolOtorgado(e) if e.cuenta == accounts.bob && e.rol == Rol::Administrador));
+            assert!(matches!(&eventos[4], Event::RolRevocado(e) if e.cuenta == accounts.alice && e.rol == Rol::Administrador && !e.renuncia));
+            assert!(matches!(&eventos[5], Event::AdministradorTransferido(e) if e.anterior == accounts.alice && e.nuevo == accounts.bob));
         }
-        
     }
 }    
\ No newline at end of file